@@ -1,6 +1,8 @@
 //! Operator trait implementations for bit-arrays.
 
 use core::ops::{
+	Add,
+	AddAssign,
 	BitAnd,
 	BitAndAssign,
 	BitOr,
@@ -12,6 +14,12 @@ use core::ops::{
 	Index,
 	IndexMut,
 	Not,
+	Shl,
+	ShlAssign,
+	Shr,
+	ShrAssign,
+	Sub,
+	SubAssign,
 };
 
 use super::BitArray;
@@ -22,6 +30,33 @@ use crate::{
 	view::BitViewSized,
 };
 
+/// Bit-arrays add as little-endian integers, with index `0` as the least
+/// significant bit, and wrap around on overflow.
+impl<A, O> Add for BitArray<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	type Output = Self;
+
+	#[inline]
+	fn add(mut self, rhs: Self) -> Self::Output {
+		self += rhs;
+		self
+	}
+}
+
+impl<A, O> AddAssign for BitArray<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn add_assign(&mut self, rhs: Self) {
+		self.as_mut_bitslice().wrapping_add_le(rhs.as_bitslice());
+	}
+}
+
 #[cfg(not(tarpaulin_include))]
 impl<A, O> BitAndAssign<BitArray<A, O>> for BitSlice<A::Store, O>
 where
@@ -242,3 +277,80 @@ where
 		self
 	}
 }
+
+impl<A, O> Shl<usize> for BitArray<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	type Output = Self;
+
+	#[inline]
+	fn shl(mut self, by: usize) -> Self::Output {
+		self <<= by;
+		self
+	}
+}
+
+impl<A, O> ShlAssign<usize> for BitArray<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn shl_assign(&mut self, by: usize) {
+		*self.as_mut_bitslice() <<= by;
+	}
+}
+
+impl<A, O> Shr<usize> for BitArray<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	type Output = Self;
+
+	#[inline]
+	fn shr(mut self, by: usize) -> Self::Output {
+		self >>= by;
+		self
+	}
+}
+
+impl<A, O> ShrAssign<usize> for BitArray<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn shr_assign(&mut self, by: usize) {
+		*self.as_mut_bitslice() >>= by;
+	}
+}
+
+/// Bit-arrays subtract as little-endian integers, with index `0` as the least
+/// significant bit, and wrap around on overflow.
+impl<A, O> Sub for BitArray<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	type Output = Self;
+
+	#[inline]
+	fn sub(mut self, rhs: Self) -> Self::Output {
+		self -= rhs;
+		self
+	}
+}
+
+impl<A, O> SubAssign for BitArray<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn sub_assign(&mut self, rhs: Self) {
+		self.as_mut_bitslice().wrapping_sub_le(rhs.as_bitslice());
+	}
+}
@@ -91,6 +91,26 @@ fn ops() {
 	let mut f = !e;
 	assert_eq!(f[.. 4], bitarr![1, 0, 0, 1][.. 4]);
 
+	let g = bitarr![u8, Lsb0; 1, 1, 0, 1, 0, 0, 1, 0];
+	assert_eq!(g << 2, bitarr![u8, Lsb0; 0, 1, 0, 0, 1, 0, 0, 0]);
+	assert_eq!(g >> 3, bitarr![u8, Lsb0; 0, 0, 0, 1, 1, 0, 1, 0]);
+	assert_eq!(g << 8, bitarr![u8, Lsb0; 0; 8]);
+	let mut h = g;
+	h <<= 1;
+	h >>= 1;
+	assert_eq!(h, bitarr![u8, Lsb0; 0, 1, 0, 1, 0, 0, 1, 0]);
+
+	let (x, y) = (rand::random::<u64>(), rand::random::<u64>());
+	let split = |val: u64| {
+		BitArray::<[u32; 2], Lsb0>::new([val as u32, (val >> 32) as u32])
+	};
+	assert_eq!(split(x) + split(y), split(x.wrapping_add(y)));
+	assert_eq!(split(x) - split(y), split(x.wrapping_sub(y)));
+	let mut i = split(x);
+	i += split(y);
+	i -= split(x);
+	assert_eq!(i, split(y));
+
 	let _: &BitSlice = &a;
 	let _: &mut BitSlice = &mut f;
 }
@@ -145,7 +165,9 @@ mod format {
 	use super::{
 		BitArray,
 		Lsb0,
+		Msb0,
 	};
+	use crate::slice::ParseBitsError;
 
 	#[test]
 	fn render() {
@@ -173,4 +195,30 @@ mod format {
 			"TryFromBitSliceError::Misaligned",
 		);
 	}
+
+	#[test]
+	fn parse() {
+		let arr = BitArray::<[u16; 2], Msb0>::new(rand::random());
+		assert_eq!(format!("{}", arr).parse(), Ok(arr));
+		assert_eq!(format!("{:#b}", arr).parse(), Ok(arr));
+		assert!(
+			format!("{:#x}", arr)
+				.parse::<BitArray<[u16; 2], Msb0>>()
+				.is_err()
+		);
+		assert_eq!(
+			"0x1234".parse::<BitArray<[u16; 2], Msb0>>(),
+			Err(ParseBitsError::Length {
+				expected: 32,
+				found:    16,
+			})
+		);
+		assert_eq!(
+			"0x1234_5678_9".parse::<BitArray<[u16; 2], Msb0>>(),
+			Err(ParseBitsError::Length {
+				expected: 32,
+				found:    36,
+			})
+		);
+	}
 }
@@ -18,6 +18,7 @@ use core::{
 		Hasher,
 	},
 	marker::Unpin,
+	str::FromStr,
 };
 
 use tap::TryConv;
@@ -27,7 +28,11 @@ use crate::{
 	index::BitIdx,
 	mem,
 	order::BitOrder,
-	slice::BitSlice,
+	slice::{
+		parse_bits,
+		BitSlice,
+		ParseBitsError,
+	},
 	store::BitStore,
 	view::BitViewSized,
 };
@@ -267,6 +272,47 @@ easy_fmt! {
 	for BitArray
 }
 
+/// Parses bit-text, including the `Display` and `{:#b}` renderings, in the
+/// same manner as [`BitVec`].
+///
+/// The text must hold exactly as many bits as the bit-array.
+///
+/// ## Examples
+///
+/// ```rust
+/// use bitvec::prelude::*;
+///
+/// let arr: BitArr!(for 8, in u8) = "0x5A".parse().unwrap();
+/// assert_eq!(arr.into_inner(), [0x5A]);
+/// assert!("0b1".parse::<BitArr!(for 8, in u8)>().is_err());
+/// ```
+///
+/// [`BitVec`]: crate::vec::BitVec
+impl<A, O> FromStr for BitArray<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	type Err = ParseBitsError;
+
+	#[inline]
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		let mut out = Self::ZERO;
+		let expected = out.len();
+		let mut found = 0;
+		parse_bits(text, |bit| {
+			if found < expected {
+				out.set(found, bit);
+			}
+			found += 1;
+		})?;
+		if found != expected {
+			return Err(ParseBitsError::Length { expected, found });
+		}
+		Ok(out)
+	}
+}
+
 #[cfg(not(tarpaulin_include))]
 impl<A, O> Hash for BitArray<A, O>
 where
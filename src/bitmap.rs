@@ -0,0 +1,298 @@
+#![doc = include_str!("../doc/bitmap.md")]
+#![cfg(feature = "alloc")]
+
+use core::fmt::{
+	self,
+	Debug,
+	Formatter,
+};
+
+use crate::{
+	boxed::BitBox,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+mod tests;
+
+#[doc = include_str!("../doc/bitmap/BitmapAllocator.md")]
+pub struct BitmapAllocator<T = usize, O = Lsb0>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The occupancy map. A `1` bit marks an allocated block.
+	bits: BitBox<T, O>,
+}
+
+/// Constructors and conversions.
+impl<T, O> BitmapAllocator<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Creates an allocator that manages `capacity` blocks, all of which are
+	/// free.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::bitmap::BitmapAllocator;
+	///
+	/// let alloc = BitmapAllocator::<u64>::new(100);
+	/// assert_eq!(alloc.capacity(), 100);
+	/// assert_eq!(alloc.available(), 100);
+	/// ```
+	#[inline]
+	pub fn new(capacity: usize) -> Self {
+		Self {
+			bits: BitVec::repeat(false, capacity).into_boxed_bitslice(),
+		}
+	}
+
+	/// Creates an allocator from an existing occupancy map, in which `1` bits
+	/// mark allocated blocks.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::bitmap::BitmapAllocator;
+	///
+	/// let alloc = BitmapAllocator::from_bitbox(bitbox![0, 1, 1, 0]);
+	/// assert_eq!(alloc.used(), 2);
+	/// ```
+	#[inline]
+	pub fn from_bitbox(bits: BitBox<T, O>) -> Self {
+		Self { bits }
+	}
+
+	/// Views the occupancy map, in which `1` bits mark allocated blocks.
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<T, O> {
+		self.bits.as_bitslice()
+	}
+
+	/// Unwraps the occupancy map.
+	#[inline]
+	pub fn into_bitbox(self) -> BitBox<T, O> {
+		self.bits
+	}
+}
+
+/// Allocation.
+impl<T, O> BitmapAllocator<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Gets the total number of blocks that the allocator manages.
+	#[inline]
+	pub fn capacity(&self) -> usize {
+		self.bits.len()
+	}
+
+	/// Counts the allocated blocks.
+	#[inline]
+	pub fn used(&self) -> usize {
+		self.bits.count_ones()
+	}
+
+	/// Counts the free blocks.
+	#[inline]
+	pub fn available(&self) -> usize {
+		self.bits.count_zeros()
+	}
+
+	/// Tests whether a block is allocated.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds.
+	#[inline]
+	pub fn is_allocated(&self, index: usize) -> bool {
+		self.bits[index]
+	}
+
+	/// Allocates `len` contiguous blocks.
+	///
+	/// This is a first-fit allocator: it claims the lowest-numbered run of free
+	/// blocks that is long enough.
+	///
+	/// ## Returns
+	///
+	/// The index of the first allocated block, or `None` if there is no run of
+	/// `len` free blocks.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::bitmap::BitmapAllocator;
+	///
+	/// let mut alloc = BitmapAllocator::<u8>::new(10);
+	/// assert_eq!(alloc.allocate(4), Some(0));
+	/// assert_eq!(alloc.allocate(4), Some(4));
+	/// assert_eq!(alloc.allocate(4), None);
+	/// assert_eq!(alloc.allocate(2), Some(8));
+	/// ```
+	#[inline]
+	pub fn allocate(&mut self, len: usize) -> Option<usize> {
+		self.allocate_aligned(len, 1)
+	}
+
+	/// Allocates `len` contiguous blocks, starting at a multiple of `align`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `align` is zero.
+	///
+	/// ## Returns
+	///
+	/// The index of the first allocated block, or `None` if there is no
+	/// suitably aligned run of `len` free blocks.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::bitmap::BitmapAllocator;
+	///
+	/// let mut alloc = BitmapAllocator::<u8>::new(16);
+	/// assert_eq!(alloc.allocate(1), Some(0));
+	/// assert_eq!(alloc.allocate_aligned(4, 4), Some(4));
+	/// assert_eq!(alloc.allocate_aligned(2, 8), Some(8));
+	/// ```
+	#[inline]
+	pub fn allocate_aligned(
+		&mut self,
+		len: usize,
+		align: usize,
+	) -> Option<usize> {
+		let start = self.bits.find_zero_run_aligned(len, align)?;
+		self.bits[start .. start + len].fill(true);
+		Some(start)
+	}
+
+	/// Frees `len` contiguous blocks, beginning at `start`.
+	///
+	/// ## Panics
+	///
+	/// This panics if the range is out of bounds, or if any block in it is not
+	/// allocated.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::bitmap::BitmapAllocator;
+	///
+	/// let mut alloc = BitmapAllocator::<u8>::new(10);
+	/// let start = alloc.allocate(6).unwrap();
+	/// alloc.deallocate(start + 2, 2);
+	/// assert_eq!(alloc.allocate(2), Some(2));
+	/// ```
+	#[inline]
+	pub fn deallocate(&mut self, start: usize, len: usize) {
+		let blocks = &mut self.bits[start ..][.. len];
+		assert!(blocks.all(), "attempt to free unallocated blocks");
+		blocks.fill(false);
+	}
+
+	/// Measures the occupancy and fragmentation of the managed blocks.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::bitmap::BitmapAllocator;
+	///
+	/// let alloc = BitmapAllocator::from_bitbox(bitbox![0, 1, 0, 0, 1, 0, 0, 0]);
+	/// let stats = alloc.stats();
+	/// assert_eq!(stats.free, 6);
+	/// assert_eq!(stats.free_runs, 3);
+	/// assert_eq!(stats.largest_free_run, 3);
+	/// assert_eq!(stats.fragmentation(), 0.5);
+	/// ```
+	#[inline]
+	pub fn stats(&self) -> BitmapStats {
+		let mut stats = BitmapStats {
+			capacity: self.capacity(),
+			..BitmapStats::default()
+		};
+		let mut rest = self.as_bitslice();
+		while let Some(start) = rest.first_zero() {
+			rest = &rest[start ..];
+			let run = rest.first_one().unwrap_or_else(|| rest.len());
+			stats.free += run;
+			stats.free_runs += 1;
+			stats.largest_free_run = stats.largest_free_run.max(run);
+			rest = &rest[run ..];
+		}
+		stats.used = stats.capacity - stats.free;
+		stats
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Clone for BitmapAllocator<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			bits: self.bits.clone(),
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Debug for BitmapAllocator<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("BitmapAllocator")
+			.field("capacity", &self.capacity())
+			.field("bits", &self.as_bitslice())
+			.finish()
+	}
+}
+
+/// Occupancy and fragmentation statistics of a [`BitmapAllocator`].
+///
+/// [`BitmapAllocator`]: crate::bitmap::BitmapAllocator
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BitmapStats {
+	/// The total number of managed blocks.
+	pub capacity: usize,
+	/// The number of allocated blocks.
+	pub used: usize,
+	/// The number of free blocks.
+	pub free: usize,
+	/// The number of maximal runs of free blocks.
+	pub free_runs: usize,
+	/// The length of the longest run of free blocks.
+	pub largest_free_run: usize,
+}
+
+impl BitmapStats {
+	/// Computes the external fragmentation of the free blocks.
+	///
+	/// This is the fraction of free blocks that lie outside the largest free
+	/// run: `0.0` when all free space is contiguous (or there is none), and
+	/// approaching `1.0` as free space is scattered into many small runs.
+	#[inline]
+	pub fn fragmentation(&self) -> f64 {
+		if self.free == 0 {
+			return 0.0;
+		}
+		1.0 - self.largest_free_run as f64 / self.free as f64
+	}
+}
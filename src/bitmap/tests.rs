@@ -0,0 +1,102 @@
+//! Unit tests for bitmap allocators.
+
+#![cfg(test)]
+
+use rand::random;
+
+use super::*;
+use crate::{
+	order::HiLo,
+	prelude::*,
+};
+
+#[test]
+fn allocation() {
+	let mut alloc = BitmapAllocator::<u8, Msb0>::new(20);
+	assert_eq!(alloc.stats(), BitmapStats {
+		capacity: 20,
+		used: 0,
+		free: 20,
+		free_runs: 1,
+		largest_free_run: 20,
+	});
+	assert_eq!(alloc.stats().fragmentation(), 0.0);
+
+	assert_eq!(alloc.allocate(3), Some(0));
+	assert_eq!(alloc.allocate_aligned(3, 8), Some(8));
+	assert_eq!(alloc.allocate(5), Some(3));
+	assert_eq!(alloc.allocate(10), None);
+	assert!(alloc.is_allocated(9));
+	assert!(!alloc.is_allocated(11));
+
+	alloc.deallocate(4, 2);
+	let stats = alloc.stats();
+	assert_eq!((stats.used, stats.free), (9, 11));
+	assert_eq!((stats.free_runs, stats.largest_free_run), (2, 9));
+	assert_eq!(alloc.allocate(2), Some(4));
+	assert_eq!(alloc.allocate(9), Some(11));
+	assert_eq!(alloc.available(), 0);
+	assert_eq!(alloc.stats().fragmentation(), 0.0);
+	assert_eq!(alloc.allocate(1), None);
+}
+
+#[test]
+fn random_churn() {
+	fn check<T, O>()
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		let mut alloc = BitmapAllocator::<T, O>::new(500);
+		let mut live = Vec::<(usize, usize)>::new();
+		for _ in 0 .. 300 {
+			if random::<bool>() || live.is_empty() {
+				let len = random::<usize>() % 40 + 1;
+				let align = [1, 2, 8, 32][random::<usize>() % 4];
+				let expected =
+					alloc.as_bitslice().find_zero_run_aligned(len, align);
+				let start = alloc.allocate_aligned(len, align);
+				assert_eq!(start, expected);
+				if let Some(start) = start {
+					assert_eq!(start % align, 0);
+					assert!(alloc.as_bitslice()[start ..][.. len].all());
+					live.push((start, len));
+				}
+			}
+			else {
+				let (start, len) =
+					live.swap_remove(random::<usize>() % live.len());
+				alloc.deallocate(start, len);
+				assert!(alloc.as_bitslice()[start ..][.. len].not_any());
+			}
+
+			let stats = alloc.stats();
+			assert_eq!(
+				stats.used,
+				live.iter().map(|&(_, len)| len).sum::<usize>()
+			);
+			assert_eq!(stats.used + stats.free, stats.capacity);
+			assert!(stats.largest_free_run <= stats.free);
+			assert_eq!(
+				alloc
+					.as_bitslice()
+					.find_zero_run(stats.largest_free_run + 1),
+				None,
+			);
+			assert!((0.0 ..= 1.0).contains(&stats.fragmentation()));
+		}
+	}
+
+	check::<u8, Lsb0>();
+	check::<u32, Msb0>();
+	check::<u16, HiLo>();
+}
+
+#[test]
+#[should_panic = "attempt to free unallocated blocks"]
+fn double_free() {
+	let mut alloc = BitmapAllocator::<u8>::new(8);
+	alloc.allocate(4);
+	alloc.deallocate(0, 4);
+	alloc.deallocate(2, 1);
+}
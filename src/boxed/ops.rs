@@ -14,6 +14,10 @@ use core::{
 		Index,
 		IndexMut,
 		Not,
+		Shl,
+		ShlAssign,
+		Shr,
+		ShrAssign,
 	},
 };
 
@@ -257,3 +261,53 @@ where
 		self
 	}
 }
+
+impl<T, O> Shl<usize> for BitBox<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Output = Self;
+
+	#[inline]
+	fn shl(mut self, by: usize) -> Self::Output {
+		self <<= by;
+		self
+	}
+}
+
+impl<T, O> ShlAssign<usize> for BitBox<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn shl_assign(&mut self, by: usize) {
+		*self.as_mut_bitslice() <<= by;
+	}
+}
+
+impl<T, O> Shr<usize> for BitBox<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Output = Self;
+
+	#[inline]
+	fn shr(mut self, by: usize) -> Self::Output {
+		self >>= by;
+		self
+	}
+}
+
+impl<T, O> ShrAssign<usize> for BitBox<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn shr_assign(&mut self, by: usize) {
+		*self.as_mut_bitslice() >>= by;
+	}
+}
@@ -167,6 +167,13 @@ fn ops() {
 	let mut f = !e;
 	assert_eq!(f, bitbox![1, 0, 0, 1]);
 
+	assert_eq!(f.clone() << 1, bitbox![0, 0, 1, 0]);
+	assert_eq!(f.clone() >> 1, bitbox![0, 1, 0, 0]);
+	f >>= 3;
+	assert_eq!(f, bitbox![0, 0, 0, 1]);
+	f <<= 3;
+	assert_eq!(f, bitbox![1, 0, 0, 0]);
+
 	let _: &BitSlice = &a;
 	let _: &mut BitSlice = &mut f;
 }
@@ -22,6 +22,7 @@ use core::{
 		Hasher,
 	},
 	iter::FromIterator,
+	str::FromStr,
 };
 
 use tap::Pipe;
@@ -30,7 +31,10 @@ use super::BitBox;
 use crate::{
 	array::BitArray,
 	order::BitOrder,
-	slice::BitSlice,
+	slice::{
+		BitSlice,
+		ParseBitsError,
+	},
 	store::BitStore,
 	vec::BitVec,
 	view::BitViewSized,
@@ -356,6 +360,25 @@ where
 	}
 }
 
+/// Parses bit-text, including the `Display` and `{:#b}` renderings, in the
+/// same manner as [`BitVec`].
+///
+/// [`BitVec`]: crate::vec::BitVec
+#[cfg(not(tarpaulin_include))]
+impl<T, O> FromStr for BitBox<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Err = ParseBitsError;
+
+	#[inline]
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		text.parse::<BitVec<T, O>>()
+			.map(BitVec::into_boxed_bitslice)
+	}
+}
+
 #[cfg(not(tarpaulin_include))]
 impl<T, O> Hash for BitBox<T, O>
 where
@@ -0,0 +1,347 @@
+#![doc = include_str!("../doc/crc.md")]
+
+use core::fmt::{
+	self,
+	Debug,
+	Formatter,
+};
+
+use crate::{
+	mem::bits_of,
+	order::BitOrder,
+	slice::{
+		BitSlice,
+		WORD_BITS,
+	},
+	store::BitStore,
+};
+
+mod tests;
+
+#[doc = include_str!("../doc/crc/CrcParams.md")]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CrcParams {
+	/// The width of the CRC register, in bits. This must be in the domain
+	/// `1 ..= 64`.
+	pub width:  usize,
+	/// The generator polynomial, without its leading `x^width` term, in
+	/// normal (unreflected) form.
+	pub poly:   u64,
+	/// The value of the register before any input is processed, in normal
+	/// (unreflected) form.
+	pub init:   u64,
+	/// Whether each byte of a byte buffer is processed least significant bit
+	/// first.
+	pub refin:  bool,
+	/// Whether the register is reflected before it is output.
+	pub refout: bool,
+	/// The value xored into the output.
+	pub xorout: u64,
+}
+
+impl CrcParams {
+	/// CRC-16/ARC, also known as CRC-16 and CRC-16/IBM.
+	pub const CRC_16_ARC: Self = Self {
+		width:  16,
+		poly:   0x8005,
+		init:   0x0000,
+		refin:  true,
+		refout: true,
+		xorout: 0x0000,
+	};
+	/// CRC-16/IBM-3740, also known as CRC-16/CCITT-FALSE.
+	pub const CRC_16_IBM_3740: Self = Self {
+		width:  16,
+		poly:   0x1021,
+		init:   0xFFFF,
+		refin:  false,
+		refout: false,
+		xorout: 0x0000,
+	};
+	/// CRC-16/KERMIT, also known as CRC-16/CCITT.
+	pub const CRC_16_KERMIT: Self = Self {
+		width:  16,
+		poly:   0x1021,
+		init:   0x0000,
+		refin:  true,
+		refout: true,
+		xorout: 0x0000,
+	};
+	/// CRC-32/ISCSI, also known as CRC-32C.
+	pub const CRC_32_ISCSI: Self = Self {
+		width:  32,
+		poly:   0x1EDC_6F41,
+		init:   0xFFFF_FFFF,
+		refin:  true,
+		refout: true,
+		xorout: 0xFFFF_FFFF,
+	};
+	/// CRC-32/ISO-HDLC, the CRC-32 of Ethernet, zlib, and PNG.
+	pub const CRC_32_ISO_HDLC: Self = Self {
+		width:  32,
+		poly:   0x04C1_1DB7,
+		init:   0xFFFF_FFFF,
+		refin:  true,
+		refout: true,
+		xorout: 0xFFFF_FFFF,
+	};
+	/// CRC-64/XZ.
+	pub const CRC_64_XZ: Self = Self {
+		width:  64,
+		poly:   0x42F0_E1EB_A9EA_3693,
+		init:   0xFFFF_FFFF_FFFF_FFFF,
+		refin:  true,
+		refout: true,
+		xorout: 0xFFFF_FFFF_FFFF_FFFF,
+	};
+	/// CRC-8/SMBUS.
+	pub const CRC_8_SMBUS: Self = Self {
+		width:  8,
+		poly:   0x07,
+		init:   0x00,
+		refin:  false,
+		refout: false,
+		xorout: 0x00,
+	};
+}
+
+#[doc = include_str!("../doc/crc/Crc.md")]
+#[derive(Clone)]
+pub struct Crc {
+	/// The parameters of the CRC.
+	params: CrcParams,
+	/// The polynomial, shifted so that its `x^(width - 1)` term is the most
+	/// significant bit of the word.
+	poly:   u64,
+	/// The effect of each possible leading byte of the register on the rest of
+	/// the register, for the shifted polynomial.
+	table:  [u64; 256],
+}
+
+impl Crc {
+	/// Prepares a CRC engine for a set of parameters.
+	///
+	/// This builds a 256-entry lookup table, which allows whole bytes of input
+	/// to be processed at once.
+	///
+	/// ## Panics
+	///
+	/// This panics if `params.width` is not in the domain `1 ..= 64`, or if
+	/// `params.poly`, `params.init`, or `params.xorout` have bits set above
+	/// `params.width`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::crc::{Crc, CrcParams};
+	///
+	/// let crc = Crc::new(CrcParams::CRC_32_ISO_HDLC);
+	/// assert_eq!(crc.checksum_bytes(b"123456789"), 0xCBF4_3926);
+	/// ```
+	#[inline]
+	pub fn new(params: CrcParams) -> Self {
+		let width = params.width;
+		assert!(
+			(1 ..= bits_of::<u64>()).contains(&width),
+			"CRC width {} is not in the domain 1 ..= {}",
+			width,
+			bits_of::<u64>(),
+		);
+		for &(name, value) in &[
+			("polynomial", params.poly),
+			("initial value", params.init),
+			("output xor", params.xorout),
+		] {
+			assert!(
+				value & !mask(width) == 0,
+				"CRC {} {:#x} does not fit in {} bits",
+				name,
+				value,
+				width,
+			);
+		}
+
+		let poly = params.poly << (bits_of::<u64>() - width);
+		let mut table = [0; 256];
+		for (byte, slot) in table.iter_mut().enumerate() {
+			*slot = (0 .. 8).fold((byte as u64) << 56, |reg, _| step(reg, poly));
+		}
+		Self {
+			params,
+			poly,
+			table,
+		}
+	}
+
+	/// Gets the parameters of the CRC.
+	#[inline]
+	pub fn params(&self) -> &CrcParams {
+		&self.params
+	}
+
+	/// Computes the CRC of a bit-slice.
+	///
+	/// The bits are processed in index order, so the first bit of the
+	/// bit-slice is the first bit into the register. The bit-slice may have
+	/// any length.
+	///
+	/// The `refin` parameter describes how bytes are ordered into bits, and so
+	/// does not apply here: the ordering parameter of the bit-slice does that
+	/// instead. A bit-slice over bytes with `Lsb0` ordering produces the same
+	/// CRC as the bytes themselves do with `refin` set, and with `Msb0`
+	/// ordering, the same CRC as the bytes do with `refin` clear.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::crc::{Crc, CrcParams};
+	///
+	/// let crc = Crc::new(CrcParams::CRC_16_KERMIT);
+	/// let frame = &0x1_2345_6789u64.view_bits::<Msb0>()[64 - 37 ..];
+	/// let check = crc.checksum(frame);
+	///
+	/// let mut digest = crc.digest();
+	/// digest.update(&frame[.. 20]);
+	/// digest.update(&frame[20 ..]);
+	/// assert_eq!(digest.finalize(), check);
+	///
+	/// assert_eq!(
+	///   crc.checksum(b"123456789".view_bits::<Lsb0>()),
+	///   crc.checksum_bytes(b"123456789"),
+	/// );
+	/// ```
+	#[inline]
+	pub fn checksum<T, O>(&self, bits: &BitSlice<T, O>) -> u64
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		let mut digest = self.digest();
+		digest.update(bits);
+		digest.finalize()
+	}
+
+	/// Computes the CRC of a byte buffer.
+	///
+	/// Each byte is processed most significant bit first, or least significant
+	/// bit first if the `refin` parameter is set.
+	#[inline]
+	pub fn checksum_bytes(&self, bytes: &[u8]) -> u64 {
+		let mut digest = self.digest();
+		digest.update_bytes(bytes);
+		digest.finalize()
+	}
+
+	/// Begins an incremental CRC computation, which can be fed its input in
+	/// pieces.
+	#[inline]
+	pub fn digest(&self) -> Digest<'_> {
+		Digest {
+			crc: self,
+			reg: self.params.init << (bits_of::<u64>() - self.params.width),
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Debug for Crc {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("Crc")
+			.field("params", &self.params)
+			.finish()
+	}
+}
+
+#[doc = include_str!("../doc/crc/Digest.md")]
+#[derive(Clone, Debug)]
+pub struct Digest<'a> {
+	/// The CRC engine.
+	crc: &'a Crc,
+	/// The register, shifted so that its most significant bit is the most
+	/// significant bit of the word.
+	reg: u64,
+}
+
+impl Digest<'_> {
+	/// Feeds a bit-slice into the CRC, in index order.
+	///
+	/// See [`Crc::checksum`] for how bit-slices are processed.
+	///
+	/// [`Crc::checksum`]: crate::crc::Crc::checksum
+	#[inline]
+	pub fn update<T, O>(&mut self, bits: &BitSlice<T, O>)
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		let mut chunks = bits.chunks_exact(WORD_BITS);
+		for chunk in &mut chunks {
+			//  Move the first bit of the chunk to the top of the word, so that
+			//  its bytes can be fed in from most significant to least.
+			let word = chunk.load_word().reverse_bits();
+			for byte in (0 .. WORD_BITS / 8).rev() {
+				self.byte((word >> (byte * 8)) as u8);
+			}
+		}
+		for bit in chunks.remainder().iter().by_vals() {
+			self.bit(bit);
+		}
+	}
+
+	/// Feeds a byte buffer into the CRC.
+	///
+	/// See [`Crc::checksum_bytes`] for how bytes are processed.
+	///
+	/// [`Crc::checksum_bytes`]: crate::crc::Crc::checksum_bytes
+	#[inline]
+	pub fn update_bytes(&mut self, bytes: &[u8]) {
+		let refin = self.crc.params.refin;
+		for &byte in bytes {
+			self.byte(if refin { byte.reverse_bits() } else { byte });
+		}
+	}
+
+	/// Produces the CRC of all input fed so far.
+	///
+	/// This does not consume the digest, which can continue to be fed.
+	#[inline]
+	pub fn finalize(&self) -> u64 {
+		let params = &self.crc.params;
+		let out = if params.refout {
+			self.reg.reverse_bits()
+		}
+		else {
+			self.reg >> (bits_of::<u64>() - params.width)
+		};
+		out ^ params.xorout
+	}
+
+	/// Feeds one bit into the register.
+	fn bit(&mut self, bit: bool) {
+		self.reg = step(self.reg ^ ((bit as u64) << 63), self.crc.poly);
+	}
+
+	/// Feeds eight bits into the register, most significant first.
+	fn byte(&mut self, byte: u8) {
+		let idx = ((self.reg >> 56) as u8 ^ byte) as usize;
+		self.reg = (self.reg << 8) ^ self.crc.table[idx];
+	}
+}
+
+/// Shifts a register one bit, dividing out the polynomial if the bit shifted
+/// out is `1`.
+fn step(reg: u64, poly: u64) -> u64 {
+	if reg >> 63 == 1 {
+		(reg << 1) ^ poly
+	}
+	else {
+		reg << 1
+	}
+}
+
+/// Produces a mask over the low `width` bits of a `u64`.
+fn mask(width: usize) -> u64 {
+	!0 >> (bits_of::<u64>() - width)
+}
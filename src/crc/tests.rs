@@ -0,0 +1,164 @@
+//! Unit tests for the CRC engine.
+
+#![cfg(test)]
+
+use rand::random;
+
+use super::*;
+use crate::{
+	order::HiLo,
+	prelude::*,
+};
+
+/// Computes a CRC one bit at a time, directly from the Rocksoft model.
+fn reference<T, O>(params: &CrcParams, bits: &BitSlice<T, O>) -> u64
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let top = 1 << (params.width - 1);
+	let mut reg = params.init;
+	for bit in bits.iter().by_vals() {
+		let out = (reg & top != 0) ^ bit;
+		reg = (reg << 1) & mask(params.width);
+		if out {
+			reg ^= params.poly;
+		}
+	}
+	if params.refout {
+		reg = reg.reverse_bits() >> (64 - params.width);
+	}
+	reg ^ params.xorout
+}
+
+#[test]
+fn catalogue() {
+	let check = b"123456789";
+	for &(params, expected) in &[
+		(CrcParams::CRC_8_SMBUS, 0xF4),
+		(CrcParams::CRC_16_ARC, 0xBB3D),
+		(CrcParams::CRC_16_IBM_3740, 0x29B1),
+		(CrcParams::CRC_16_KERMIT, 0x2189),
+		(CrcParams::CRC_32_ISO_HDLC, 0xCBF4_3926),
+		(CrcParams::CRC_32_ISCSI, 0xE306_9283),
+		(CrcParams::CRC_64_XZ, 0x995D_C9BB_DF19_39FA),
+		//  CRC-3/GSM
+		(
+			CrcParams {
+				width:  3,
+				poly:   0x3,
+				init:   0x0,
+				refin:  false,
+				refout: false,
+				xorout: 0x7,
+			},
+			0x4,
+		),
+		//  CRC-7/MMC
+		(
+			CrcParams {
+				width:  7,
+				poly:   0x09,
+				init:   0x00,
+				refin:  false,
+				refout: false,
+				xorout: 0x00,
+			},
+			0x75,
+		),
+		//  CRC-16/RIELLO, whose initial value is not a palindrome.
+		(
+			CrcParams {
+				width:  16,
+				poly:   0x1021,
+				init:   0xB2AA,
+				refin:  true,
+				refout: true,
+				xorout: 0x0000,
+			},
+			0x63D0,
+		),
+		//  CRC-64/ECMA-182
+		(
+			CrcParams {
+				width:  64,
+				poly:   0x42F0_E1EB_A9EA_3693,
+				init:   0,
+				refin:  false,
+				refout: false,
+				xorout: 0,
+			},
+			0x6C40_DF5F_0B49_7347,
+		),
+	] {
+		let crc = Crc::new(params);
+		assert_eq!(crc.checksum_bytes(check), expected, "{:?}", params);
+		let bits = if params.refin {
+			crc.checksum(check.view_bits::<Lsb0>())
+		}
+		else {
+			crc.checksum(check.view_bits::<Msb0>())
+		};
+		assert_eq!(bits, expected, "{:?}", params);
+	}
+}
+
+#[test]
+fn arbitrary_lengths() {
+	fn exercise<T, O>(params: CrcParams)
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		let crc = Crc::new(params);
+		let mut bits = BitVec::<T, O>::repeat(false, 300);
+		for mut bit in bits.iter_mut() {
+			*bit = random();
+		}
+		for len in (0 .. 300).filter(|len| len % 13 < 3) {
+			let frame = &bits[len % 7 .. len];
+			let expected = reference(&params, frame);
+			assert_eq!(crc.checksum(frame), expected, "{:?} {}", params, len);
+
+			let split = random::<usize>() % (frame.len() + 1);
+			let mut digest = crc.digest();
+			digest.update(&frame[.. split]);
+			digest.update(&frame[split ..]);
+			assert_eq!(digest.finalize(), expected);
+		}
+	}
+
+	for _ in 0 .. 4 {
+		let width = 1 + random::<usize>() % 64;
+		let params = CrcParams {
+			width,
+			poly: random::<u64>() & mask(width),
+			init: random::<u64>() & mask(width),
+			refin: random(),
+			refout: random(),
+			xorout: random::<u64>() & mask(width),
+		};
+		exercise::<u8, Lsb0>(params);
+		exercise::<u16, Msb0>(params);
+		exercise::<u32, HiLo>(params);
+		exercise::<usize, Msb0>(params);
+	}
+}
+
+#[test]
+#[should_panic = "CRC width 65 is not in the domain 1 ..= 64"]
+fn too_wide() {
+	Crc::new(CrcParams {
+		width: 65,
+		..CrcParams::CRC_64_XZ
+	});
+}
+
+#[test]
+#[should_panic = "CRC polynomial 0x107 does not fit in 8 bits"]
+fn poly_too_wide() {
+	Crc::new(CrcParams {
+		poly: 0x107,
+		..CrcParams::CRC_8_SMBUS
+	});
+}
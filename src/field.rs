@@ -1,6 +1,11 @@
 #![doc = include_str!("../doc/field.md")]
 
 use core::{
+	fmt::{
+		self,
+		Display,
+		Formatter,
+	},
 	mem,
 	ptr,
 };
@@ -35,6 +40,9 @@ use crate::{
 	vec::BitVec,
 };
 
+mod checked;
+mod extend;
+mod float;
 mod io;
 mod tests;
 
@@ -109,6 +117,58 @@ pub trait BitField {
 	where I: Integral;
 }
 
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[doc = include_str!("../doc/field/FieldError.md")]
+pub enum FieldError {
+	/// The value cannot be represented in the bit-field.
+	Overflow {
+		/// The width of the bit-field.
+		width: usize,
+	},
+	/// The bit-field is empty, or wider than the integer being transferred.
+	Width {
+		/// The width of the bit-field.
+		requested: usize,
+		/// The width of the integer.
+		max:       usize,
+	},
+}
+
+impl FieldError {
+	/// Checks that a bit-field of width `len` can transfer an `I` integer.
+	pub(crate) fn check_width<I>(len: usize) -> Result<(), Self>
+	where I: Integral {
+		let max = bits_of::<I>();
+		if !(1 ..= max).contains(&len) {
+			return Err(Self::Width {
+				requested: len,
+				max,
+			});
+		}
+		Ok(())
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Display for FieldError {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::Overflow { width } => {
+				write!(fmt, "the value does not fit in a {}-bit field", width)
+			},
+			Self::Width { requested, max } => write!(
+				fmt,
+				"cannot transfer a {}-bit field through a {}-bit integer",
+				requested, max,
+			),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FieldError {}
+
 #[doc = include_str!("../doc/field/BitField_Lsb0.md")]
 impl<T> BitField for BitSlice<T, Lsb0>
 where T: BitStore
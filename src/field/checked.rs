@@ -0,0 +1,136 @@
+#![doc = include_str!("../../doc/field/checked.md")]
+
+use funty::Integral;
+
+use super::{
+	BitField,
+	FieldError,
+};
+use crate::{
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+/// Fallible transfers.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	/// Loads a value out of the bit-slice with [`.load_le()`], if the
+	/// bit-slice has a width that `I` can hold.
+	///
+	/// ## Errors
+	///
+	/// This fails if the bit-slice is empty, or wider than `I`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::FieldError;
+	///
+	/// let data = 0x1234u16;
+	/// let bits = data.view_bits::<Lsb0>();
+	/// assert_eq!(bits[.. 12].try_load_le::<u16>(), Ok(0x234));
+	/// assert_eq!(
+	///   bits.try_load_le::<u8>(),
+	///   Err(FieldError::Width { requested: 16, max: 8 }),
+	/// );
+	/// assert!(bits[.. 0].try_load_le::<u8>().is_err());
+	/// ```
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	#[inline]
+	pub fn try_load_le<I>(&self) -> Result<I, FieldError>
+	where I: Integral {
+		FieldError::check_width::<I>(self.len())?;
+		Ok(self.load_le::<I>())
+	}
+
+	/// Loads a value out of the bit-slice with [`.load_be()`], if the
+	/// bit-slice has a width that `I` can hold.
+	///
+	/// ## Errors
+	///
+	/// This fails if the bit-slice is empty, or wider than `I`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0x12u8, 0x34];
+	/// let bits = data.view_bits::<Msb0>();
+	/// assert_eq!(bits[4 ..].try_load_be::<u16>(), Ok(0x234));
+	/// assert!(bits.try_load_be::<u8>().is_err());
+	/// ```
+	///
+	/// [`.load_be()`]: crate::field::BitField::load_be
+	#[inline]
+	pub fn try_load_be<I>(&self) -> Result<I, FieldError>
+	where I: Integral {
+		FieldError::check_width::<I>(self.len())?;
+		Ok(self.load_be::<I>())
+	}
+
+	/// Stores a value into the bit-slice with [`.store_le()`], if the
+	/// bit-slice has a width that `I` can fill.
+	///
+	/// ## Errors
+	///
+	/// This fails, and leaves the bit-slice unmodified, if the bit-slice is
+	/// empty, or wider than `I`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u16;
+	/// let bits = data.view_bits_mut::<Lsb0>();
+	/// assert!(bits[4 ..].try_store_le(0x123u16).is_ok());
+	/// assert!(bits.try_store_le(0xFFu8).is_err());
+	/// assert_eq!(data, 0x1230);
+	/// ```
+	///
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	#[inline]
+	pub fn try_store_le<I>(&mut self, value: I) -> Result<(), FieldError>
+	where I: Integral {
+		FieldError::check_width::<I>(self.len())?;
+		self.store_le(value);
+		Ok(())
+	}
+
+	/// Stores a value into the bit-slice with [`.store_be()`], if the
+	/// bit-slice has a width that `I` can fill.
+	///
+	/// ## Errors
+	///
+	/// This fails, and leaves the bit-slice unmodified, if the bit-slice is
+	/// empty, or wider than `I`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 2];
+	/// let bits = data.view_bits_mut::<Msb0>();
+	/// assert!(bits[.. 12].try_store_be(0x123u16).is_ok());
+	/// assert!(bits[.. 0].try_store_be(0u16).is_err());
+	/// assert_eq!(data, [0x12, 0x30]);
+	/// ```
+	///
+	/// [`.store_be()`]: crate::field::BitField::store_be
+	#[inline]
+	pub fn try_store_be<I>(&mut self, value: I) -> Result<(), FieldError>
+	where I: Integral {
+		FieldError::check_width::<I>(self.len())?;
+		self.store_be(value);
+		Ok(())
+	}
+}
@@ -0,0 +1,288 @@
+#![doc = include_str!("../../doc/field/extend.md")]
+
+use funty::Integral;
+
+use super::{
+	check,
+	BitField,
+	FieldError,
+};
+use crate::{
+	mem::bits_of,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+/// Explicitly-extended loads and checked stores.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	/// Loads a two’s-complement value out of the bit-slice, with
+	/// [`.load_le()`], and sign-extends it into `I`.
+	///
+	/// Unlike `.load_le()`, the sign extension does not depend on whether `I`
+	/// is a signed integer: the most significant bit of the bit-slice is
+	/// always copied into all the high bits of the result.
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is empty, or wider than `I`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = 0x0500u16;
+	/// let bits = &data.view_bits::<Lsb0>()[.. 11];
+	/// assert_eq!(bits.load_signed_le::<i16>(), -768);
+	/// assert_eq!(bits.load_signed_le::<u16>(), 0xFD00);
+	/// ```
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	#[inline]
+	pub fn load_signed_le<I>(&self) -> I
+	where I: Integral {
+		extend(self.load_le::<I>(), self.len(), true)
+	}
+
+	/// Loads a two’s-complement value out of the bit-slice, with
+	/// [`.load_be()`], and sign-extends it into `I`.
+	///
+	/// Unlike `.load_be()`, the sign extension does not depend on whether `I`
+	/// is a signed integer: the most significant bit of the bit-slice is
+	/// always copied into all the high bits of the result.
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is empty, or wider than `I`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = 0xA000u16;
+	/// let bits = &data.view_bits::<Msb0>()[.. 11];
+	/// assert_eq!(bits.load_signed_be::<i16>(), -768);
+	/// assert_eq!(bits.load_signed_be::<u32>(), 0xFFFF_FD00);
+	/// ```
+	///
+	/// [`.load_be()`]: crate::field::BitField::load_be
+	#[inline]
+	pub fn load_signed_be<I>(&self) -> I
+	where I: Integral {
+		extend(self.load_be::<I>(), self.len(), true)
+	}
+
+	/// Loads an unsigned value out of the bit-slice, with [`.load_le()`], and
+	/// zero-extends it into `I`.
+	///
+	/// Unlike `.load_le()`, the high bits of the result are always cleared,
+	/// even when `I` is a signed integer.
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is empty, or wider than `I`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = 0x0500u16;
+	/// let bits = &data.view_bits::<Lsb0>()[.. 11];
+	/// assert_eq!(bits.load_le::<i16>(), -768);
+	/// assert_eq!(bits.load_unsigned_le::<i16>(), 1280);
+	/// ```
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	#[inline]
+	pub fn load_unsigned_le<I>(&self) -> I
+	where I: Integral {
+		extend(self.load_le::<I>(), self.len(), false)
+	}
+
+	/// Loads an unsigned value out of the bit-slice, with [`.load_be()`], and
+	/// zero-extends it into `I`.
+	///
+	/// Unlike `.load_be()`, the high bits of the result are always cleared,
+	/// even when `I` is a signed integer.
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is empty, or wider than `I`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = 0xA000u16;
+	/// let bits = &data.view_bits::<Msb0>()[.. 11];
+	/// assert_eq!(bits.load_be::<i16>(), -768);
+	/// assert_eq!(bits.load_unsigned_be::<i16>(), 1280);
+	/// ```
+	///
+	/// [`.load_be()`]: crate::field::BitField::load_be
+	#[inline]
+	pub fn load_unsigned_be<I>(&self) -> I
+	where I: Integral {
+		extend(self.load_be::<I>(), self.len(), false)
+	}
+
+	/// Stores a value into the bit-slice, with [`.store_le()`], if it can be
+	/// represented as a two’s-complement integer of the bit-slice’s width.
+	///
+	/// When `I` is an unsigned integer, only its values below `2^(n - 1)` fit.
+	///
+	/// ## Errors
+	///
+	/// This fails, and leaves the bit-slice unmodified, if the value is outside
+	/// the range `-2^(n - 1) .. 2^(n - 1)`, where `n` is the bit-slice’s width.
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is empty, or wider than `I`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u8;
+	/// let bits = &mut data.view_bits_mut::<Lsb0>()[.. 4];
+	/// assert!(bits.store_signed_le(-8i8).is_ok());
+	/// assert!(bits.store_signed_le(8i8).is_err());
+	/// assert_eq!(bits.load_signed_le::<i8>(), -8);
+	/// ```
+	///
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	#[inline]
+	pub fn store_signed_le<I>(&mut self, value: I) -> Result<(), FieldError>
+	where I: Integral {
+		self.check_fit(value, true)?;
+		self.store_le(value);
+		Ok(())
+	}
+
+	/// Stores a value into the bit-slice, with [`.store_be()`], if it can be
+	/// represented as a two’s-complement integer of the bit-slice’s width.
+	///
+	/// When `I` is an unsigned integer, only its values below `2^(n - 1)` fit.
+	///
+	/// ## Errors
+	///
+	/// This fails, and leaves the bit-slice unmodified, if the value is outside
+	/// the range `-2^(n - 1) .. 2^(n - 1)`, where `n` is the bit-slice’s width.
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is empty, or wider than `I`.
+	///
+	/// [`.store_be()`]: crate::field::BitField::store_be
+	#[inline]
+	pub fn store_signed_be<I>(&mut self, value: I) -> Result<(), FieldError>
+	where I: Integral {
+		self.check_fit(value, true)?;
+		self.store_be(value);
+		Ok(())
+	}
+
+	/// Stores a value into the bit-slice, with [`.store_le()`], if it can be
+	/// represented as an unsigned integer of the bit-slice’s width.
+	///
+	/// ## Errors
+	///
+	/// This fails, and leaves the bit-slice unmodified, if the value is
+	/// negative or is not less than `2^n`, where `n` is the bit-slice’s width.
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is empty, or wider than `I`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u8;
+	/// let bits = &mut data.view_bits_mut::<Lsb0>()[.. 4];
+	/// assert!(bits.store_unsigned_le(15u8).is_ok());
+	/// assert!(bits.store_unsigned_le(16u8).is_err());
+	/// assert!(bits.store_unsigned_le(-1i8).is_err());
+	/// assert_eq!(data, 15);
+	/// ```
+	///
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	#[inline]
+	pub fn store_unsigned_le<I>(&mut self, value: I) -> Result<(), FieldError>
+	where I: Integral {
+		self.check_fit(value, false)?;
+		self.store_le(value);
+		Ok(())
+	}
+
+	/// Stores a value into the bit-slice, with [`.store_be()`], if it can be
+	/// represented as an unsigned integer of the bit-slice’s width.
+	///
+	/// ## Errors
+	///
+	/// This fails, and leaves the bit-slice unmodified, if the value is
+	/// negative or is not less than `2^n`, where `n` is the bit-slice’s width.
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is empty, or wider than `I`.
+	///
+	/// [`.store_be()`]: crate::field::BitField::store_be
+	#[inline]
+	pub fn store_unsigned_be<I>(&mut self, value: I) -> Result<(), FieldError>
+	where I: Integral {
+		self.check_fit(value, false)?;
+		self.store_be(value);
+		Ok(())
+	}
+
+	/// Tests that a value survives truncation to the bit-slice’s width, and
+	/// re-extension back to `I`.
+	fn check_fit<I>(&self, value: I, signed: bool) -> Result<(), FieldError>
+	where I: Integral {
+		let width = self.len();
+		check::<I>("store", width);
+		//  A full-width field keeps every value through the truncation, so the
+		//  sign of `value` must be checked separately.
+		let negative = if signed && I::MIN == I::ZERO {
+			value >> (bits_of::<I>() - 1) != I::ZERO
+		}
+		else {
+			!signed && value < I::ZERO
+		};
+		if negative || extend(value, width, signed) != value {
+			return Err(FieldError::Overflow { width });
+		}
+		Ok(())
+	}
+}
+
+/// Truncates a value to its low `width` bits, then sign- or zero-extends it
+/// back to the full width of `I`.
+///
+/// `width` must be in the domain `1 ..= I::BITS`.
+fn extend<I>(value: I, width: usize, signed: bool) -> I
+where I: Integral {
+	if width == bits_of::<I>() {
+		return value;
+	}
+	let high = !I::ZERO << width;
+	if signed && (value >> (width - 1)) & I::ONE == I::ONE {
+		value | high
+	}
+	else {
+		value & !high
+	}
+}
@@ -0,0 +1,544 @@
+#![doc = include_str!("../../doc/field/float.md")]
+
+use core::convert::TryFrom;
+
+use super::{
+	BitField,
+	FieldError,
+};
+use crate::{
+	mem::bits_of,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+/// Floating-point transfers.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	/// Loads an `f32` out of the bit-slice, using [`.load::<u32>()`] to read
+	/// its bit-pattern.
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is not exactly 32 bits wide.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bits = bitarr![u8, Lsb0; 0; 40];
+	/// bits[3 .. 35].store_f32(1.5);
+	/// assert_eq!(bits[3 .. 35].load_f32(), 1.5);
+	/// ```
+	///
+	/// [`.load::<u32>()`]: crate::field::BitField::load
+	#[inline]
+	pub fn load_f32(&self) -> f32 {
+		check_float::<u32>("load", self.len());
+		f32::from_bits(self.load::<u32>())
+	}
+
+	/// Loads an `f32` out of the bit-slice, using [`.load_le::<u32>()`] to
+	/// read its bit-pattern.
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is not exactly 32 bits wide.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = 1.5f32.to_bits().to_le_bytes();
+	/// assert_eq!(data.view_bits::<Lsb0>().load_f32_le(), 1.5);
+	/// ```
+	///
+	/// [`.load_le::<u32>()`]: crate::field::BitField::load_le
+	#[inline]
+	pub fn load_f32_le(&self) -> f32 {
+		check_float::<u32>("load", self.len());
+		f32::from_bits(self.load_le::<u32>())
+	}
+
+	/// Loads an `f32` out of the bit-slice, using [`.load_be::<u32>()`] to
+	/// read its bit-pattern.
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is not exactly 32 bits wide.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = (-0.25f32).to_bits().to_be_bytes();
+	/// assert_eq!(data.view_bits::<Msb0>().load_f32_be(), -0.25);
+	/// ```
+	///
+	/// [`.load_be::<u32>()`]: crate::field::BitField::load_be
+	#[inline]
+	pub fn load_f32_be(&self) -> f32 {
+		check_float::<u32>("load", self.len());
+		f32::from_bits(self.load_be::<u32>())
+	}
+
+	/// Loads an `f64` out of the bit-slice, using [`.load::<u64>()`] to read
+	/// its bit-pattern.
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is not exactly 64 bits wide.
+	///
+	/// [`.load::<u64>()`]: crate::field::BitField::load
+	#[inline]
+	pub fn load_f64(&self) -> f64 {
+		check_float::<u64>("load", self.len());
+		f64::from_bits(self.load::<u64>())
+	}
+
+	/// Loads an `f64` out of the bit-slice, using [`.load_le::<u64>()`] to
+	/// read its bit-pattern.
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is not exactly 64 bits wide.
+	///
+	/// [`.load_le::<u64>()`]: crate::field::BitField::load_le
+	#[inline]
+	pub fn load_f64_le(&self) -> f64 {
+		check_float::<u64>("load", self.len());
+		f64::from_bits(self.load_le::<u64>())
+	}
+
+	/// Loads an `f64` out of the bit-slice, using [`.load_be::<u64>()`] to
+	/// read its bit-pattern.
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is not exactly 64 bits wide.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = core::f64::consts::PI.to_bits().to_be_bytes();
+	/// assert_eq!(
+	///   data.view_bits::<Msb0>().load_f64_be(),
+	///   core::f64::consts::PI,
+	/// );
+	/// ```
+	///
+	/// [`.load_be::<u64>()`]: crate::field::BitField::load_be
+	#[inline]
+	pub fn load_f64_be(&self) -> f64 {
+		check_float::<u64>("load", self.len());
+		f64::from_bits(self.load_be::<u64>())
+	}
+
+	/// Stores the bit-pattern of an `f32` into the bit-slice, using
+	/// [`.store::<u32>()`].
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is not exactly 32 bits wide.
+	///
+	/// [`.store::<u32>()`]: crate::field::BitField::store
+	#[inline]
+	pub fn store_f32(&mut self, value: f32) {
+		check_float::<u32>("store", self.len());
+		self.store::<u32>(value.to_bits());
+	}
+
+	/// Stores the bit-pattern of an `f32` into the bit-slice, using
+	/// [`.store_le::<u32>()`].
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is not exactly 32 bits wide.
+	///
+	/// [`.store_le::<u32>()`]: crate::field::BitField::store_le
+	#[inline]
+	pub fn store_f32_le(&mut self, value: f32) {
+		check_float::<u32>("store", self.len());
+		self.store_le::<u32>(value.to_bits());
+	}
+
+	/// Stores the bit-pattern of an `f32` into the bit-slice, using
+	/// [`.store_be::<u32>()`].
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is not exactly 32 bits wide.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 4];
+	/// data.view_bits_mut::<Msb0>().store_f32_be(-0.25);
+	/// assert_eq!(data, (-0.25f32).to_bits().to_be_bytes());
+	/// ```
+	///
+	/// [`.store_be::<u32>()`]: crate::field::BitField::store_be
+	#[inline]
+	pub fn store_f32_be(&mut self, value: f32) {
+		check_float::<u32>("store", self.len());
+		self.store_be::<u32>(value.to_bits());
+	}
+
+	/// Stores the bit-pattern of an `f64` into the bit-slice, using
+	/// [`.store::<u64>()`].
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is not exactly 64 bits wide.
+	///
+	/// [`.store::<u64>()`]: crate::field::BitField::store
+	#[inline]
+	pub fn store_f64(&mut self, value: f64) {
+		check_float::<u64>("store", self.len());
+		self.store::<u64>(value.to_bits());
+	}
+
+	/// Stores the bit-pattern of an `f64` into the bit-slice, using
+	/// [`.store_le::<u64>()`].
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is not exactly 64 bits wide.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u16; 4];
+	/// data.view_bits_mut::<Lsb0>().store_f64_le(f64::NAN);
+	/// assert!(data.view_bits::<Lsb0>().load_f64_le().is_nan());
+	/// ```
+	///
+	/// [`.store_le::<u64>()`]: crate::field::BitField::store_le
+	#[inline]
+	pub fn store_f64_le(&mut self, value: f64) {
+		check_float::<u64>("store", self.len());
+		self.store_le::<u64>(value.to_bits());
+	}
+
+	/// Stores the bit-pattern of an `f64` into the bit-slice, using
+	/// [`.store_be::<u64>()`].
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is not exactly 64 bits wide.
+	///
+	/// [`.store_be::<u64>()`]: crate::field::BitField::store_be
+	#[inline]
+	pub fn store_f64_be(&mut self, value: f64) {
+		check_float::<u64>("store", self.len());
+		self.store_be::<u64>(value.to_bits());
+	}
+}
+
+/// Fixed-point transfers.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	/// Loads a two’s-complement fixed-point number with `frac` fractional bits
+	/// out of the bit-slice, using [`.load_signed_le()`].
+	///
+	/// The raw field is divided by `2^frac`. Fields wider than 53 bits may
+	/// lose precision in the conversion to `f64`.
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is empty or wider than 64 bits, or if
+	/// `frac` is greater than 1022.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u16;
+	/// let bits = &mut data.view_bits_mut::<Lsb0>()[.. 12];
+	/// bits.store_signed_fixed_le(-2.75, 4).unwrap();
+	/// assert_eq!(bits.load_signed_le::<i16>(), -44);
+	/// assert_eq!(bits.load_signed_fixed_le(4), -2.75);
+	/// ```
+	///
+	/// [`.load_signed_le()`]: Self::load_signed_le
+	#[inline]
+	pub fn load_signed_fixed_le(&self, frac: u32) -> f64 {
+		self.load_signed_le::<i64>() as f64 * exp2(-frac_exp(frac))
+	}
+
+	/// Loads a two’s-complement fixed-point number with `frac` fractional bits
+	/// out of the bit-slice, using [`.load_signed_be()`].
+	///
+	/// The raw field is divided by `2^frac`. Fields wider than 53 bits may
+	/// lose precision in the conversion to `f64`.
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is empty or wider than 64 bits, or if
+	/// `frac` is greater than 1022.
+	///
+	/// [`.load_signed_be()`]: Self::load_signed_be
+	#[inline]
+	pub fn load_signed_fixed_be(&self, frac: u32) -> f64 {
+		self.load_signed_be::<i64>() as f64 * exp2(-frac_exp(frac))
+	}
+
+	/// Loads an unsigned fixed-point number with `frac` fractional bits out of
+	/// the bit-slice, using [`.load_unsigned_le()`].
+	///
+	/// The raw field is divided by `2^frac`. Fields wider than 53 bits may
+	/// lose precision in the conversion to `f64`.
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is empty or wider than 64 bits, or if
+	/// `frac` is greater than 1022.
+	///
+	/// [`.load_unsigned_le()`]: Self::load_unsigned_le
+	#[inline]
+	pub fn load_unsigned_fixed_le(&self, frac: u32) -> f64 {
+		self.load_unsigned_le::<u64>() as f64 * exp2(-frac_exp(frac))
+	}
+
+	/// Loads an unsigned fixed-point number with `frac` fractional bits out of
+	/// the bit-slice, using [`.load_unsigned_be()`].
+	///
+	/// The raw field is divided by `2^frac`. Fields wider than 53 bits may
+	/// lose precision in the conversion to `f64`.
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is empty or wider than 64 bits, or if
+	/// `frac` is greater than 1022.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0b1010_0110u8];
+	/// let bits = &data.view_bits::<Msb0>()[.. 6];
+	/// assert_eq!(bits.load_unsigned_fixed_be(2), 10.25);
+	/// ```
+	///
+	/// [`.load_unsigned_be()`]: Self::load_unsigned_be
+	#[inline]
+	pub fn load_unsigned_fixed_be(&self, frac: u32) -> f64 {
+		self.load_unsigned_be::<u64>() as f64 * exp2(-frac_exp(frac))
+	}
+
+	/// Stores a number into the bit-slice as a two’s-complement fixed-point
+	/// number with `frac` fractional bits, using [`.store_signed_le()`].
+	///
+	/// The number is multiplied by `2^frac` and rounded to the nearest
+	/// integer, with ties rounding away from zero.
+	///
+	/// ## Errors
+	///
+	/// This fails, and leaves the bit-slice unmodified, if the rounded number
+	/// does not fit in the bit-slice, or if it is not a number.
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is empty or wider than 64 bits, or if
+	/// `frac` is greater than 1022.
+	///
+	/// [`.store_signed_le()`]: Self::store_signed_le
+	#[inline]
+	pub fn store_signed_fixed_le(
+		&mut self,
+		value: f64,
+		frac: u32,
+	) -> Result<(), FieldError> {
+		let raw = self.fixed_signed(value, frac)?;
+		self.store_signed_le(raw)
+	}
+
+	/// Stores a number into the bit-slice as a two’s-complement fixed-point
+	/// number with `frac` fractional bits, using [`.store_signed_be()`].
+	///
+	/// The number is multiplied by `2^frac` and rounded to the nearest
+	/// integer, with ties rounding away from zero.
+	///
+	/// ## Errors
+	///
+	/// This fails, and leaves the bit-slice unmodified, if the rounded number
+	/// does not fit in the bit-slice, or if it is not a number.
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is empty or wider than 64 bits, or if
+	/// `frac` is greater than 1022.
+	///
+	/// [`.store_signed_be()`]: Self::store_signed_be
+	#[inline]
+	pub fn store_signed_fixed_be(
+		&mut self,
+		value: f64,
+		frac: u32,
+	) -> Result<(), FieldError> {
+		let raw = self.fixed_signed(value, frac)?;
+		self.store_signed_be(raw)
+	}
+
+	/// Stores a number into the bit-slice as an unsigned fixed-point number
+	/// with `frac` fractional bits, using [`.store_unsigned_le()`].
+	///
+	/// The number is multiplied by `2^frac` and rounded to the nearest
+	/// integer, with ties rounding away from zero.
+	///
+	/// ## Errors
+	///
+	/// This fails, and leaves the bit-slice unmodified, if the rounded number
+	/// is negative, does not fit in the bit-slice, or is not a number.
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is empty or wider than 64 bits, or if
+	/// `frac` is greater than 1022.
+	///
+	/// [`.store_unsigned_le()`]: Self::store_unsigned_le
+	#[inline]
+	pub fn store_unsigned_fixed_le(
+		&mut self,
+		value: f64,
+		frac: u32,
+	) -> Result<(), FieldError> {
+		let raw = self.fixed_unsigned(value, frac)?;
+		self.store_unsigned_le(raw)
+	}
+
+	/// Stores a number into the bit-slice as an unsigned fixed-point number
+	/// with `frac` fractional bits, using [`.store_unsigned_be()`].
+	///
+	/// The number is multiplied by `2^frac` and rounded to the nearest
+	/// integer, with ties rounding away from zero.
+	///
+	/// ## Errors
+	///
+	/// This fails, and leaves the bit-slice unmodified, if the rounded number
+	/// is negative, does not fit in the bit-slice, or is not a number.
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is empty or wider than 64 bits, or if
+	/// `frac` is greater than 1022.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u8;
+	/// let bits = &mut data.view_bits_mut::<Msb0>()[.. 6];
+	/// bits.store_unsigned_fixed_be(10.2, 2).unwrap();
+	/// assert!(bits.store_unsigned_fixed_be(16.0, 2).is_err());
+	/// assert!(bits.store_unsigned_fixed_be(-1.0, 2).is_err());
+	/// assert_eq!(data, 0b1010_0100);
+	/// ```
+	///
+	/// [`.store_unsigned_be()`]: Self::store_unsigned_be
+	#[inline]
+	pub fn store_unsigned_fixed_be(
+		&mut self,
+		value: f64,
+		frac: u32,
+	) -> Result<(), FieldError> {
+		let raw = self.fixed_unsigned(value, frac)?;
+		self.store_unsigned_be(raw)
+	}
+
+	/// Scales and rounds a number into a signed raw fixed-point value.
+	fn fixed_signed(&self, value: f64, frac: u32) -> Result<i64, FieldError> {
+		let scaled = round(value * exp2(frac_exp(frac)));
+		//  `i64::MIN` is exactly representable; `i64::MAX` is not.
+		let limit = exp2(bits_of::<i64>() as i32 - 1);
+		if (-limit .. limit).contains(&scaled) {
+			Ok(scaled as i64)
+		}
+		else {
+			Err(FieldError::Overflow { width: self.len() })
+		}
+	}
+
+	/// Scales and rounds a number into an unsigned raw fixed-point value.
+	fn fixed_unsigned(&self, value: f64, frac: u32) -> Result<u64, FieldError> {
+		let scaled = round(value * exp2(frac_exp(frac)));
+		let limit = exp2(bits_of::<u64>() as i32);
+		if (0.0 .. limit).contains(&scaled) {
+			Ok(scaled as u64)
+		}
+		else {
+			Err(FieldError::Overflow { width: self.len() })
+		}
+	}
+}
+
+/// Asserts that a bit-slice is exactly as wide as the raw bit-pattern of a
+/// floating-point number.
+fn check_float<R>(action: &'static str, len: usize) {
+	let width = bits_of::<R>();
+	assert_eq!(
+		len, width,
+		"cannot {} a {}-bit float using a {}-bit region",
+		action, width, len,
+	);
+}
+
+/// Computes `2^exp` exactly, by constructing its bit-pattern.
+///
+/// `exp` must be a normal exponent, in the domain `-1022 ..= 1023`.
+fn exp2(exp: i32) -> f64 {
+	assert!(
+		(-1022 ..= 1023).contains(&exp),
+		"fixed-point scale 2^{} is out of range",
+		exp,
+	);
+	f64::from_bits(((exp + 1023) as u64) << 52)
+}
+
+/// Converts a count of fractional bits into a binary exponent.
+fn frac_exp(frac: u32) -> i32 {
+	i32::try_from(frac).unwrap_or(i32::MAX)
+}
+
+/// Rounds a number to the nearest integer, with ties rounding away from zero.
+///
+/// This is `f64::round`, which is not available in `core`.
+fn round(value: f64) -> f64 {
+	//  Every `f64` of this magnitude or greater is already an integer.
+	let limit = exp2(52);
+	if !(-limit < value && value < limit) {
+		return value;
+	}
+	let trunc = value as i64 as f64;
+	let diff = value - trunc;
+	if diff >= 0.5 {
+		trunc + 1.0
+	}
+	else if diff <= -0.5 {
+		trunc - 1.0
+	}
+	else {
+		trunc
+	}
+}
@@ -5,6 +5,7 @@ use std::io;
 
 use rand::prelude::*;
 
+use super::FieldError;
 use crate::prelude::*;
 
 #[test]
@@ -313,3 +314,186 @@ fn write_bits() {
 
 	assert_eq!(data, [0b1010_0000, 0b1011_0101, 0b0011_0100, 0b0000_1100]);
 }
+
+#[test]
+fn explicit_extension() {
+	let mut data = [0u16; 2];
+
+	for width in 1 ..= 16 {
+		let value = random::<i16>() >> (16 - width);
+		let unsigned = (value as u16) & (!0u16 >> (16 - width));
+
+		let bits = &mut data.view_bits_mut::<Lsb0>()[5 .. 5 + width];
+		bits.store_signed_le(value).unwrap();
+		assert_eq!(bits.load_signed_le::<i16>(), value);
+		assert_eq!(bits.load_signed_le::<u16>(), value as u16);
+		assert_eq!(bits.load_signed_le::<i32>(), value as i32);
+		assert_eq!(bits.load_unsigned_le::<i16>(), unsigned as i16);
+		assert_eq!(bits.load_unsigned_le::<u32>(), unsigned as u32);
+		bits.store_unsigned_le(unsigned).unwrap();
+		assert_eq!(bits.load_unsigned_le::<u16>(), unsigned);
+
+		let bits = &mut data.view_bits_mut::<Msb0>()[9 .. 9 + width];
+		bits.store_signed_be(value as i32).unwrap();
+		assert_eq!(bits.load_signed_be::<i64>(), value as i64);
+		assert_eq!(bits.load_unsigned_be::<u16>(), unsigned);
+		bits.store_unsigned_be(unsigned as i32).unwrap();
+		assert_eq!(bits.load_signed_be::<i16>(), value);
+	}
+}
+
+#[test]
+fn checked_stores() {
+	let mut data = 0u32;
+	let bits = &mut data.view_bits_mut::<Msb0>()[4 .. 9];
+
+	assert!(bits.store_signed_be(15i8).is_ok());
+	assert!(bits.store_signed_be(-16i8).is_ok());
+	assert!(bits.store_signed_be(0xF0u8).is_err());
+	assert_eq!(bits.load_signed_be::<i8>(), -16);
+	assert_eq!(
+		bits.store_signed_be(16i8),
+		Err(FieldError::Overflow { width: 5 })
+	);
+	assert!(bits.store_signed_le(-17i32).is_err());
+	assert_eq!(bits.load_signed_be::<i8>(), -16);
+
+	assert!(bits.store_unsigned_le(31u16).is_ok());
+	assert_eq!(
+		bits.store_unsigned_le(32u16),
+		Err(FieldError::Overflow { width: 5 })
+	);
+	assert!(bits.store_unsigned_be(-1i64).is_err());
+	assert_eq!(bits.load_unsigned_le::<u8>(), 31);
+	assert_eq!(data, 0x0F80_0000);
+
+	//  A field as wide as `I` still rejects values of the wrong sign.
+	let bits = &mut data.view_bits_mut::<Lsb0>()[8 .. 16];
+	assert_eq!(
+		bits.store_unsigned_le(-1i8),
+		Err(FieldError::Overflow { width: 8 })
+	);
+	assert_eq!(
+		bits.store_signed_le(255u8),
+		Err(FieldError::Overflow { width: 8 })
+	);
+	assert!(bits.store_signed_be(-128i8).is_ok());
+	assert!(bits.store_signed_be(127u8).is_ok());
+	assert!(bits.store_unsigned_be(255u8).is_ok());
+	assert!(bits.store_unsigned_le(127i8).is_ok());
+	assert_eq!(bits.load_le::<u8>(), 127);
+}
+
+#[test]
+fn fallible_transfers() {
+	let mut data = random::<[u8; 4]>();
+	let copy = data;
+	let bits = data.view_bits_mut::<Lsb0>();
+
+	for len in 0 .. 32 {
+		let expected = match len {
+			1 ..= 16 => Ok(bits[.. len].load_le::<u16>()),
+			_ => Err(FieldError::Width {
+				requested: len,
+				max:       16,
+			}),
+		};
+		assert_eq!(bits[.. len].try_load_le::<u16>(), expected);
+		assert_eq!(
+			bits[.. len].try_load_be::<u16>(),
+			expected.map(|_| bits[.. len].load_be::<u16>()),
+		);
+		if expected.is_err() {
+			assert!(bits[.. len].try_store_le(!0u16).is_err());
+			assert!(bits[.. len].try_store_be(!0u16).is_err());
+		}
+	}
+	assert_eq!(data, copy);
+
+	let bits = data.view_bits_mut::<Msb0>();
+	bits[8 .. 24].try_store_be(0x1234u16).unwrap();
+	assert_eq!(bits[8 .. 24].try_load_be::<u32>(), Ok(0x1234));
+	bits[8 .. 24].try_store_le(0x5678u16).unwrap();
+	assert_eq!(bits[8 .. 24].try_load_le::<i64>(), Ok(0x5678));
+	assert_eq!(
+		bits.try_load_be::<u16>(),
+		Err(FieldError::Width {
+			requested: 32,
+			max:       16,
+		})
+	);
+}
+
+#[test]
+fn floats() {
+	let mut data = [0u16; 7];
+	let single = random::<f32>() - 0.5;
+	let double = random::<f64>() * -1e300;
+
+	let bits = data.view_bits_mut::<Lsb0>();
+	bits[3 .. 35].store_f32_le(single);
+	bits[35 .. 99].store_f64_be(double);
+	assert_eq!(bits[3 .. 35].load_f32_le().to_bits(), single.to_bits());
+	assert_eq!(bits[35 .. 99].load_f64_be().to_bits(), double.to_bits());
+	assert_eq!(bits[3 .. 35].load_le::<u32>(), single.to_bits());
+
+	let bits = data.view_bits_mut::<Msb0>();
+	bits[5 .. 37].store_f32(f32::NEG_INFINITY);
+	bits[37 .. 101].store_f64_le(-0.0);
+	assert_eq!(bits[5 .. 37].load_f32(), f32::NEG_INFINITY);
+	assert_eq!(bits[37 .. 101].load_f64_le().to_bits(), (-0.0f64).to_bits());
+	bits[37 .. 101].store_f64(f64::NAN);
+	assert!(bits[37 .. 101].load_f64().is_nan());
+	bits[.. 32].store_f32_be(1.0);
+	assert_eq!(bits[.. 32].load_be::<u32>(), 1.0f32.to_bits());
+}
+
+#[test]
+#[should_panic]
+fn float_width() {
+	bits![u8, Lsb0; 0; 31].load_f32_le();
+}
+
+#[test]
+fn fixed_point() {
+	let mut data = [0u8; 4];
+	let bits = data.view_bits_mut::<Msb0>();
+
+	for &(value, raw) in &[(0.0, 0), (1.5, 24), (-2.03125, -33), (-0.03, 0)] {
+		bits[3 .. 13].store_signed_fixed_be(value, 4).unwrap();
+		assert_eq!(bits[3 .. 13].load_signed_be::<i16>(), raw);
+		bits[13 .. 23].store_signed_fixed_le(value, 4).unwrap();
+		assert_eq!(bits[13 .. 23].load_signed_le::<i16>(), raw);
+		assert_eq!(bits[13 .. 23].load_signed_fixed_le(4), raw as f64 / 16.0);
+	}
+	//  Rounding is to nearest, with ties away from zero.
+	bits[3 .. 13].store_signed_fixed_be(-0.03125, 4).unwrap();
+	assert_eq!(bits[3 .. 13].load_signed_be::<i16>(), -1);
+	bits[3 .. 13].store_signed_fixed_be(31.96, 4).unwrap();
+	assert_eq!(bits[3 .. 13].load_signed_fixed_be(4), 31.9375);
+	assert_eq!(
+		bits[3 .. 13].store_signed_fixed_be(31.97, 4),
+		Err(FieldError::Overflow { width: 10 })
+	);
+	assert!(bits[3 .. 13].store_signed_fixed_le(f64::NAN, 4).is_err());
+	assert_eq!(bits[3 .. 13].load_signed_fixed_be(4), 31.9375);
+
+	bits[23 ..].store_unsigned_fixed_le(1.0, 8).unwrap();
+	assert_eq!(bits[23 ..].load_unsigned_le::<u16>(), 0x100);
+	assert_eq!(bits[23 ..].load_unsigned_fixed_le(8), 1.0);
+	assert!(bits[23 ..].store_unsigned_fixed_le(-0.5, 8).is_err());
+	assert!(bits[23 ..]
+		.store_unsigned_fixed_be(f64::INFINITY, 0)
+		.is_err());
+	bits[23 ..].store_unsigned_fixed_be(0.9, 0).unwrap();
+	assert_eq!(bits[23 ..].load_unsigned_fixed_be(0), 1.0);
+
+	let mut wide = [0u64; 2];
+	let bits = &mut wide.view_bits_mut::<Lsb0>()[32 .. 96];
+	bits.store_signed_fixed_le(i64::MIN as f64, 0).unwrap();
+	assert_eq!(bits.load_signed_le::<i64>(), i64::MIN);
+	assert!(bits.store_signed_fixed_le(-(i64::MIN as f64), 0).is_err());
+	bits.store_unsigned_fixed_be(u64::MAX as f64 - 2048.0, 0)
+		.unwrap();
+	assert!(bits.store_unsigned_fixed_be(u64::MAX as f64, 0).is_err());
+}
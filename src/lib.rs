@@ -26,16 +26,24 @@ pub mod macros;
 
 pub mod access;
 pub mod array;
+pub mod bitmap;
 pub mod boxed;
+pub mod crc;
 pub mod domain;
 pub mod field;
 pub mod index;
+pub mod matrix;
 pub mod mem;
 pub mod order;
+pub mod packed;
+pub mod poly;
 pub mod ptr;
-mod serdes;
+pub mod rank;
+pub mod serdes;
+pub mod set;
 pub mod slice;
 pub mod store;
+pub mod stream;
 pub mod vec;
 pub mod view;
 
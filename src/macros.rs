@@ -46,6 +46,38 @@ macro_rules! bitarr {
 	 * valid in `const` contexts.
 	 */
 
+	//  Bit-string literals must be captured before the sequence arms, as a
+	//  `:literal` forwarded as an `:expr` can no longer be recognized.
+
+	(const Cell<$store:ident>, $order:ident; $text:literal $(,)?) => {{
+		use $crate::macros::internal::core;
+		type Celled = core::cell::Cell<$store>;
+
+		const ELTS: usize = $crate::__count_elts!($store; $text);
+		type Data = [Celled; ELTS];
+		const DATA: Data = $crate::__encode_literal!(Celled, $order; $text);
+
+		type This = $crate::array::BitArray<Data, $order>;
+		This { data: DATA, ..This::ZERO }
+	}};
+	(const $store:ident, $order:ident; $text:literal $(,)?) => {{
+		const ELTS: usize = $crate::__count_elts!($store; $text);
+		type Data = [$store; ELTS];
+		const DATA: Data = $crate::__encode_literal!($store, $order; $text);
+
+		type This = $crate::array::BitArray<Data, $order>;
+		This { data: DATA, ..This::ZERO }
+	}};
+	(const Lsb0; $text:literal $(,)?) => {
+		$crate::bitarr!(const usize, Lsb0; $text)
+	};
+	(const Msb0; $text:literal $(,)?) => {
+		$crate::bitarr!(const usize, Msb0; $text)
+	};
+	(const LocalBits; $text:literal $(,)?) => {
+		$crate::bitarr!(const usize, LocalBits; $text)
+	};
+
 	//  Bit-sequencing requires detecting `Cell` separately from other types.
 	//  See below.
 
@@ -83,6 +115,9 @@ macro_rules! bitarr {
 		$crate::bitarr!(const usize, $crate::order::Lsb0; $val; $len)
 	}};
 
+	(const $text:literal $(,)?) => {{
+		$crate::bitarr!(const usize, Lsb0; $text)
+	}};
 	(const $($val:expr),* $(,)?) => {{
 		$crate::bitarr!(const usize, Lsb0; $($val),*)
 	}};
@@ -112,6 +147,58 @@ macro_rules! bitarr {
 	 * `:ident` does not match `Cell<_>`.
 	 */
 
+	/* Bit-string literals.
+	 *
+	 * These must precede the bit-sequence arms, which would otherwise capture
+	 * the literal as an opaque `:expr`. The literal is decoded by `const fn`s
+	 * rather than by the macro, so these arms need no width-specific logic.
+	 */
+
+	(Cell<$store:ident>, $order:ident; $text:literal $(,)?) => {{
+		use $crate::macros::internal::core;
+		type Celled = core::cell::Cell<$store>;
+
+		const ELTS: usize = $crate::__count_elts!($store; $text);
+		type This = $crate::array::BitArray<[Celled; ELTS], $order>;
+
+		This::new($crate::__encode_literal!(Celled, $order; $text))
+	}};
+	(Cell<$store:ident>, $order:path; $text:literal $(,)?) => {{
+		use $crate::macros::internal::core;
+		type Celled = core::cell::Cell<$store>;
+
+		const ELTS: usize = $crate::__count_elts!($store; $text);
+		type This = $crate::array::BitArray<[Celled; ELTS], $order>;
+
+		This::new($crate::__encode_literal!(Celled, $order; $text))
+	}};
+
+	($store:ident, $order:ident; $text:literal $(,)?) => {{
+		const ELTS: usize = $crate::__count_elts!($store; $text);
+		type This = $crate::array::BitArray<[$store; ELTS], $order>;
+
+		This::new($crate::__encode_literal!($store, $order; $text))
+	}};
+	($store:ident, $order:path; $text:literal $(,)?) => {{
+		const ELTS: usize = $crate::__count_elts!($store; $text);
+		type This = $crate::array::BitArray<[$store; ELTS], $order>;
+
+		This::new($crate::__encode_literal!($store, $order; $text))
+	}};
+
+	(Lsb0; $text:literal $(,)?) => {
+		$crate::bitarr!(usize, Lsb0; $text)
+	};
+	(Msb0; $text:literal $(,)?) => {
+		$crate::bitarr!(usize, Msb0; $text)
+	};
+	(LocalBits; $text:literal $(,)?) => {
+		$crate::bitarr!(usize, LocalBits; $text)
+	};
+	($text:literal $(,)?) => {
+		$crate::bitarr!(usize, Lsb0; $text)
+	};
+
 	(Cell<$store:ident>, $order:ident; $($val:expr),* $(,)?) => {{
 		use $crate::macros::internal::core;
 		type Celled = core::cell::Cell<$store>;
@@ -180,6 +267,40 @@ macro_rules! bits {
 		DATA.get_unchecked_mut(.. $len)
 	}};
 
+	//  Bit-string literals must precede the bit-sequence arms.
+
+	(static mut Cell<$store:ident>, $order:ident; $text:literal $(,)?) => {{
+		use $crate::macros::internal::core;
+		type Celled = core::cell::Cell<$store>;
+		const BITS: usize = $crate::__count!($text);
+
+		static mut DATA: $crate::BitArr!(for BITS, in $store, $order) =
+			$crate::bitarr!(const $store, $order; $text);
+		&mut *(
+			DATA.get_unchecked_mut(.. BITS)
+				as *mut $crate::slice::BitSlice<$store, $order>
+				as *mut $crate::slice::BitSlice<Celled, $order>
+		)
+	}};
+	(static mut $store:ident, $order:ident; $text:literal $(,)?) => {{
+		const BITS: usize = $crate::__count!($text);
+		static mut DATA: $crate::BitArr!(for BITS, in $store, $order) =
+			$crate::bitarr!(const $store, $order; $text);
+		DATA.get_unchecked_mut(.. BITS)
+	}};
+	(static mut Lsb0; $text:literal $(,)?) => {
+		$crate::bits!(static mut usize, Lsb0; $text)
+	};
+	(static mut Msb0; $text:literal $(,)?) => {
+		$crate::bits!(static mut usize, Msb0; $text)
+	};
+	(static mut LocalBits; $text:literal $(,)?) => {
+		$crate::bits!(static mut usize, LocalBits; $text)
+	};
+	(static mut $text:literal $(,)?) => {
+		$crate::bits!(static mut usize, Lsb0; $text)
+	};
+
 	(static mut Cell<$store:ident>, $order:ident; $($val:expr),* $(,)?) => {{
 		use $crate::macros::internal::core;
 		type Celled = core::cell::Cell<$store>;
@@ -222,6 +343,40 @@ macro_rules! bits {
 			)
 		}
 	}};
+	(static Cell<$store:ident>, $order:ident; $text:literal $(,)?) => {{
+		use $crate::macros::internal::core;
+		type Celled = core::cell::Cell<$store>;
+		const BITS: usize = $crate::__count!($text);
+
+		static DATA: $crate::BitArr!(for BITS, in $store, $order) =
+			$crate::bitarr!(const $store, $order; $text);
+		unsafe {
+			&*(
+				DATA.get_unchecked(.. BITS)
+					as *const $crate::slice::BitSlice<$store, $order>
+					as *const $crate::slice::BitSlice<Celled, $order>
+			)
+		}
+	}};
+	(static $store:ident, $order:ident; $text:literal $(,)?) => {{
+		const BITS: usize = $crate::__count!($text);
+		static DATA: $crate::BitArr!(for BITS, in $store, $order) =
+			$crate::bitarr!(const $store, $order; $text);
+		unsafe { DATA.get_unchecked(.. BITS) }
+	}};
+	(static Lsb0; $text:literal $(,)?) => {
+		$crate::bits!(static usize, Lsb0; $text)
+	};
+	(static Msb0; $text:literal $(,)?) => {
+		$crate::bits!(static usize, Msb0; $text)
+	};
+	(static LocalBits; $text:literal $(,)?) => {
+		$crate::bits!(static usize, LocalBits; $text)
+	};
+	(static $text:literal $(,)?) => {
+		$crate::bits!(static usize, Lsb0; $text)
+	};
+
 	(static Cell<$store:ident>, $order:ident; $($val:expr),* $(,)?) => {{
 		use $crate::macros::internal::core;
 		type Celled = core::cell::Cell<$store>;
@@ -266,6 +421,37 @@ macro_rules! bits {
 
 	//  Explicit order and store.
 
+	//  Bit-string literals.
+
+	(mut Cell<$store:ident>, $order:ident; $text:literal $(,)?) => {{
+		const BITS: usize = $crate::__count!($text);
+		&mut $crate::bitarr!(Cell<$store>, $order; $text)[.. BITS]
+	}};
+	(mut Cell<$store:ident>, $order:path; $text:literal $(,)?) => {{
+		const BITS: usize = $crate::__count!($text);
+		&mut $crate::bitarr!(Cell<$store>, $order; $text)[.. BITS]
+	}};
+	(mut $store:ident, $order:ident; $text:literal $(,)?) => {{
+		const BITS: usize = $crate::__count!($text);
+		&mut $crate::bitarr!($store, $order; $text)[.. BITS]
+	}};
+	(mut $store:ident, $order:path; $text:literal $(,)?) => {{
+		const BITS: usize = $crate::__count!($text);
+		&mut $crate::bitarr!($store, $order; $text)[.. BITS]
+	}};
+	(mut Lsb0; $text:literal $(,)?) => {
+		$crate::bits!(mut usize, Lsb0; $text)
+	};
+	(mut Msb0; $text:literal $(,)?) => {
+		$crate::bits!(mut usize, Msb0; $text)
+	};
+	(mut LocalBits; $text:literal $(,)?) => {
+		$crate::bits!(mut usize, LocalBits; $text)
+	};
+	(mut $text:literal $(,)?) => {
+		$crate::bits!(mut usize, Lsb0; $text)
+	};
+
 	(mut Cell<$store:ident>, $order:ident; $($val:expr),* $(,)?) => {{
 		const BITS: usize = $crate::__count!($($val),*);
 		&mut $crate::bitarr!(Cell<$store>, $order; $($val),*)[.. BITS]
@@ -304,6 +490,35 @@ macro_rules! bits {
 		&$crate::bitarr!($store, $order; $val; $len)[.. $len]
 	}};
 
+	(Cell<$store:ident>, $order:ident; $text:literal $(,)?) => {{
+		const BITS: usize = $crate::__count!($text);
+		&$crate::bitarr!(Cell<$store>, $order; $text)[.. BITS]
+	}};
+	(Cell<$store:ident>, $order:path; $text:literal $(,)?) => {{
+		const BITS: usize = $crate::__count!($text);
+		&$crate::bitarr!(Cell<$store>, $order; $text)[.. BITS]
+	}};
+	($store:ident, $order:ident; $text:literal $(,)?) => {{
+		const BITS: usize = $crate::__count!($text);
+		&$crate::bitarr!($store, $order; $text)[.. BITS]
+	}};
+	($store:ident, $order:path; $text:literal $(,)?) => {{
+		const BITS: usize = $crate::__count!($text);
+		&$crate::bitarr!($store, $order; $text)[.. BITS]
+	}};
+	(Lsb0; $text:literal $(,)?) => {
+		$crate::bits!(usize, Lsb0; $text)
+	};
+	(Msb0; $text:literal $(,)?) => {
+		$crate::bits!(usize, Msb0; $text)
+	};
+	(LocalBits; $text:literal $(,)?) => {
+		$crate::bits!(usize, LocalBits; $text)
+	};
+	($text:literal $(,)?) => {
+		$crate::bits!(usize, Lsb0; $text)
+	};
+
 	(Cell<$store:ident>, $order:ident; $($val:expr),* $(,)?) => {{
 		const BITS: usize = $crate::__count!($($val),*);
 		&$crate::bitarr!(Cell<$store>, $order; $($val),*)[.. BITS]
@@ -345,6 +560,17 @@ macro_rules! bitvec {
 	(Cell<$store:ident>, $order:ident $($rest:tt)*) => {
 		$crate::vec::BitVec::from_bitslice($crate::bits!(Cell<$store>, $order $($rest)*))
 	};
+	//  Ordering-only bit-string literals look like repetitions, and must be
+	//  captured before the repetition arm.
+	(Lsb0; $text:literal $(,)?) => {
+		$crate::vec::BitVec::from_bitslice($crate::bits!(Lsb0; $text))
+	};
+	(Msb0; $text:literal $(,)?) => {
+		$crate::vec::BitVec::from_bitslice($crate::bits!(Msb0; $text))
+	};
+	(LocalBits; $text:literal $(,)?) => {
+		$crate::vec::BitVec::from_bitslice($crate::bits!(LocalBits; $text))
+	};
 	($val:expr; $len:expr) => {
 		$crate::bitvec!(usize, $crate::order::Lsb0; $val; $len)
 	};
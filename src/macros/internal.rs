@@ -9,6 +9,13 @@ pub use core;
 #[doc(hidden)]
 pub use funty;
 
+use crate::slice::{
+	digit_value,
+	is_separator,
+	scan_word,
+	Fault,
+};
+
 #[doc(hidden)]
 #[macro_export]
 #[doc = include_str!("../../doc/macros/encode_bits.md")]
@@ -219,11 +226,67 @@ macro_rules! __encode_bits {
 	};
 }
 
+#[doc(hidden)]
+#[macro_export]
+#[doc = include_str!("../../doc/macros/encode_literal.md")]
+macro_rules! __encode_literal {
+	//  The three known orderings are encoded in `const` context, directly
+	//  into the bytes of the memory elements.
+	(@ $typ:ty, $msb0:expr; $text:literal) => {{
+		use $crate::macros::internal::core;
+		const TEXT: &str = core::stringify!($text);
+		const BITS: usize = $crate::macros::internal::literal_len(TEXT);
+		const ELTS: usize = $crate::mem::elts::<$typ>(BITS);
+		const SIZE: usize = core::mem::size_of::<$typ>();
+		const BYTES: [u8; ELTS * SIZE] =
+			$crate::macros::internal::encode_literal::<{ ELTS * SIZE }>(
+				&$crate::macros::internal::literal_bits::<BITS>(TEXT),
+				SIZE,
+				$msb0,
+			);
+		//  `u8` storage transmutes to its own type.
+		#[allow(clippy::useless_transmute)]
+		let data = unsafe {
+			core::mem::transmute::<[u8; ELTS * SIZE], [$typ; ELTS]>(BYTES)
+		};
+		data
+	}};
+	($typ:ty, Lsb0; $text:literal) => {
+		$crate::__encode_literal!(@ $typ, false; $text)
+	};
+	($typ:ty, Msb0; $text:literal) => {
+		$crate::__encode_literal!(@ $typ, true; $text)
+	};
+	($typ:ty, LocalBits; $text:literal) => {
+		$crate::__encode_literal!(@ $typ, cfg!(target_endian = "big"); $text)
+	};
+	//  Otherwise, decode the bits at compile-time and write them through the
+	//  `BitOrder` at runtime.
+	($typ:ty, $ord:tt; $text:literal) => {{
+		use $crate::macros::internal::core;
+		const TEXT: &str = core::stringify!($text);
+		const BITS: usize = $crate::macros::internal::literal_len(TEXT);
+		const ELTS: usize = $crate::mem::elts::<$typ>(BITS);
+		let mut out = $crate::array::BitArray::<[$typ; ELTS], $ord>::ZERO;
+		let bits = $crate::macros::internal::literal_bits::<BITS>(TEXT);
+		for (idx, &bit) in bits.iter().enumerate() {
+			out.set(idx, bit);
+		}
+		out.into_inner()
+	}};
+}
+
 /// Counts the number of expression tokens in a repetition sequence.
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __count {
 	(@ $val:expr) => { 1 };
+	($text:literal) => {{
+		const LEN: usize = $crate::macros::internal::literal_len(
+			$crate::macros::internal::core::stringify!($text),
+		);
+		LEN
+	}};
 	($($val:expr),* $(,)?) => {{
 		const LEN: usize = 0 $(+ $crate::__count!(@ $val))*;
 		LEN
@@ -234,6 +297,9 @@ macro_rules! __count {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __count_elts {
+	($t:ty; $text:literal) => {
+		$crate::mem::elts::<$t>($crate::__count!($text))
+	};
 	($t:ty; $($val:expr),*) => {
 		$crate::mem::elts::<$t>($crate::__count!($($val),*))
 	};
@@ -412,3 +478,218 @@ pub use self::u8_from_be_bits as u8_from_ne_bits;
 #[doc(hidden)]
 #[cfg(target_endian = "little")]
 pub use self::u8_from_le_bits as u8_from_ne_bits;
+
+/// Halts `const` evaluation of a malformed bit-literal.
+///
+/// `panic!` is not available in `const fn` at this crate’s MSRV, so this
+/// indexes past the end of an array instead. The compiler reports the failed
+/// expression, which contains the message.
+macro_rules! invalid_literal {
+	($msg:literal) => {{
+		#[allow(unconditional_panic, clippy::out_of_bounds_indexing)]
+		let _ = [$msg][1].len();
+	}};
+}
+
+/// Counts the bits that a bit-literal token describes.
+///
+/// `token` is the text of the literal, as produced by `stringify!`, including
+/// its quotes.
+#[doc(hidden)]
+pub const fn literal_len(token: &str) -> usize {
+	decode_literal::<0>(token).1
+}
+
+/// Decodes a bit-literal token into its bits, in the order written.
+///
+/// `N` must be the value that [`literal_len`] computes for `token`.
+///
+/// [`literal_len`]: self::literal_len
+#[doc(hidden)]
+pub const fn literal_bits<const N: usize>(token: &str) -> [bool; N] {
+	decode_literal::<N>(token).0
+}
+
+/// Encodes a sequence of bits into the memory bytes of a sequence of
+/// `width`-byte elements.
+///
+/// The bits fill each element from its least significant (`msb0 == false`)
+/// or most significant (`msb0 == true`) bit, which are the `Lsb0` and `Msb0`
+/// orderings. The elements are written in the target’s byte order, so that the
+/// output can be transmuted into the element type.
+#[doc(hidden)]
+pub const fn encode_literal<const N: usize>(
+	bits: &[bool],
+	width: usize,
+	msb0: bool,
+) -> [u8; N] {
+	let mut out = [0u8; N];
+	let elem_bits = width * 8;
+	let mut idx = 0;
+	while idx < bits.len() {
+		if bits[idx] {
+			let pos = idx % elem_bits;
+			let bit = if msb0 { elem_bits - 1 - pos } else { pos };
+			let byte = if cfg!(target_endian = "big") {
+				width - 1 - bit / 8
+			}
+			else {
+				bit / 8
+			};
+			out[idx / elem_bits * width + byte] |= 1 << (bit % 8);
+		}
+		idx += 1;
+	}
+	out
+}
+
+/// Decodes a bit-literal token, storing its first `N` bits and counting all of
+/// them.
+///
+/// A string literal (plain or raw) holds a run of binary digits, or a run of
+/// digits with a `0b`, `0o`, or `0x` prefix, where each digit contributes as
+/// many bits as its radix requires. A `W'b`, `W'o`, or `W'h` prefix, where `W`
+/// is a decimal bit-count, instead sizes the literal to exactly `W` bits. The
+/// text follows the same rules as for [`FromStr`], and is checked by the same
+/// scanner.
+///
+/// An integer literal is a single bit, and is `1` if its value is nonzero. Any
+/// other literal is an error.
+///
+/// [`FromStr`]: core::str::FromStr
+const fn decode_literal<const N: usize>(token: &str) -> ([bool; N], usize) {
+	let text = token.as_bytes();
+	let mut out = [false; N];
+	let mut len = 0;
+
+	//  Locate the contents of a string literal.
+	let (mut idx, end) = match text {
+		[b'"', ..] => (1, text.len() - 1),
+		[b'r', ..] => {
+			let mut hashes = 1;
+			while text[hashes] == b'#' {
+				hashes += 1;
+			}
+			(hashes + 1, text.len() - hashes)
+		},
+		_ => {
+			let bit = int_literal_bit(text);
+			if N > 0 {
+				out[0] = bit;
+			}
+			return (out, 1);
+		},
+	};
+
+	let word = match scan_word(text, idx, end) {
+		Ok(word) => word,
+		Err(Fault::Empty(_)) => {
+			invalid_literal!("bit-literal has no digits");
+			return (out, 0);
+		},
+		Err(Fault::InvalidDigit(..)) => {
+			invalid_literal!("invalid digit in bit-literal");
+			return (out, 0);
+		},
+		Err(Fault::Width(..)) => {
+			invalid_literal!("bit-literal digits do not fill exactly its width");
+			return (out, 0);
+		},
+	};
+
+	let mut skip = word.skip;
+	idx = word.digits;
+	while idx < end {
+		let byte = text[idx];
+		idx += 1;
+		if is_separator(byte) {
+			continue;
+		}
+		let value = digit_value(byte);
+		let mut bit = word.shift;
+		while bit > 0 {
+			bit -= 1;
+			if skip > 0 {
+				skip -= 1;
+				continue;
+			}
+			if len < N {
+				out[len] = value >> bit & 1 == 1;
+			}
+			len += 1;
+		}
+	}
+	(out, len)
+}
+
+/// Reads the text of an integer literal as a bit, which is `1` if the value is
+/// nonzero.
+///
+/// Any other literal, such as `true` or `1.5`, halts `const` evaluation. Bit
+/// values are otherwise compared against `0`, which rejects them as a type
+/// error; the literal is only seen as text here, so it is checked by hand.
+const fn int_literal_bit(text: &[u8]) -> bool {
+	let mut idx = 0;
+	while idx < text.len() && matches!(text[idx], b'-' | b' ') {
+		idx += 1;
+	}
+	let mut radix = 10;
+	if idx + 1 < text.len() && text[idx] == b'0' {
+		match text[idx + 1] {
+			b'b' => radix = 2,
+			b'o' => radix = 8,
+			b'x' => radix = 16,
+			_ => {},
+		}
+		if radix != 10 {
+			idx += 2;
+		}
+	}
+	let mut digits = false;
+	let mut nonzero = false;
+	while idx < text.len() {
+		let byte = text[idx];
+		let value = digit_value(byte);
+		if byte != b'_' {
+			if value >= radix {
+				break;
+			}
+			digits = true;
+			nonzero |= value != 0;
+		}
+		idx += 1;
+	}
+	if !digits || !is_int_suffix(text, idx) {
+		invalid_literal!("bit values must be integers or strings");
+	}
+	nonzero
+}
+
+/// The type suffixes that an integer literal may carry.
+const INT_SUFFIXES: [&[u8]; 12] = [
+	b"u8", b"u16", b"u32", b"u64", b"u128", b"usize", b"i8", b"i16", b"i32",
+	b"i64", b"i128", b"isize",
+];
+
+/// Tests whether `text[start ..]` is empty or an integer type suffix.
+const fn is_int_suffix(text: &[u8], start: usize) -> bool {
+	let rest = text.len() - start;
+	if rest == 0 {
+		return true;
+	}
+	let mut which = 0;
+	while which < INT_SUFFIXES.len() {
+		let suffix = INT_SUFFIXES[which];
+		if suffix.len() == rest {
+			let mut idx = 0;
+			while idx < rest && suffix[idx] == text[start + idx] {
+				idx += 1;
+			}
+			if idx == rest {
+				return true;
+			}
+		}
+		which += 1;
+	}
+	false
+}
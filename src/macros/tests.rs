@@ -585,3 +585,146 @@ fn make_elem() {
 		invoke_make_elem!(Cell<usize> as usize, crate::order::Lsb0; 0, 0, 1, 1);
 	assert_eq!(cell.get(), 12);
 }
+
+#[test]
+fn bit_literals() {
+	let a = bits![u8, Msb0; "1010_0001"];
+	assert_eq!(a, bits![1, 0, 1, 0, 0, 0, 0, 1]);
+	assert_eq!(bitarr![u8, Msb0; "1010_0001"].into_inner(), [0xA1]);
+
+	assert_eq!(bits![Msb0; "1010_0001"], a);
+	assert_eq!(bits![u16, Lsb0; "0xA1"], a);
+	assert_eq!(bitarr![u16, Lsb0; "0xA1"].into_inner(), [0x85]);
+	assert_eq!(bits![u32, LocalBits; "0o502"][.. 8], a);
+	assert_eq!(bits![u8, Msb0; "0o502"].len(), 9);
+
+	//  Sized forms drop the clear leading bits of their first digit.
+	assert_eq!(bits![u8, Msb0; "12'h0A1"][4 ..], a);
+	assert!(bits![u8, Msb0; "12'h0A1"][.. 4].not_any());
+	assert_eq!(bits![u8, Msb0; "6'b00_1010"], bits![0, 0, 1, 0, 1, 0]);
+	assert_eq!(bits![u8, Msb0; "3'o5"], bits![1, 0, 1]);
+	assert_eq!(bits![u8, Msb0; "3'h5"], bits![1, 0, 1]);
+
+	//  Long vectors span elements, and may be written across lines.
+	let long = bitarr![u16, Msb0; r"
+		0x0123_4567
+		  89AB_CDEF
+	"];
+	assert_eq!(long.into_inner(), [0x0123, 0x4567, 0x89AB, 0xCDEF]);
+	let long = bits![Cell<u64>, Lsb0; "0x0123_4567_89AB_CDEF_FEDC_BA98"];
+	assert_eq!(long.len(), 96);
+	assert_eq!(
+		long[.. 64].load_le::<u64>().reverse_bits(),
+		0x0123_4567_89AB_CDEF
+	);
+	assert_eq!(long[64 ..].load_le::<u32>().reverse_bits(), 0xFEDC_BA98);
+
+	//  Integer literals remain single bit-expressions.
+	assert_eq!(bits![u8, Msb0; 1], bits![1]);
+	assert_eq!(bits![u8, Msb0; 0x10], bits![1]);
+	assert_eq!(bits![u8, Msb0; 0u8], bits![0]);
+	assert_eq!(bits![u8, Msb0; 0b0_0usize], bits![0]);
+	assert_eq!(bits![u8, Msb0; -1], bits![1]);
+	assert_eq!(bits![u8, Msb0; 1_0i64], bits![1]);
+	assert_eq!(bits![u8, Msb0; "1"], bits![1]);
+
+	//  Orderings other than the three known names are encoded at runtime.
+	let hilo = bits![u16, crate::order::HiLo; "0x1234"];
+	assert_eq!(hilo, bits![u16, Msb0; "0x1234"]);
+	let hilo = bitarr![Cell<u8>, crate::order::HiLo; "0b0110"];
+	assert_eq!(hilo[.. 4], bits![0, 1, 1, 0]);
+
+	let d: &mut BitSlice<u8, Msb0> = bits![mut u8, Msb0; "0x0F"];
+	d.set(0, true);
+	assert_eq!(d.load_be::<u8>(), 0x8F);
+	assert_eq!(bits![mut Msb0; "110"], bits![1, 1, 0]);
+	assert_eq!(bits!["0b110"], bits![1, 1, 0]);
+	assert_eq!(bits![mut RadiumU32, Lsb0; "011"], bits![0, 1, 1]);
+
+	const E: BitArr!(for 12, in u16, Msb0) = bitarr!(const u16, Msb0; "12'h3F0");
+	assert_eq!(E.into_inner(), [0x3F00]);
+	const F: BitArr!(for 12, in Cell<u8>, Lsb0) =
+		bitarr!(const Cell<u8>, Lsb0; "0x3F0");
+	let f = F;
+	assert_eq!(f[.. 12], bits![0, 0, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0]);
+	const G: BitArr!(for 4, in usize, Msb0) = bitarr!(const Msb0; "0xF");
+	assert_eq!(G.count_ones(), 4);
+	const H: BitArr!(for 4) = bitarr!(const "1001");
+	assert_eq!(H[.. 4], bits![1, 0, 0, 1]);
+
+	let s: &'static BitSlice<u32, Msb0> = bits![static u32, Msb0; "0xDEAD"];
+	assert_eq!(s.load_be::<u16>(), 0xDEAD);
+	let s: &'static BitSlice<Cell<u8>, Lsb0> =
+		bits![static Cell<u8>, Lsb0; "0o17"];
+	assert_eq!(s, bits![0, 0, 1, 1, 1, 1]);
+	let s: &'static BitSlice<usize, LocalBits> = bits![static LocalBits; "101"];
+	assert_eq!(s, bits![1, 0, 1]);
+	let s: &'static mut BitSlice<u8, Msb0> =
+		unsafe { bits![static mut u8, Msb0; "0xC3"] };
+	assert_eq!(s.load_be::<u8>(), 0xC3);
+	let s: &'static mut BitSlice<Cell<u16>, Lsb0> =
+		unsafe { bits![static mut Cell<u16>, Lsb0; "0b11"] };
+	assert_eq!(s, bits![1, 1]);
+	let s: &'static mut BitSlice = unsafe { bits![static mut "0"] };
+	assert_eq!(s, bits![0]);
+
+	radium::if_atomic! {
+		if atomic(32) {
+			let atom = bitarr![AtomicU32, Msb0; "0x8000_0001"];
+			assert_eq!(atom.count_ones(), 2);
+		}
+	}
+
+	#[cfg(feature = "alloc")]
+	{
+		let bv = bitvec![Msb0; "0xA5"];
+		assert_eq!(bv, bits![1, 0, 1, 0, 0, 1, 0, 1]);
+		let bv = bitvec![u8, Lsb0; "0b1"];
+		assert_eq!(bv.as_raw_slice(), [1]);
+		let bb = bitbox![Cell<u16>, Msb0; "4'hA"];
+		assert_eq!(bb, bits![1, 0, 1, 0]);
+	}
+}
+
+/// Every bit-literal that the macros accept is read the same way by `FromStr`.
+#[test]
+#[cfg(feature = "alloc")]
+fn bit_literals_parse() {
+	macro_rules! agree {
+		($($text:literal),+ $(,)?) => { $(
+			assert_eq!(
+				bits![u8, Msb0; $text],
+				$text.parse::<BitVec<u8, Msb0>>().unwrap(),
+				"{}",
+				$text,
+			);
+		)+ };
+	}
+
+	agree![
+		"1010_0001",
+		"0",
+		"0b110",
+		"0B1_1",
+		"0o502",
+		"0O17",
+		"0xA1",
+		"0X0f",
+		"0xDEAD",
+		" 0x0123 4567 ",
+		"12'h0A1",
+		"12'h3F0",
+		"10'h2cf",
+		"4'HA",
+		"6'b00_1010",
+		"1'B1",
+		"3'o5",
+		"5'O07",
+		"3'h5",
+		"8'hb3",
+		r"
+			0x0123_4567
+			  89AB_CDEF
+		",
+	];
+}
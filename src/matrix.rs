@@ -0,0 +1,601 @@
+#![doc = include_str!("../doc/matrix.md")]
+#![cfg(feature = "alloc")]
+
+use alloc::vec::Vec;
+use core::cmp;
+
+use crate::{
+	mem::bits_of,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::{
+		BitSlice,
+		WORD_BITS,
+	},
+	store::BitStore,
+	vec::BitVec,
+};
+
+mod iter;
+mod ops;
+mod tests;
+mod traits;
+
+pub use self::iter::{
+	Column,
+	Rows,
+};
+
+#[doc = include_str!("../doc/matrix/BitMatrix.md")]
+pub struct BitMatrix<T = usize, O = Lsb0>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The rows, one after another. Each row begins at a new memory element,
+	/// and the bits that pad it out to the next row are always `0`.
+	bits:   BitVec<T, O>,
+	/// The number of rows.
+	rows:   usize,
+	/// The number of columns, which is the length of each row.
+	cols:   usize,
+	/// The distance between the starts of consecutive rows.
+	stride: usize,
+}
+
+/// Constructors.
+impl<T, O> BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Creates a matrix of `0` bits.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let m = BitMatrix::<u8>::new(3, 10);
+	/// assert_eq!((m.rows(), m.cols()), (3, 10));
+	/// assert!(m.iter_rows().all(|row| row.not_any()));
+	/// ```
+	#[inline]
+	pub fn new(rows: usize, cols: usize) -> Self {
+		let elem = bits_of::<T::Mem>();
+		let stride = (cols + elem - 1) / elem * elem;
+		Self {
+			bits: BitVec::repeat(false, rows * stride),
+			rows,
+			cols,
+			stride,
+		}
+	}
+
+	/// Creates a square identity matrix.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let id = BitMatrix::<u8>::identity(4);
+	/// assert!(id[2][2]);
+	/// assert!(!id[2][1]);
+	/// ```
+	#[inline]
+	pub fn identity(size: usize) -> Self {
+		Self::from_fn(size, size, |row, col| row == col)
+	}
+
+	/// Creates a matrix by calling a function with the row and column of each
+	/// bit.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let upper = BitMatrix::<u8>::from_fn(3, 3, |row, col| row <= col);
+	/// assert_eq!(upper[1], bits![0, 1, 1]);
+	/// ```
+	#[inline]
+	pub fn from_fn<F>(rows: usize, cols: usize, mut func: F) -> Self
+	where F: FnMut(usize, usize) -> bool {
+		let mut out = Self::new(rows, cols);
+		for row in 0 .. rows {
+			for (col, mut bit) in out.row_mut(row).iter_mut().enumerate() {
+				*bit = func(row, col);
+			}
+		}
+		out
+	}
+
+	/// Creates a matrix by copying a sequence of rows.
+	///
+	/// ## Panics
+	///
+	/// This panics if the rows do not all have the same length.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let m = BitMatrix::<u8>::from_rows(vec![
+	///   bits![1, 0, 1],
+	///   bits![0, 1, 1],
+	/// ]);
+	/// assert_eq!((m.rows(), m.cols()), (2, 3));
+	/// assert_eq!(m[1], bits![0, 1, 1]);
+	/// ```
+	#[inline]
+	pub fn from_rows<'a, I, T2, O2>(rows: I) -> Self
+	where
+		I: IntoIterator<Item = &'a BitSlice<T2, O2>>,
+		T2: 'a + BitStore,
+		O2: 'a + BitOrder,
+	{
+		let rows = rows.into_iter().collect::<Vec<_>>();
+		let cols = rows.first().map(|row| row.len()).unwrap_or(0);
+		let mut out = Self::new(rows.len(), cols);
+		for (idx, row) in rows.into_iter().enumerate() {
+			assert_eq!(
+				row.len(),
+				cols,
+				"row {} has {} columns, but the matrix has {}",
+				idx,
+				row.len(),
+				cols,
+			);
+			out.row_mut(idx).clone_from_bitslice(row);
+		}
+		out
+	}
+}
+
+/// Rows and columns.
+impl<T, O> BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Gets the number of rows.
+	#[inline]
+	pub fn rows(&self) -> usize {
+		self.rows
+	}
+
+	/// Gets the number of columns.
+	#[inline]
+	pub fn cols(&self) -> usize {
+		self.cols
+	}
+
+	/// Views a row.
+	///
+	/// This is also available through indexing: `matrix[row]`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `row` is out of bounds.
+	#[inline]
+	pub fn row(&self, row: usize) -> &BitSlice<T, O> {
+		self.assert_row(row);
+		let start = row * self.stride;
+		unsafe { self.bits.get_unchecked(start .. start + self.cols) }
+	}
+
+	/// Views a row mutably.
+	///
+	/// This is also available through indexing: `matrix[row]`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `row` is out of bounds.
+	#[inline]
+	pub fn row_mut(&mut self, row: usize) -> &mut BitSlice<T, O> {
+		self.assert_row(row);
+		let start = row * self.stride;
+		unsafe { self.bits.get_unchecked_mut(start .. start + self.cols) }
+	}
+
+	/// Iterates over the rows, top to bottom.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let id = BitMatrix::<u16>::identity(5);
+	/// assert!(id.iter_rows().all(|row| row.count_ones() == 1));
+	/// ```
+	#[inline]
+	pub fn iter_rows(&self) -> Rows<'_, T, O> {
+		Rows::new(self)
+	}
+
+	/// Iterates over the bits of a column, top to bottom.
+	///
+	/// ## Panics
+	///
+	/// This panics if `col` is out of bounds.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let lower = BitMatrix::<u8>::from_fn(4, 4, |row, col| row >= col);
+	/// assert!(lower.column(1).eq([false, true, true, true].iter().copied()));
+	/// ```
+	#[inline]
+	pub fn column(&self, col: usize) -> Column<'_, T, O> {
+		assert!(
+			col < self.cols,
+			"column index {} out of bounds: {}",
+			col,
+			self.cols,
+		);
+		Column::new(self, col)
+	}
+
+	/// Exchanges the contents of two rows.
+	///
+	/// ## Panics
+	///
+	/// This panics if either row is out of bounds.
+	#[inline]
+	pub fn swap_rows(&mut self, a: usize, b: usize) {
+		self.assert_row(a);
+		self.assert_row(b);
+		if a != b {
+			self.combine_rows(a, b, 0, |this, that| (that, this));
+		}
+	}
+
+	/// Adds (exclusive-ors) one row into another.
+	///
+	/// This is the elementary row operation of Gaussian elimination over
+	/// GF(2).
+	///
+	/// ## Panics
+	///
+	/// This panics if either row is out of bounds, or if they are the same row.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let mut m = BitMatrix::<u8>::identity(3);
+	/// m.add_row(0, 2);
+	/// assert_eq!(m[2], bits![1, 0, 1]);
+	/// ```
+	#[inline]
+	pub fn add_row(&mut self, src: usize, dst: usize) {
+		self.assert_row(src);
+		self.assert_row(dst);
+		assert_ne!(src, dst, "cannot add a row to itself");
+		self.combine_rows(src, dst, 0, |this, that| (this, this ^ that));
+	}
+
+	/// Asserts that a row index is in bounds.
+	fn assert_row(&self, row: usize) {
+		assert!(
+			row < self.rows,
+			"row index {} out of bounds: {}",
+			row,
+			self.rows,
+		);
+	}
+
+	/// Combines two rows one processor word at a time, starting at column
+	/// `from`, and writes the results back into them.
+	fn combine_rows<F>(&mut self, a: usize, b: usize, from: usize, func: F)
+	where F: Fn(usize, usize) -> (usize, usize) {
+		let (a, b) = (a * self.stride, b * self.stride);
+		let mut col = from;
+		while col < self.cols {
+			let end = cmp::min(col + WORD_BITS, self.cols);
+			let (this, that) = unsafe {
+				(
+					self.bits.get_unchecked(a + col .. a + end).load_word(),
+					self.bits.get_unchecked(b + col .. b + end).load_word(),
+				)
+			};
+			let (this, that) = func(this, that);
+			unsafe {
+				self.bits
+					.get_unchecked_mut(a + col .. a + end)
+					.store_word(this);
+				self.bits
+					.get_unchecked_mut(b + col .. b + end)
+					.store_word(that);
+			}
+			col = end;
+		}
+	}
+}
+
+/// Linear algebra over GF(2).
+impl<T, O> BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Transposes the matrix, exchanging its rows and columns.
+	///
+	/// The matrix is processed in square blocks of `WORD_BITS` rows and
+	/// columns (64 × 64 on 64-bit targets). Each block is loaded into an array
+	/// of processor words, transposed in registers with masked shifts, and
+	/// stored into the output.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let m = BitMatrix::<u8>::from_fn(2, 100, |row, col| col % (row + 2) == 0);
+	/// let t = m.transpose();
+	/// assert_eq!((t.rows(), t.cols()), (100, 2));
+	/// assert_eq!(t[6], bits![1, 1]);
+	/// assert_eq!(t[9], bits![0, 1]);
+	/// assert_eq!(t.transpose(), m);
+	/// ```
+	#[inline]
+	pub fn transpose(&self) -> Self {
+		let mut out = Self::new(self.cols, self.rows);
+		let mut block = [0usize; WORD_BITS];
+		for r0 in (0 .. self.rows).step_by(WORD_BITS) {
+			let r1 = cmp::min(r0 + WORD_BITS, self.rows);
+			for c0 in (0 .. self.cols).step_by(WORD_BITS) {
+				let c1 = cmp::min(c0 + WORD_BITS, self.cols);
+				for (idx, word) in block.iter_mut().enumerate() {
+					*word = match r0 + idx {
+						row if row < r1 => self.row(row)[c0 .. c1].load_word(),
+						_ => 0,
+					};
+				}
+				transpose_block(&mut block);
+				for (idx, word) in block[.. c1 - c0].iter().enumerate() {
+					out.row_mut(c0 + idx)[r0 .. r1].store_word(*word);
+				}
+			}
+		}
+		out
+	}
+
+	/// Multiplies the matrix by a column vector over GF(2).
+	///
+	/// Each bit of the product is the parity of the `1` bits that a row shares
+	/// with the vector.
+	///
+	/// This is also available as the `*` operator.
+	///
+	/// ## Panics
+	///
+	/// This panics if the length of `vec` is not the number of columns.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let m = BitMatrix::<u8>::from_rows(vec![
+	///   bits![1, 1, 0],
+	///   bits![0, 1, 1],
+	/// ]);
+	/// assert_eq!(m.mul_vec(bits![1, 1, 1]), bits![0, 0]);
+	/// assert_eq!(m.mul_vec(bits![1, 0, 0]), bits![1, 0]);
+	/// ```
+	#[inline]
+	pub fn mul_vec<T2, O2>(&self, vec: &BitSlice<T2, O2>) -> BitVec<T, O>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		assert_eq!(
+			vec.len(),
+			self.cols,
+			"cannot multiply a matrix with {} columns by a vector of length {}",
+			self.cols,
+			vec.len(),
+		);
+		self.iter_rows()
+			.map(|row| row.and_count(vec) & 1 == 1)
+			.collect()
+	}
+
+	/// Multiplies two matrices over GF(2).
+	///
+	/// Each row of the product is the sum (exclusive-or) of the rows of `rhs`
+	/// selected by the `1` bits in the corresponding row of `self`, so the
+	/// product is built from whole-row word operations.
+	///
+	/// This is also available as the `*` operator.
+	///
+	/// ## Panics
+	///
+	/// This panics if the number of columns in `self` is not the number of rows
+	/// in `rhs`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let a = BitMatrix::<u8>::from_fn(5, 7, |row, col| (row ^ col) & 1 == 0);
+	/// let id = BitMatrix::identity(7);
+	/// assert_eq!(a.mul_matrix(&id), a);
+	/// ```
+	#[inline]
+	pub fn mul_matrix(&self, rhs: &Self) -> Self {
+		assert_eq!(
+			self.cols, rhs.rows,
+			"cannot multiply a matrix with {} columns by a matrix with {} rows",
+			self.cols, rhs.rows,
+		);
+		let mut out = Self::new(self.rows, rhs.cols);
+		for (idx, row) in self.iter_rows().enumerate() {
+			let dst = out.row_mut(idx);
+			for src in row.iter_ones() {
+				*dst ^= rhs.row(src);
+			}
+		}
+		out
+	}
+
+	/// Reduces the matrix to reduced row echelon form by Gaussian elimination
+	/// over GF(2).
+	///
+	/// ## Returns
+	///
+	/// The rank of the matrix. The first `rank` rows are its nonzero rows, each
+	/// of which begins with a `1` bit in a column that is `0` in every other
+	/// row.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let mut m = BitMatrix::<u8>::from_rows(vec![
+	///   bits![0, 1, 1],
+	///   bits![1, 1, 0],
+	///   bits![1, 0, 1],
+	/// ]);
+	/// assert_eq!(m.row_reduce(), 2);
+	/// assert_eq!(m[0], bits![1, 0, 1]);
+	/// assert_eq!(m[1], bits![0, 1, 1]);
+	/// assert!(m[2].not_any());
+	/// ```
+	#[inline]
+	pub fn row_reduce(&mut self) -> usize {
+		let mut rank = 0;
+		for col in 0 .. self.cols {
+			if rank == self.rows {
+				break;
+			}
+			let pivot = match (rank .. self.rows).find(|&row| self.row(row)[col])
+			{
+				Some(pivot) => pivot,
+				None => continue,
+			};
+			if pivot != rank {
+				self.combine_rows(pivot, rank, col, |this, that| (that, this));
+			}
+			for row in 0 .. self.rows {
+				if row != rank && self.row(row)[col] {
+					self.combine_rows(rank, row, col, |this, that| {
+						(this, this ^ that)
+					});
+				}
+			}
+			rank += 1;
+		}
+		rank
+	}
+
+	/// Computes the rank of the matrix over GF(2).
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// assert_eq!(BitMatrix::<u8>::identity(9).rank(), 9);
+	/// assert_eq!(BitMatrix::<u8>::from_fn(4, 4, |_, _| true).rank(), 1);
+	/// ```
+	#[inline]
+	pub fn rank(&self) -> usize {
+		self.clone().row_reduce()
+	}
+
+	/// Solves the linear system `self * x = rhs` over GF(2).
+	///
+	/// ## Panics
+	///
+	/// This panics if the length of `rhs` is not the number of rows.
+	///
+	/// ## Returns
+	///
+	/// A solution `x`, or `None` if the system has no solution. If it has many
+	/// solutions, all of its free variables are set to `0`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let m = BitMatrix::<u8>::from_rows(vec![
+	///   bits![1, 1, 0],
+	///   bits![0, 1, 1],
+	///   bits![1, 0, 1],
+	/// ]);
+	/// let x = m.solve(bits![1, 0, 1]).unwrap();
+	/// assert_eq!(m.mul_vec(&x), bits![1, 0, 1]);
+	/// assert!(m.solve(bits![1, 0, 0]).is_none());
+	/// ```
+	#[inline]
+	pub fn solve<T2, O2>(&self, rhs: &BitSlice<T2, O2>) -> Option<BitVec<T, O>>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		assert_eq!(
+			rhs.len(),
+			self.rows,
+			"cannot solve a system of {} equations with {} constants",
+			self.rows,
+			rhs.len(),
+		);
+		let cols = self.cols;
+		let mut aug = Self::new(self.rows, cols + 1);
+		for (idx, row) in self.iter_rows().enumerate() {
+			let dst = aug.row_mut(idx);
+			dst[.. cols].clone_from_bitslice(row);
+			dst.set(cols, rhs[idx]);
+		}
+		let rank = aug.row_reduce();
+
+		let mut out = BitVec::repeat(false, cols);
+		for row in aug.iter_rows().take(rank) {
+			match row.first_one() {
+				Some(pivot) if pivot < cols => out.set(pivot, row[cols]),
+				_ => return None,
+			}
+		}
+		Some(out)
+	}
+}
+
+/// Transposes a square block of `WORD_BITS` × `WORD_BITS` bits, in which bit
+/// `n` of word `m` is the bit at row `m`, column `n`.
+///
+/// This exchanges the off-diagonal quadrants of the block, then of each
+/// quadrant, and so on down to single bits.
+fn transpose_block(block: &mut [usize; WORD_BITS]) {
+	let mut width = WORD_BITS / 2;
+	let mut mask = !0usize >> width;
+	while width != 0 {
+		for idx in 0 .. WORD_BITS {
+			if idx & width == 0 {
+				let swap = ((block[idx] >> width) ^ block[idx + width]) & mask;
+				block[idx] ^= swap << width;
+				block[idx + width] ^= swap;
+			}
+		}
+		width /= 2;
+		mask ^= mask << width;
+	}
+}
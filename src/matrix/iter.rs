@@ -0,0 +1,220 @@
+//! Row and column iteration for bit-matrices.
+
+use core::iter::FusedIterator;
+
+use super::BitMatrix;
+use crate::{
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+#[derive(Debug)]
+#[doc = include_str!("../../doc/matrix/Rows.md")]
+pub struct Rows<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The matrix whose rows are being yielded.
+	matrix: &'a BitMatrix<T, O>,
+	/// The index of the next row to yield from the front.
+	front:  usize,
+	/// The index after the next row to yield from the back.
+	back:   usize,
+}
+
+impl<'a, T, O> Rows<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Begins iteration over all rows of a matrix.
+	pub(super) fn new(matrix: &'a BitMatrix<T, O>) -> Self {
+		Self {
+			matrix,
+			front: 0,
+			back: matrix.rows(),
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Clone for Rows<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self { ..*self }
+	}
+}
+
+impl<'a, T, O> Iterator for Rows<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	type Item = &'a BitSlice<T, O>;
+
+	easy_iter!();
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.front == self.back {
+			return None;
+		}
+		self.front += 1;
+		Some(self.matrix.row(self.front - 1))
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		if n >= self.len() {
+			self.front = self.back;
+			return None;
+		}
+		self.front += n + 1;
+		Some(self.matrix.row(self.front - 1))
+	}
+}
+
+impl<T, O> DoubleEndedIterator for Rows<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.nth_back(0)
+	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		if n >= self.len() {
+			self.back = self.front;
+			return None;
+		}
+		self.back -= n + 1;
+		Some(self.matrix.row(self.back))
+	}
+}
+
+impl<T, O> ExactSizeIterator for Rows<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn len(&self) -> usize {
+		self.back - self.front
+	}
+}
+
+impl<T, O> FusedIterator for Rows<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+}
+
+#[derive(Debug)]
+#[doc = include_str!("../../doc/matrix/Column.md")]
+pub struct Column<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The rows of the matrix.
+	rows: Rows<'a, T, O>,
+	/// The index of the column within each row.
+	col:  usize,
+}
+
+impl<'a, T, O> Column<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Begins iteration over one column of a matrix.
+	pub(super) fn new(matrix: &'a BitMatrix<T, O>, col: usize) -> Self {
+		Self {
+			rows: Rows::new(matrix),
+			col,
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Clone for Column<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			rows: self.rows.clone(),
+			..*self
+		}
+	}
+}
+
+impl<'a, T, O> Iterator for Column<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	type Item = bool;
+
+	easy_iter!();
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let col = self.col;
+		self.rows.next().map(|row| row[col])
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		let col = self.col;
+		self.rows.nth(n).map(|row| row[col])
+	}
+}
+
+impl<T, O> DoubleEndedIterator for Column<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let col = self.col;
+		self.rows.next_back().map(|row| row[col])
+	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		let col = self.col;
+		self.rows.nth_back(n).map(|row| row[col])
+	}
+}
+
+impl<T, O> ExactSizeIterator for Column<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn len(&self) -> usize {
+		self.rows.len()
+	}
+}
+
+impl<T, O> FusedIterator for Column<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+}
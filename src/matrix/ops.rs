@@ -0,0 +1,72 @@
+//! Operator trait implementations for bit-matrices.
+
+use core::ops::{
+	Index,
+	IndexMut,
+	Mul,
+};
+
+use super::BitMatrix;
+use crate::{
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+/// Indexing a matrix selects a row.
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Index<usize> for BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Output = BitSlice<T, O>;
+
+	#[inline]
+	fn index(&self, row: usize) -> &Self::Output {
+		self.row(row)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> IndexMut<usize> for BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn index_mut(&mut self, row: usize) -> &mut Self::Output {
+		self.row_mut(row)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Mul for &BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Output = BitMatrix<T, O>;
+
+	#[inline]
+	fn mul(self, rhs: Self) -> Self::Output {
+		self.mul_matrix(rhs)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T1, T2, O1, O2> Mul<&BitSlice<T2, O2>> for &BitMatrix<T1, O1>
+where
+	T1: BitStore,
+	T2: BitStore,
+	O1: BitOrder,
+	O2: BitOrder,
+{
+	type Output = BitVec<T1, O1>;
+
+	#[inline]
+	fn mul(self, rhs: &BitSlice<T2, O2>) -> Self::Output {
+		self.mul_vec(rhs)
+	}
+}
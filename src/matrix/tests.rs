@@ -0,0 +1,172 @@
+//! Unit tests for bit-matrices.
+
+#![cfg(test)]
+
+use rand::random;
+
+use super::*;
+use crate::{
+	order::HiLo,
+	prelude::*,
+};
+
+/// Builds a random matrix.
+fn random_matrix<T, O>(rows: usize, cols: usize) -> BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	BitMatrix::from_fn(rows, cols, |_, _| random())
+}
+
+#[test]
+fn layout() {
+	let mut m = BitMatrix::<u8, Msb0>::new(3, 10);
+	assert_eq!((m.rows(), m.cols()), (3, 10));
+	m[1].set(9, true);
+	m.row_mut(2).set(0, true);
+	assert_eq!(m[1], bits![0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+	assert!(m.column(9).eq([false, true, false].iter().copied()));
+	assert!(m.column(0).rev().eq([true, false, false].iter().copied()));
+	assert_eq!(m.iter_rows().len(), 3);
+	assert_eq!(m.iter_rows().nth(2), Some(m.row(2)));
+
+	m.swap_rows(1, 2);
+	assert!(m[1][0]);
+	assert!(m[2][9]);
+	m.add_row(2, 1);
+	assert_eq!(m[1], bits![1, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+
+	let copy = BitMatrix::<u32, Lsb0>::from_rows(m.iter_rows());
+	assert_eq!(copy, m);
+	assert_eq!(
+		BitMatrix::<u8>::from_rows(Vec::<&BitSlice>::new()).rows(),
+		0
+	);
+
+	let id = BitMatrix::<u16, Lsb0>::identity(3);
+	#[cfg(feature = "std")]
+	{
+		assert_eq!(id.to_string(), "100\n010\n001");
+		assert_eq!(format!("{:?}", id), "BitMatrix(3x3) [100, 010, 001]");
+	}
+	assert_eq!(id.rank(), 3);
+	assert_eq!(BitMatrix::<u8>::new(0, 5).transpose().rows(), 5);
+}
+
+#[test]
+fn transpose() {
+	fn check<T, O>(rows: usize, cols: usize)
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		let m = random_matrix::<T, O>(rows, cols);
+		let t = m.transpose();
+		assert_eq!((t.rows(), t.cols()), (cols, rows));
+		for row in 0 .. rows {
+			for col in 0 .. cols {
+				assert_eq!(m[row][col], t[col][row]);
+			}
+		}
+		assert_eq!(t.transpose(), m);
+	}
+
+	for &(rows, cols) in &[(1, 1), (3, 70), (64, 64), (65, 130), (200, 9)] {
+		check::<u8, Lsb0>(rows, cols);
+		check::<u16, Msb0>(rows, cols);
+		check::<u32, HiLo>(rows, cols);
+		check::<usize, Lsb0>(rows, cols);
+	}
+}
+
+#[test]
+fn products() {
+	fn check<T, O>(n: usize, k: usize, m: usize)
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		let a = random_matrix::<T, O>(n, k);
+		let b = random_matrix::<T, O>(k, m);
+		let ab = &a * &b;
+		assert_eq!((ab.rows(), ab.cols()), (n, m));
+		for row in 0 .. n {
+			for col in 0 .. m {
+				let dot = (0 .. k).filter(|&i| a[row][i] & b[i][col]).count();
+				assert_eq!(ab[row][col], dot % 2 == 1);
+			}
+		}
+		assert_eq!(ab.transpose(), &b.transpose() * &a.transpose());
+
+		let v = (0 .. m).map(|_| random::<bool>()).collect::<BitVec>();
+		let bv = &b * v.as_bitslice();
+		for (row, bit) in b.iter_rows().zip(bv.iter().by_vals()) {
+			assert_eq!(
+				bit,
+				(row.to_bitvec() & v.as_bitslice()).count_ones() % 2 == 1
+			);
+		}
+		assert_eq!(&ab * v.as_bitslice(), a.mul_vec(&bv));
+	}
+
+	for &(n, k, m) in &[(1, 1, 1), (5, 70, 3), (33, 9, 66), (64, 64, 64)] {
+		check::<u8, Lsb0>(n, k, m);
+		check::<u16, Msb0>(n, k, m);
+		check::<u32, HiLo>(n, k, m);
+	}
+}
+
+#[test]
+fn elimination() {
+	fn check<T, O>(rows: usize, cols: usize)
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		let a = random_matrix::<T, O>(rows, cols);
+		let mut r = a.clone();
+		let rank = r.row_reduce();
+		assert_eq!(rank, a.transpose().rank());
+		assert!(rank <= rows.min(cols));
+
+		let mut last = None;
+		for (idx, row) in r.iter_rows().enumerate() {
+			let pivot = row.first_one();
+			assert_eq!(pivot.is_some(), idx < rank);
+			if let Some(pivot) = pivot {
+				assert!(last.map_or(true, |last| pivot > last));
+				assert_eq!(r.column(pivot).filter(|bit| *bit).count(), 1);
+				last = Some(pivot);
+			}
+		}
+
+		let x = (0 .. cols).map(|_| random::<bool>()).collect::<BitVec>();
+		let b = a.mul_vec(&x);
+		let y = a.solve(&b).expect("the system has a solution");
+		assert_eq!(a.mul_vec(&y), b);
+	}
+
+	for &(rows, cols) in &[(1, 1), (4, 4), (10, 30), (30, 10), (70, 70)] {
+		for _ in 0 .. 3 {
+			check::<u8, Lsb0>(rows, cols);
+			check::<u16, Msb0>(rows, cols);
+			check::<u32, HiLo>(rows, cols);
+		}
+	}
+
+	let singular = BitMatrix::<u8>::from_fn(3, 3, |row, _| row < 2);
+	assert_eq!(singular.rank(), 1);
+	assert!(singular.solve(bits![1, 0, 0]).is_none());
+	assert!(singular.solve(bits![0, 0, 1]).is_none());
+	assert_eq!(
+		singular.solve(bits![1, 1, 0]),
+		Some(bitvec![u8, Lsb0; 1, 0, 0])
+	);
+}
+
+#[test]
+#[should_panic = "row index 3 out of bounds: 3"]
+fn row_out_of_bounds() {
+	BitMatrix::<u8>::identity(3).row(3);
+}
@@ -0,0 +1,130 @@
+//! General trait implementations for bit-matrices.
+
+use core::{
+	fmt::{
+		self,
+		Debug,
+		Display,
+		Formatter,
+		Write,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+};
+
+use super::BitMatrix;
+use crate::{
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Clone for BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			bits: self.bits.clone(),
+			..*self
+		}
+	}
+}
+
+impl<T, O> Eq for BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+}
+
+/// Matrices are equal when they have the same dimensions and the same bits,
+/// regardless of their memory layout.
+#[cfg(not(tarpaulin_include))]
+impl<T1, T2, O1, O2> PartialEq<BitMatrix<T2, O2>> for BitMatrix<T1, O1>
+where
+	T1: BitStore,
+	T2: BitStore,
+	O1: BitOrder,
+	O2: BitOrder,
+{
+	#[inline]
+	fn eq(&self, other: &BitMatrix<T2, O2>) -> bool {
+		self.rows == other.rows
+			&& self.cols == other.cols
+			&& self.iter_rows().zip(other.iter_rows()).all(|(a, b)| a == b)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Hash for BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn hash<H>(&self, state: &mut H)
+	where H: Hasher {
+		self.rows.hash(state);
+		self.cols.hash(state);
+		for row in self.iter_rows() {
+			row.hash(state);
+		}
+	}
+}
+
+impl<T, O> Debug for BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(fmt, "BitMatrix({}x{}) ", self.rows, self.cols)?;
+		fmt.debug_list()
+			.entries(self.iter_rows().map(RowDigits))
+			.finish()
+	}
+}
+
+/// Renders the matrix as a grid of `0` and `1` digits, one row per line.
+impl<T, O> Display for BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		for (idx, row) in self.iter_rows().enumerate() {
+			if idx > 0 {
+				fmt.write_char('\n')?;
+			}
+			Debug::fmt(&RowDigits(row), fmt)?;
+		}
+		Ok(())
+	}
+}
+
+/// Renders a row as a string of `0` and `1` digits.
+struct RowDigits<'a, T, O>(&'a BitSlice<T, O>)
+where
+	T: BitStore,
+	O: BitOrder;
+
+impl<T, O> Debug for RowDigits<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		for bit in self.0.iter().by_vals() {
+			fmt.write_char(if bit { '1' } else { '0' })?;
+		}
+		Ok(())
+	}
+}
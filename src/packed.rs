@@ -0,0 +1,35 @@
+#![doc = include_str!("../doc/packed.md")]
+
+use crate::mem::bits_of;
+
+mod slice;
+mod tests;
+mod vec;
+
+pub use self::slice::{
+	Iter,
+	PackedSlice,
+};
+#[cfg(feature = "alloc")]
+pub use self::vec::PackedVec;
+
+/// Asserts that a packed element width is in the domain `1 ..= 64`.
+fn check_width(width: usize) {
+	assert!(
+		(1 ..= bits_of::<u64>()).contains(&width),
+		"packed element width {} is not in the domain 1 ..= {}",
+		width,
+		bits_of::<u64>(),
+	);
+}
+
+/// Asserts that a value can be stored in a packed element of `width` bits.
+#[cfg(feature = "alloc")]
+fn check_value(value: u64, width: usize) {
+	assert!(
+		width == bits_of::<u64>() || value >> width == 0,
+		"value {} does not fit in a {}-bit packed element",
+		value,
+		width,
+	);
+}
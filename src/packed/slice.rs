@@ -0,0 +1,293 @@
+//! Borrowed views of packed integers.
+
+use core::{
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	iter::FusedIterator,
+};
+
+use super::check_width;
+use crate::{
+	field::BitField,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::{
+		BitSlice,
+		ChunksExact,
+	},
+	store::BitStore,
+};
+
+#[doc = include_str!("../../doc/packed/PackedSlice.md")]
+pub struct PackedSlice<'a, T = usize, O = Lsb0>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The bit-slice holding the packed elements.
+	bits:  &'a BitSlice<T, O>,
+	/// The width of each element.
+	width: usize,
+}
+
+impl<'a, T, O> PackedSlice<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	/// Views a bit-slice as a sequence of `width`-bit integers.
+	///
+	/// Any bits at the end of the bit-slice that are too few to form a whole
+	/// element are not part of the view.
+	///
+	/// ## Panics
+	///
+	/// This panics if `width` is not in the domain `1 ..= 64`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::packed::PackedSlice;
+	///
+	/// let data = [0x21u8, 0x43];
+	/// let packed = PackedSlice::new(data.view_bits::<Lsb0>(), 5);
+	/// assert_eq!(packed.len(), 3);
+	/// assert_eq!(packed.as_bitslice().len(), 15);
+	/// ```
+	#[inline]
+	pub fn new(bits: &'a BitSlice<T, O>, width: usize) -> Self {
+		check_width(width);
+		let len = bits.len() / width * width;
+		Self {
+			bits: unsafe { bits.get_unchecked(.. len) },
+			width,
+		}
+	}
+
+	/// Gets the width of each element.
+	#[inline]
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	/// Gets the number of elements in the view.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.bits.len() / self.width
+	}
+
+	/// Tests if the view has no elements.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.bits.is_empty()
+	}
+
+	/// Views the bits that hold the elements.
+	#[inline]
+	pub fn as_bitslice(&self) -> &'a BitSlice<T, O> {
+		self.bits
+	}
+
+	/// Loads the element at an index.
+	///
+	/// Elements are loaded with [`.load_le()`], and zero-extended.
+	///
+	/// ## Returns
+	///
+	/// The element value, or `None` if `index` is out of bounds.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::packed::PackedSlice;
+	///
+	/// let data = [0x21u8, 0x43];
+	/// let packed = PackedSlice::new(data.view_bits::<Lsb0>(), 5);
+	/// assert_eq!(packed.get(0), Some(0x01));
+	/// assert_eq!(packed.get(1), Some(0x19));
+	/// assert!(packed.get(3).is_none());
+	/// ```
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	#[inline]
+	pub fn get(&self, index: usize) -> Option<u64> {
+		if index >= self.len() {
+			return None;
+		}
+		let start = index * self.width;
+		Some(
+			unsafe { self.bits.get_unchecked(start .. start + self.width) }
+				.load_le::<u64>(),
+		)
+	}
+
+	/// Iterates over the elements.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::packed::PackedSlice;
+	///
+	/// let data = [0x21u8, 0x43];
+	/// let packed = PackedSlice::new(data.view_bits::<Lsb0>(), 5);
+	/// assert!(packed.iter().eq([1, 25, 16].iter().copied()));
+	/// ```
+	#[inline]
+	pub fn iter(&self) -> Iter<'a, T, O> {
+		Iter {
+			chunks: self.bits.chunks_exact(self.width),
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Clone for PackedSlice<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<T, O> Copy for PackedSlice<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+}
+
+impl<'a, T, O> IntoIterator for PackedSlice<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	type IntoIter = Iter<'a, T, O>;
+	type Item = u64;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+impl<T1, T2, O1, O2> PartialEq<PackedSlice<'_, T2, O2>>
+	for PackedSlice<'_, T1, O1>
+where
+	T1: BitStore,
+	T2: BitStore,
+	O1: BitOrder,
+	O2: BitOrder,
+	BitSlice<T1, O1>: BitField,
+	BitSlice<T2, O2>: BitField,
+{
+	/// Packed sequences are equal when they hold the same element values,
+	/// regardless of their widths or memory layouts.
+	#[inline]
+	fn eq(&self, other: &PackedSlice<'_, T2, O2>) -> bool {
+		self.len() == other.len() && self.iter().eq(other.iter())
+	}
+}
+
+impl<T, O> Eq for PackedSlice<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Debug for PackedSlice<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(fmt, "PackedSlice<u{}> ", self.width)?;
+		fmt.debug_list().entries(self.iter()).finish()
+	}
+}
+
+#[derive(Clone, Debug)]
+#[doc = include_str!("../../doc/packed/Iter.md")]
+pub struct Iter<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The elements that have not yet been yielded.
+	chunks: ChunksExact<'a, T, O>,
+}
+
+impl<'a, T, O> Iterator for Iter<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	type Item = u64;
+
+	easy_iter!();
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.chunks.next().map(BitField::load_le)
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.chunks.nth(n).map(BitField::load_le)
+	}
+}
+
+impl<T, O> DoubleEndedIterator for Iter<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.chunks.next_back().map(BitField::load_le)
+	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		self.chunks.nth_back(n).map(BitField::load_le)
+	}
+}
+
+impl<T, O> ExactSizeIterator for Iter<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	#[inline]
+	fn len(&self) -> usize {
+		self.chunks.len()
+	}
+}
+
+impl<T, O> FusedIterator for Iter<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+}
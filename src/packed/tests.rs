@@ -0,0 +1,155 @@
+//! Unit tests for packed integer sequences.
+
+#![cfg(test)]
+
+#[cfg(feature = "alloc")]
+use rand::random;
+
+use super::*;
+use crate::{
+	field::BitField,
+	prelude::*,
+};
+
+#[test]
+fn slice_view() {
+	let data = [0x21u8, 0x43, 0x65];
+	let packed = PackedSlice::new(data.view_bits::<Lsb0>(), 5);
+	assert_eq!(packed.width(), 5);
+	assert_eq!(packed.len(), 4);
+	assert!(!packed.is_empty());
+	assert_eq!(packed.as_bitslice().len(), 20);
+	assert!(packed.iter().eq([1, 25, 16, 10].iter().copied()));
+	assert!(packed.iter().rev().eq([10, 16, 25, 1].iter().copied()));
+	assert_eq!(packed.iter().nth(2), Some(16));
+	assert_eq!(packed.iter().nth_back(1), Some(16));
+	assert!(packed.get(4).is_none());
+
+	let packed = PackedSlice::new(data.view_bits::<Msb0>(), 12);
+	assert_eq!(packed.len(), 2);
+	assert_eq!(
+		packed.get(0),
+		Some(data.view_bits::<Msb0>()[.. 12].load_le::<u64>()),
+	);
+
+	let short = PackedSlice::new(&data.view_bits::<Lsb0>()[.. 4], 5);
+	assert!(short.is_empty());
+	assert!(short.iter().next().is_none());
+
+	let wide = [!0u64, 0];
+	let packed = PackedSlice::new(wide.view_bits::<Msb0>(), 64);
+	assert!(packed.iter().eq([!0, 0].iter().copied()));
+}
+
+#[test]
+#[should_panic]
+fn zero_width() {
+	PackedSlice::new(bits![0; 8], 0);
+}
+
+#[test]
+#[should_panic]
+fn too_wide() {
+	PackedSlice::new(bits![0; 128], 65);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vec_round_trip() {
+	fn check<O>(width: usize)
+	where
+		O: BitOrder,
+		BitSlice<u8, O>: BitField,
+	{
+		let mask = !0u64 >> (64 - width);
+		let values = (0 .. 50)
+			.map(|_| random::<u64>() & mask)
+			.collect::<Vec<_>>();
+
+		let mut pv = PackedVec::<u8, O>::with_width(width);
+		for &value in &values {
+			pv.push(value);
+		}
+		assert_eq!(pv.len(), values.len());
+		assert_eq!(pv.as_bitslice().len(), values.len() * width);
+		assert!(pv.iter().eq(values.iter().copied()));
+		for (idx, &value) in values.iter().enumerate() {
+			assert_eq!(pv.get(idx), Some(value));
+		}
+
+		let other = !values[7] & mask;
+		pv.set(7, other);
+		assert_eq!(pv.get(6), Some(values[6]));
+		assert_eq!(pv.get(7), Some(other));
+		assert_eq!(pv.get(8), Some(values[8]));
+
+		assert_eq!(pv.pop(), values.last().copied());
+		assert_eq!(pv.len(), values.len() - 1);
+		pv.truncate(10);
+		assert_eq!(pv.len(), 10);
+		assert_eq!(pv.as_bitslice().len(), 10 * width);
+		pv.clear();
+		assert!(pv.is_empty());
+		assert!(pv.pop().is_none());
+	}
+
+	for &width in &[1, 5, 11, 17, 63, 64] {
+		check::<Lsb0>(width);
+		check::<Msb0>(width);
+	}
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vec_collect() {
+	let pv = [0u64, 0, 0].iter().copied().collect::<PackedVec>();
+	assert_eq!(pv.width(), 1);
+	assert_eq!(pv.len(), 3);
+
+	let pv = PackedVec::<u16, Msb0>::from_iter(vec![5, 300, 2]);
+	assert_eq!(pv.width(), 9);
+	assert!(pv.iter().eq([5, 300, 2].iter().copied()));
+
+	let pv = core::iter::once(!0u64).collect::<PackedVec<u32>>();
+	assert_eq!(pv.width(), 64);
+	assert_eq!(pv.get(0), Some(!0));
+
+	let empty = core::iter::empty().collect::<PackedVec>();
+	assert!(empty.is_empty());
+
+	let mut wide = PackedVec::<u32, Lsb0>::with_capacity(20, 4);
+	assert!(wide.capacity() >= 4);
+	wide.extend(vec![5, 300, 2]);
+	assert_eq!(wide, pv_from([5, 300, 2]));
+	assert_ne!(wide, pv_from([5, 300]));
+	assert_eq!(
+		wide.as_packed_slice(),
+		pv_from([5, 300, 2]).as_packed_slice()
+	);
+
+	let bits = wide.clone().into_bitvec();
+	assert_eq!(bits.len(), 60);
+	assert_eq!(bits[20 .. 40].load_le::<u64>(), 300);
+	assert!((&wide).into_iter().eq(wide.iter()));
+}
+
+#[cfg(feature = "alloc")]
+fn pv_from(values: impl IntoIterator<Item = u64>) -> PackedVec<u8, Msb0> {
+	values.into_iter().collect()
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+#[should_panic]
+fn vec_value_overflow() {
+	PackedVec::<u8>::with_width(4).push(16);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+#[should_panic]
+fn vec_set_out_of_bounds() {
+	let mut pv = PackedVec::<u8>::with_width(4);
+	pv.push(1);
+	pv.set(1, 1);
+}
@@ -0,0 +1,369 @@
+//! Owned vectors of packed integers.
+
+#![cfg(feature = "alloc")]
+
+use alloc::vec::Vec;
+use core::{
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	iter::FromIterator,
+};
+
+use super::{
+	check_value,
+	check_width,
+	Iter,
+	PackedSlice,
+};
+use crate::{
+	field::BitField,
+	mem::bits_of,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+#[doc = include_str!("../../doc/packed/PackedVec.md")]
+pub struct PackedVec<T = usize, O = Lsb0>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The bit-vector holding the packed elements.
+	bits:  BitVec<T, O>,
+	/// The width of each element.
+	width: usize,
+}
+
+impl<T, O> PackedVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	/// Creates an empty vector of `width`-bit integers.
+	///
+	/// ## Panics
+	///
+	/// This panics if `width` is not in the domain `1 ..= 64`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::packed::PackedVec;
+	///
+	/// let pv = PackedVec::<u8>::with_width(11);
+	/// assert_eq!(pv.width(), 11);
+	/// assert!(pv.is_empty());
+	/// ```
+	#[inline]
+	pub fn with_width(width: usize) -> Self {
+		Self::with_capacity(width, 0)
+	}
+
+	/// Creates an empty vector of `width`-bit integers, with room for at least
+	/// `capacity` elements before reallocating.
+	///
+	/// ## Panics
+	///
+	/// This panics if `width` is not in the domain `1 ..= 64`, or if the
+	/// requested capacity is too large for a bit-vector.
+	#[inline]
+	pub fn with_capacity(width: usize, capacity: usize) -> Self {
+		check_width(width);
+		Self {
+			bits: BitVec::with_capacity(capacity * width),
+			width,
+		}
+	}
+
+	/// Gets the width of each element.
+	#[inline]
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	/// Gets the number of elements in the vector.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.bits.len() / self.width
+	}
+
+	/// Tests if the vector has no elements.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.bits.is_empty()
+	}
+
+	/// Gets the number of elements the vector can hold without reallocating.
+	#[inline]
+	pub fn capacity(&self) -> usize {
+		self.bits.capacity() / self.width
+	}
+
+	/// Views the vector as a borrowed packed sequence.
+	#[inline]
+	pub fn as_packed_slice(&self) -> PackedSlice<'_, T, O> {
+		PackedSlice::new(self.bits.as_bitslice(), self.width)
+	}
+
+	/// Views the bits that hold the elements.
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<T, O> {
+		self.bits.as_bitslice()
+	}
+
+	/// Unwraps the bit-vector that holds the elements.
+	#[inline]
+	pub fn into_bitvec(self) -> BitVec<T, O> {
+		self.bits
+	}
+
+	/// Loads the element at an index.
+	///
+	/// Elements are loaded with [`.load_le()`], and zero-extended.
+	///
+	/// ## Returns
+	///
+	/// The element value, or `None` if `index` is out of bounds.
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	#[inline]
+	pub fn get(&self, index: usize) -> Option<u64> {
+		self.as_packed_slice().get(index)
+	}
+
+	/// Stores a value into the element at an index.
+	///
+	/// Elements are stored with [`.store_le()`].
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds, or if `value` does not fit in
+	/// the element width.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::packed::PackedVec;
+	///
+	/// let mut pv = PackedVec::<u8, Lsb0>::with_width(5);
+	/// pv.extend([1, 2, 3].iter().copied());
+	/// pv.set(1, 31);
+	/// assert_eq!(pv.get(1), Some(31));
+	/// assert_eq!(pv.as_bitslice()[5 .. 10], bits![1; 5]);
+	/// ```
+	///
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	#[inline]
+	pub fn set(&mut self, index: usize, value: u64) {
+		let len = self.len();
+		assert!(
+			index < len,
+			"index {} out of range for packed vector of length {}",
+			index,
+			len,
+		);
+		check_value(value, self.width);
+		let start = index * self.width;
+		unsafe { self.bits.get_unchecked_mut(start .. start + self.width) }
+			.store_le(value);
+	}
+
+	/// Appends an element to the back of the vector.
+	///
+	/// ## Panics
+	///
+	/// This panics if `value` does not fit in the element width.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::packed::PackedVec;
+	///
+	/// let mut pv = PackedVec::<u16>::with_width(17);
+	/// pv.push(100_000);
+	/// assert_eq!(pv.len(), 1);
+	/// assert_eq!(pv.as_bitslice().len(), 17);
+	/// ```
+	#[inline]
+	pub fn push(&mut self, value: u64) {
+		check_value(value, self.width);
+		let start = self.bits.len();
+		self.bits.resize(start + self.width, false);
+		unsafe { self.bits.get_unchecked_mut(start ..) }.store_le(value);
+	}
+
+	/// Removes the last element of the vector.
+	///
+	/// ## Returns
+	///
+	/// The removed element, or `None` if the vector is empty.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::packed::PackedVec;
+	///
+	/// let mut pv = PackedVec::<u8>::with_width(3);
+	/// pv.push(5);
+	/// assert_eq!(pv.pop(), Some(5));
+	/// assert!(pv.pop().is_none());
+	/// ```
+	#[inline]
+	pub fn pop(&mut self) -> Option<u64> {
+		let last = self.len().checked_sub(1)?;
+		let out = self.get(last);
+		self.bits.truncate(last * self.width);
+		out
+	}
+
+	/// Shortens the vector to at most `len` elements.
+	#[inline]
+	pub fn truncate(&mut self, len: usize) {
+		self.bits.truncate(len.saturating_mul(self.width));
+	}
+
+	/// Removes all elements from the vector.
+	#[inline]
+	pub fn clear(&mut self) {
+		self.bits.clear();
+	}
+
+	/// Iterates over the elements.
+	#[inline]
+	pub fn iter(&self) -> Iter<'_, T, O> {
+		self.as_packed_slice().iter()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Clone for PackedVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			bits:  self.bits.clone(),
+			width: self.width,
+		}
+	}
+}
+
+impl<T, O> Extend<u64> for PackedVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	#[inline]
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = u64> {
+		let iter = iter.into_iter();
+		self.bits
+			.reserve(iter.size_hint().0.saturating_mul(self.width));
+		for value in iter {
+			self.push(value);
+		}
+	}
+}
+
+/// Collects integers into a vector whose elements are just wide enough to
+/// hold the widest of them.
+///
+/// The width is at least one bit, even when every value is zero.
+///
+/// ## Examples
+///
+/// ```rust
+/// use bitvec::packed::PackedVec;
+///
+/// let pv: PackedVec = [3, 9, 1000].iter().copied().collect();
+/// assert_eq!(pv.width(), 10);
+/// assert_eq!(pv.get(2), Some(1000));
+/// ```
+impl<T, O> FromIterator<u64> for PackedVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	#[inline]
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = u64> {
+		let values = iter.into_iter().collect::<Vec<_>>();
+		let width = values
+			.iter()
+			.map(|value| bits_of::<u64>() - value.leading_zeros() as usize)
+			.max()
+			.unwrap_or(0)
+			.max(1);
+		let mut out = Self::with_capacity(width, values.len());
+		out.extend(values);
+		out
+	}
+}
+
+impl<'a, T, O> IntoIterator for &'a PackedVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	type IntoIter = Iter<'a, T, O>;
+	type Item = u64;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+impl<T1, T2, O1, O2> PartialEq<PackedVec<T2, O2>> for PackedVec<T1, O1>
+where
+	T1: BitStore,
+	T2: BitStore,
+	O1: BitOrder,
+	O2: BitOrder,
+	BitSlice<T1, O1>: BitField,
+	BitSlice<T2, O2>: BitField,
+{
+	/// Packed sequences are equal when they hold the same element values,
+	/// regardless of their widths or memory layouts.
+	#[inline]
+	fn eq(&self, other: &PackedVec<T2, O2>) -> bool {
+		self.as_packed_slice() == other.as_packed_slice()
+	}
+}
+
+impl<T, O> Eq for PackedVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Debug for PackedVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(fmt, "PackedVec<u{}> ", self.width)?;
+		fmt.debug_list().entries(self.iter()).finish()
+	}
+}
@@ -0,0 +1,380 @@
+#![doc = include_str!("../doc/poly.md")]
+#![cfg(feature = "alloc")]
+
+use core::mem;
+
+use crate::{
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+mod ops;
+mod tests;
+mod traits;
+
+#[doc = include_str!("../doc/poly/BitPoly.md")]
+pub struct BitPoly<T = usize, O = Lsb0>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The coefficients, with the coefficient of `x^n` at index `n`. The last
+	/// bit, if any, is always `1`.
+	coeffs: BitVec<T, O>,
+}
+
+/// Constructors and conversions.
+impl<T, O> BitPoly<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Creates the zero polynomial.
+	///
+	/// This does not allocate.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::poly::BitPoly;
+	///
+	/// let zero = BitPoly::<u8>::new();
+	/// assert!(zero.is_zero());
+	/// assert_eq!(zero.degree(), None);
+	/// ```
+	#[inline]
+	pub fn new() -> Self {
+		Self {
+			coeffs: BitVec::new(),
+		}
+	}
+
+	/// Creates the constant polynomial `1`.
+	#[inline]
+	pub fn one() -> Self {
+		Self::monomial(0)
+	}
+
+	/// Creates the polynomial `x^degree`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::poly::BitPoly;
+	///
+	/// let x3 = BitPoly::<u8>::monomial(3);
+	/// assert_eq!(x3.degree(), Some(3));
+	/// assert_eq!(x3.to_string(), "x^3");
+	/// ```
+	#[inline]
+	pub fn monomial(degree: usize) -> Self {
+		let mut coeffs = BitVec::repeat(false, degree + 1);
+		coeffs.set(degree, true);
+		Self { coeffs }
+	}
+
+	/// Creates a polynomial whose coefficients are the bits of a bit-vector,
+	/// with the coefficient of `x^n` at index `n`.
+	///
+	/// Trailing `0` bits are removed from the bit-vector.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::poly::BitPoly;
+	///
+	/// let poly = BitPoly::from_bitvec(bitvec![1, 1, 0, 1, 0, 0]);
+	/// assert_eq!(poly.degree(), Some(3));
+	/// assert_eq!(poly.to_string(), "x^3 + x + 1");
+	/// ```
+	#[inline]
+	pub fn from_bitvec(coeffs: BitVec<T, O>) -> Self {
+		let mut out = Self { coeffs };
+		out.trim();
+		out
+	}
+
+	/// Creates a polynomial whose coefficients are copied from a bit-slice,
+	/// with the coefficient of `x^n` at index `n`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::poly::BitPoly;
+	///
+	/// // x^8 + x^4 + x^3 + x + 1
+	/// let aes = BitPoly::from_bitslice(0x11Bu16.view_bits::<Lsb0>());
+	/// assert_eq!(aes.degree(), Some(8));
+	/// ```
+	#[inline]
+	pub fn from_bitslice(coeffs: &BitSlice<T, O>) -> Self {
+		Self::from_bitvec(BitVec::from_bitslice(coeffs))
+	}
+
+	/// Views the coefficients of the polynomial.
+	///
+	/// The bit at index `n` is the coefficient of `x^n`. The bit-slice always
+	/// ends with the leading coefficient, so it is empty for the zero
+	/// polynomial.
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<T, O> {
+		self.coeffs.as_bitslice()
+	}
+
+	/// Unwraps the bit-vector that holds the coefficients.
+	#[inline]
+	pub fn into_bitvec(self) -> BitVec<T, O> {
+		self.coeffs
+	}
+}
+
+/// Inspection.
+impl<T, O> BitPoly<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Gets the degree of the polynomial.
+	///
+	/// This is a constant-time operation.
+	///
+	/// ## Returns
+	///
+	/// The exponent of the leading term, or `None` for the zero polynomial,
+	/// which has no terms.
+	#[inline]
+	pub fn degree(&self) -> Option<usize> {
+		self.coeffs.len().checked_sub(1)
+	}
+
+	/// Tests if this is the zero polynomial.
+	#[inline]
+	pub fn is_zero(&self) -> bool {
+		self.coeffs.is_empty()
+	}
+
+	/// Tests if this is the constant polynomial `1`.
+	#[inline]
+	pub fn is_one(&self) -> bool {
+		self.coeffs.len() == 1
+	}
+
+	/// Gets the coefficient of `x^power`.
+	///
+	/// Powers above the degree have a coefficient of `0`.
+	#[inline]
+	pub fn coeff(&self, power: usize) -> bool {
+		self.coeffs.get(power).map(|bit| *bit).unwrap_or(false)
+	}
+}
+
+/// Arithmetic.
+///
+/// Coefficients are added without carries, so addition and subtraction are
+/// both the exclusive-or of the coefficients, and are provided by the `+`
+/// operator.
+impl<T, O> BitPoly<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Multiplies two polynomials without carries.
+	///
+	/// This is also available as the `*` operator.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::poly::BitPoly;
+	///
+	/// // (x + 1) * (x + 1) = x^2 + 1
+	/// let a = BitPoly::from_bitvec(bitvec![1, 1]);
+	/// assert_eq!(a.clmul(&a).to_string(), "x^2 + 1");
+	/// ```
+	#[inline]
+	pub fn clmul(&self, rhs: &Self) -> Self {
+		let (short, long) = if self.coeffs.len() <= rhs.coeffs.len() {
+			(self, rhs)
+		}
+		else {
+			(rhs, self)
+		};
+		let (ds, dl) = match (short.degree(), long.degree()) {
+			(Some(ds), Some(dl)) => (ds, dl),
+			_ => return Self::new(),
+		};
+		let mut coeffs = BitVec::repeat(false, ds + dl + 1);
+		for idx in short.coeffs.iter_ones() {
+			coeffs[idx ..= idx + dl] ^= long.as_bitslice();
+		}
+		Self { coeffs }
+	}
+
+	/// Divides one polynomial by another, producing the quotient and the
+	/// remainder.
+	///
+	/// These are also available individually as the `/` and `%` operators.
+	///
+	/// ## Panics
+	///
+	/// This panics if `divisor` is the zero polynomial.
+	///
+	/// ## Returns
+	///
+	/// The quotient `q` and remainder `r` such that `self = q * divisor + r`,
+	/// where the degree of `r` is less than the degree of `divisor`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::poly::BitPoly;
+	///
+	/// let a = BitPoly::from_bitvec(bitvec![1, 0, 1, 1, 1]);
+	/// let b = BitPoly::from_bitvec(bitvec![1, 1]);
+	/// let (q, r) = a.div_rem(&b);
+	/// assert_eq!(q.to_string(), "x^3 + x + 1");
+	/// assert!(r.is_zero());
+	/// ```
+	#[inline]
+	pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+		let mut rem = self.clone();
+		let quot = rem.reduce(divisor, true);
+		(Self { coeffs: quot }, rem)
+	}
+
+	/// Computes the greatest common divisor of two polynomials.
+	///
+	/// Over GF(2), every nonzero polynomial is monic, so the result is unique.
+	/// The greatest common divisor of two zero polynomials is zero.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::poly::BitPoly;
+	///
+	/// // (x + 1)(x^2 + x + 1) and (x + 1)x
+	/// let a = BitPoly::from_bitvec(bitvec![1, 0, 0, 1]);
+	/// let b = BitPoly::from_bitvec(bitvec![0, 1, 1]);
+	/// assert_eq!(a.gcd(&b).to_string(), "x + 1");
+	/// ```
+	#[inline]
+	pub fn gcd(&self, other: &Self) -> Self {
+		let mut a = self.clone();
+		let mut b = other.clone();
+		while !b.is_zero() {
+			a.reduce(&b, false);
+			mem::swap(&mut a, &mut b);
+		}
+		a
+	}
+
+	/// Multiplies two polynomials, and reduces the product modulo a third.
+	///
+	/// ## Panics
+	///
+	/// This panics if `modulus` is the zero polynomial.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::poly::BitPoly;
+	///
+	/// let aes = BitPoly::from_bitslice(0x11Bu16.view_bits::<Lsb0>());
+	/// let a = BitPoly::from_bitslice(0x53u16.view_bits::<Lsb0>());
+	/// let b = BitPoly::from_bitslice(0xCAu16.view_bits::<Lsb0>());
+	/// assert!(a.mul_mod(&b, &aes).is_one());
+	/// ```
+	#[inline]
+	pub fn mul_mod(&self, rhs: &Self, modulus: &Self) -> Self {
+		let mut out = self.clmul(rhs);
+		out.reduce(modulus, false);
+		out
+	}
+
+	/// Raises a polynomial to a power, modulo another polynomial.
+	///
+	/// The exponent is a bit-slice read as a little-endian unsigned integer,
+	/// with its least significant bit at index `0`, so it may be of any width.
+	///
+	/// ## Panics
+	///
+	/// This panics if `modulus` is the zero polynomial.
+	///
+	/// ## Examples
+	///
+	/// Inversion in GF(2^8), as `a^(2^8 - 2)`:
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::poly::BitPoly;
+	///
+	/// let aes = BitPoly::from_bitslice(0x11Bu16.view_bits::<Lsb0>());
+	/// let a = BitPoly::from_bitslice(0x53u16.view_bits::<Lsb0>());
+	/// let inv = a.pow_mod(254u8.view_bits::<Lsb0>(), &aes);
+	/// assert_eq!(inv, BitPoly::from_bitslice(0xCAu16.view_bits::<Lsb0>()));
+	/// ```
+	#[inline]
+	pub fn pow_mod<T2, O2>(&self, exp: &BitSlice<T2, O2>, modulus: &Self) -> Self
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let mut base = self.clone();
+		base.reduce(modulus, false);
+		let mut out = Self::one();
+		out.reduce(modulus, false);
+		for bit in exp.iter().by_vals().rev() {
+			out = out.mul_mod(&out, modulus);
+			if bit {
+				out = out.mul_mod(&base, modulus);
+			}
+		}
+		out
+	}
+
+	/// Replaces the polynomial with its remainder after division by
+	/// `divisor`, optionally collecting the coefficients of the quotient.
+	///
+	/// Each step cancels the current leading term by adding a shifted copy of
+	/// the divisor, then searches downward from it for the next leading term.
+	fn reduce(&mut self, divisor: &Self, quotient: bool) -> BitVec<T, O> {
+		let dd = divisor
+			.degree()
+			.expect("attempt to divide a polynomial by zero");
+		let mut quot = BitVec::new();
+		let mut top = self.degree();
+		if quotient {
+			if let Some(deg) = top.filter(|deg| *deg >= dd) {
+				quot.resize(deg - dd + 1, false);
+			}
+		}
+		while let Some(deg) = top.filter(|deg| *deg >= dd) {
+			let shift = deg - dd;
+			if quotient {
+				quot.set(shift, true);
+			}
+			self.coeffs[shift ..= deg] ^= divisor.as_bitslice();
+			top = self.coeffs[.. deg].last_one();
+		}
+		self.coeffs.truncate(top.map(|deg| deg + 1).unwrap_or(0));
+		quot
+	}
+
+	/// Removes trailing `0` bits from the coefficients, restoring the
+	/// invariant that the last bit is the leading coefficient.
+	fn trim(&mut self) {
+		let len = self.coeffs.last_one().map(|idx| idx + 1).unwrap_or(0);
+		self.coeffs.truncate(len);
+	}
+}
@@ -0,0 +1,100 @@
+//! Operator trait implementations for polynomials.
+
+use core::ops::{
+	Add,
+	AddAssign,
+	Div,
+	Mul,
+	Rem,
+};
+
+use super::BitPoly;
+use crate::{
+	order::BitOrder,
+	store::BitStore,
+};
+
+/// Polynomials add by the exclusive-or of their coefficients. This is also
+/// their subtraction.
+impl<T, O> Add for &BitPoly<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Output = BitPoly<T, O>;
+
+	#[inline]
+	fn add(self, rhs: Self) -> Self::Output {
+		let mut out = self.clone();
+		out += rhs;
+		out
+	}
+}
+
+impl<T, O> AddAssign<&BitPoly<T, O>> for BitPoly<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn add_assign(&mut self, rhs: &BitPoly<T, O>) {
+		let len = rhs.coeffs.len();
+		if len > self.coeffs.len() {
+			self.coeffs.resize(len, false);
+		}
+		self.coeffs[.. len] ^= rhs.as_bitslice();
+		self.trim();
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Mul for &BitPoly<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Output = BitPoly<T, O>;
+
+	#[inline]
+	fn mul(self, rhs: Self) -> Self::Output {
+		self.clmul(rhs)
+	}
+}
+
+/// Polynomial division discards the remainder.
+///
+/// ## Panics
+///
+/// This panics if `rhs` is the zero polynomial.
+impl<T, O> Div for &BitPoly<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Output = BitPoly<T, O>;
+
+	#[inline]
+	fn div(self, rhs: Self) -> Self::Output {
+		self.div_rem(rhs).0
+	}
+}
+
+/// Polynomial remainder does not compute the quotient.
+///
+/// ## Panics
+///
+/// This panics if `rhs` is the zero polynomial.
+impl<T, O> Rem for &BitPoly<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Output = BitPoly<T, O>;
+
+	#[inline]
+	fn rem(self, rhs: Self) -> Self::Output {
+		let mut out = self.clone();
+		out.reduce(rhs, false);
+		out
+	}
+}
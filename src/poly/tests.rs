@@ -0,0 +1,140 @@
+//! Unit tests for polynomials.
+
+#![cfg(test)]
+
+use rand::random;
+
+use super::*;
+use crate::{
+	order::HiLo,
+	prelude::*,
+};
+
+/// Builds a polynomial from the bits of an integer.
+fn poly<T, O>(val: u128) -> BitPoly<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let words = [val as u64, (val >> 64) as u64];
+	BitPoly::from_bitvec(words.view_bits::<Lsb0>().iter().by_vals().collect())
+}
+
+/// Carry-less multiplication of integers.
+fn clmul(a: u64, b: u64) -> u128 {
+	(0 .. 64)
+		.filter(|idx| a >> idx & 1 == 1)
+		.fold(0, |acc, idx| acc ^ (b as u128) << idx)
+}
+
+/// Polynomial division of integers.
+fn div_rem(mut a: u128, b: u128) -> (u128, u128) {
+	let db = 127 - b.leading_zeros();
+	let mut q = 0;
+	while a != 0 && 127 - a.leading_zeros() >= db {
+		let shift = 127 - a.leading_zeros() - db;
+		q |= 1 << shift;
+		a ^= b << shift;
+	}
+	(q, a)
+}
+
+#[test]
+fn basics() {
+	let zero = BitPoly::<u8>::new();
+	assert!(zero.is_zero());
+	assert_eq!(zero.degree(), None);
+	assert_eq!(zero.to_string(), "0");
+	assert!(BitPoly::<u8>::one().is_one());
+	assert_eq!(BitPoly::<u8>::default(), zero);
+
+	let p = BitPoly::<u16, Msb0>::from_bitvec(bitvec![u16, Msb0; 1, 0, 1, 1, 0]);
+	assert_eq!(p.degree(), Some(3));
+	assert!(p.coeff(3));
+	assert!(!p.coeff(1));
+	assert!(!p.coeff(100));
+	assert_eq!(p.to_string(), "x^3 + x^2 + 1");
+	assert_eq!(p, poly::<u32, HiLo>(0b1101));
+	assert_eq!(BitVec::from(p.clone()), bits![1, 0, 1, 1]);
+
+	#[cfg(feature = "std")]
+	assert_eq!(format!("{:?}", p), "BitPoly(x^3 + x^2 + 1)");
+
+	assert!((&p + &p).is_zero());
+	assert_eq!(&p + &BitPoly::one(), poly::<u8, Lsb0>(0b1100));
+	let mut q = BitPoly::monomial(10);
+	q += &p;
+	assert_eq!(q.to_string(), "x^10 + x^3 + x^2 + 1");
+	assert!((&zero * &BitPoly::monomial(4)).is_zero());
+}
+
+#[test]
+fn against_integers() {
+	fn check<T, O>()
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		for _ in 0 .. 50 {
+			let a = random::<u64>() >> (random::<u32>() % 64);
+			let b = random::<u64>() >> (random::<u32>() % 64) | 1;
+			let (pa, pb) = (poly::<T, O>(a as u128), poly::<T, O>(b as u128));
+
+			let prod = &pa * &pb;
+			assert_eq!(prod, poly::<T, O>(clmul(a, b)));
+			assert_eq!(&pa + &pb, poly::<T, O>((a ^ b) as u128));
+
+			let (q, r) = div_rem(a as u128, b as u128);
+			let (pq, pr) = pa.div_rem(&pb);
+			assert_eq!(pq, poly::<T, O>(q));
+			assert_eq!(pr, poly::<T, O>(r));
+			assert_eq!(&pa / &pb, pq);
+			assert_eq!(&pa % &pb, pr);
+			assert_eq!(&(&pq * &pb) + &pr, pa);
+
+			assert_eq!(&prod / &pb, pa);
+			assert!(pa.is_zero() || (&prod % &pa).is_zero());
+		}
+	}
+
+	check::<u8, Lsb0>();
+	check::<u16, Msb0>();
+	check::<u32, HiLo>();
+	check::<usize, Lsb0>();
+}
+
+#[test]
+fn gcd_and_powers() {
+	for _ in 0 .. 20 {
+		let a = poly::<u8, Msb0>(random::<u64>() as u128);
+		let b = poly::<u8, Msb0>(random::<u64>() as u128);
+		let c = poly::<u8, Msb0>(random::<u32>() as u128 | 1);
+		let g = (&a * &c).gcd(&(&b * &c));
+		assert!((&g % &c).is_zero());
+		assert!((&(&a * &c) % &g).is_zero());
+		assert!((&(&b * &c) % &g).is_zero());
+	}
+	assert!(BitPoly::<u8>::new().gcd(&BitPoly::new()).is_zero());
+
+	let modulus = poly::<u16, Lsb0>(random::<u64>() as u128 | 1 << 64);
+	let base = poly::<u16, Lsb0>(random::<u128>());
+	let mut acc = BitPoly::one();
+	for exp in 0u16 .. 100 {
+		assert_eq!(base.pow_mod(exp.view_bits::<Lsb0>(), &modulus), acc);
+		acc = acc.mul_mod(&base, &modulus);
+	}
+	assert!(base.pow_mod(bits![1; 8], &BitPoly::<u16>::one()).is_zero());
+
+	// x^(2^8 - 1) = 1 in GF(2^8) for every nonzero element.
+	let aes = poly::<u8, Lsb0>(0x11B);
+	for val in 1 .. 256 {
+		let elt = poly::<u8, Lsb0>(val);
+		assert!(elt.pow_mod(255u8.view_bits::<Lsb0>(), &aes).is_one());
+	}
+}
+
+#[test]
+#[should_panic = "attempt to divide a polynomial by zero"]
+fn div_by_zero() {
+	let _ = &BitPoly::<u8>::one() % &BitPoly::new();
+}
@@ -0,0 +1,147 @@
+//! General trait implementations for polynomials.
+
+use core::{
+	fmt::{
+		self,
+		Debug,
+		Display,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+};
+
+use super::BitPoly;
+use crate::{
+	order::BitOrder,
+	store::BitStore,
+	vec::BitVec,
+};
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Clone for BitPoly<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			coeffs: self.coeffs.clone(),
+		}
+	}
+}
+
+impl<T, O> Eq for BitPoly<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+}
+
+/// Polynomials are equal when they have the same coefficients, regardless of
+/// their capacity or memory layout.
+#[cfg(not(tarpaulin_include))]
+impl<T1, T2, O1, O2> PartialEq<BitPoly<T2, O2>> for BitPoly<T1, O1>
+where
+	T1: BitStore,
+	T2: BitStore,
+	O1: BitOrder,
+	O2: BitOrder,
+{
+	#[inline]
+	fn eq(&self, other: &BitPoly<T2, O2>) -> bool {
+		self.as_bitslice() == other.as_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Hash for BitPoly<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn hash<H>(&self, state: &mut H)
+	where H: Hasher {
+		self.as_bitslice().hash(state)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Default for BitPoly<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Debug for BitPoly<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(fmt, "BitPoly(")?;
+		Display::fmt(self, fmt)?;
+		write!(fmt, ")")
+	}
+}
+
+/// Renders the polynomial as a sum of terms in descending order of degree, such
+/// as `x^3 + x + 1`. The zero polynomial renders as `0`.
+impl<T, O> Display for BitPoly<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		if self.is_zero() {
+			return fmt.write_str("0");
+		}
+		for (idx, power) in self.coeffs.iter_ones().rev().enumerate() {
+			if idx > 0 {
+				fmt.write_str(" + ")?;
+			}
+			match power {
+				0 => fmt.write_str("1")?,
+				1 => fmt.write_str("x")?,
+				n => write!(fmt, "x^{}", n)?,
+			}
+		}
+		Ok(())
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> From<BitVec<T, O>> for BitPoly<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(coeffs: BitVec<T, O>) -> Self {
+		Self::from_bitvec(coeffs)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> From<BitPoly<T, O>> for BitVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(poly: BitPoly<T, O>) -> Self {
+		poly.into_bitvec()
+	}
+}
@@ -0,0 +1,370 @@
+#![doc = include_str!("../doc/rank.md")]
+#![cfg(feature = "alloc")]
+
+use alloc::vec::Vec;
+use core::{
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	ops::Range,
+};
+
+use crate::{
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+};
+
+mod tests;
+
+/// The number of bits summarized by each entry in the block table.
+const BLOCK_BITS: usize = 512;
+
+/// The number of bits summarized by each entry in the superblock table.
+///
+/// This must be small enough that the number of `1` bits which can precede a
+/// block within its superblock always fits in a `u16`.
+const SUPER_BITS: usize = 1 << 16;
+
+/// The number of blocks in each superblock.
+const BLOCKS_PER_SUPER: usize = SUPER_BITS / BLOCK_BITS;
+
+#[doc = include_str!("../doc/rank/RankSelect.md")]
+#[derive(Clone)]
+pub struct RankSelect<'a, T = usize, O = Lsb0>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The indexed bit-slice.
+	bits:   &'a BitSlice<T, O>,
+	/// The number of `1` bits before the start of each superblock.
+	supers: Vec<usize>,
+	/// The number of `1` bits between the start of a block’s superblock and
+	/// the start of the block.
+	blocks: Vec<u16>,
+	/// The total number of `1` bits in `bits`.
+	ones:   usize,
+}
+
+impl<'a, T, O> RankSelect<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Builds a rank/select index over a bit-slice.
+	///
+	/// This walks the entire bit-slice once, counting the `1` bits in each
+	/// block.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let data = [0x0Fu8; 256];
+	/// let index = RankSelect::new(data.view_bits::<Lsb0>());
+	/// assert_eq!(index.count_ones(), 1024);
+	/// ```
+	#[inline]
+	pub fn new(bits: &'a BitSlice<T, O>) -> Self {
+		let len = bits.len();
+		let mut supers = Vec::with_capacity(len / SUPER_BITS + 1);
+		let mut blocks = Vec::with_capacity(len / BLOCK_BITS + 1);
+		let (mut total, mut local) = (0, 0);
+
+		for (idx, block) in bits.chunks(BLOCK_BITS).enumerate() {
+			if idx % BLOCKS_PER_SUPER == 0 {
+				supers.push(total);
+				local = 0;
+			}
+			blocks.push(local as u16);
+			let ones = block.count_ones();
+			total += ones;
+			local += ones;
+		}
+
+		Self {
+			bits,
+			supers,
+			blocks,
+			ones: total,
+		}
+	}
+
+	/// Gets the bit-slice that this index describes.
+	#[inline]
+	pub fn as_bitslice(&self) -> &'a BitSlice<T, O> {
+		self.bits
+	}
+
+	/// Gets the length of the indexed bit-slice.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.bits.len()
+	}
+
+	/// Tests if the indexed bit-slice is empty.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.bits.is_empty()
+	}
+
+	/// Counts the number of bits set to `1` in the indexed bit-slice.
+	///
+	/// This is computed when the index is built, and does not rescan memory.
+	#[inline]
+	pub fn count_ones(&self) -> usize {
+		self.ones
+	}
+
+	/// Counts the number of bits cleared to `0` in the indexed bit-slice.
+	///
+	/// This is computed when the index is built, and does not rescan memory.
+	#[inline]
+	pub fn count_zeros(&self) -> usize {
+		self.len() - self.ones
+	}
+
+	/// Counts the number of bits set to `1` before an index.
+	///
+	/// This is equivalent to `bits[.. index].count_ones()`, but only counts at
+	/// most one block of the bit-slice directly.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is greater than the length of the bit-slice.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let bits = bits![1, 0, 1, 1, 0];
+	/// let index = RankSelect::new(bits);
+	/// assert_eq!(index.rank1(0), 0);
+	/// assert_eq!(index.rank1(3), 2);
+	/// assert_eq!(index.rank1(5), 3);
+	/// ```
+	#[inline]
+	pub fn rank1(&self, index: usize) -> usize {
+		let len = self.len();
+		assert!(
+			index <= len,
+			"rank index {} out of range for bit-slice of length {}",
+			index,
+			len,
+		);
+		if index == len {
+			return self.ones;
+		}
+
+		let block = index / BLOCK_BITS;
+		let start = block * BLOCK_BITS;
+		self.ones_before_block(block)
+			+ unsafe { self.bits.get_unchecked(start .. index) }.count_ones()
+	}
+
+	/// Counts the number of bits cleared to `0` before an index.
+	///
+	/// This is equivalent to `bits[.. index].count_zeros()`, but only counts at
+	/// most one block of the bit-slice directly.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is greater than the length of the bit-slice.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let bits = bits![1, 0, 1, 1, 0];
+	/// let index = RankSelect::new(bits);
+	/// assert_eq!(index.rank0(2), 1);
+	/// assert_eq!(index.rank0(5), 2);
+	/// ```
+	#[inline]
+	pub fn rank0(&self, index: usize) -> usize {
+		index - self.rank1(index)
+	}
+
+	/// Finds the index of the `1` bit that has exactly `rank` `1` bits before
+	/// it.
+	///
+	/// This is equivalent to `bits.iter_ones().nth(rank)`, but only scans at
+	/// most one block of the bit-slice directly.
+	///
+	/// ## Returns
+	///
+	/// The index of the requested bit, or `None` if the bit-slice does not have
+	/// more than `rank` bits set to `1`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let bits = bits![0, 0, 1, 0, 1];
+	/// let index = RankSelect::new(bits);
+	/// assert_eq!(index.select1(0), Some(2));
+	/// assert_eq!(index.select1(1), Some(4));
+	/// assert!(index.select1(2).is_none());
+	/// ```
+	#[inline]
+	pub fn select1(&self, rank: usize) -> Option<usize> {
+		if rank >= self.count_ones() {
+			return None;
+		}
+		let block = self.find_block(rank, true);
+		let start = block * BLOCK_BITS;
+		self.block_bits(block)
+			.iter_ones()
+			.nth(rank - self.ones_before_block(block))
+			.map(|idx| start + idx)
+	}
+
+	/// Finds the index of the `0` bit that has exactly `rank` `0` bits before
+	/// it.
+	///
+	/// This is equivalent to `bits.iter_zeros().nth(rank)`, but only scans at
+	/// most one block of the bit-slice directly.
+	///
+	/// ## Returns
+	///
+	/// The index of the requested bit, or `None` if the bit-slice does not have
+	/// more than `rank` bits cleared to `0`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let bits = bits![1, 1, 0, 1, 0];
+	/// let index = RankSelect::new(bits);
+	/// assert_eq!(index.select0(0), Some(2));
+	/// assert_eq!(index.select0(1), Some(4));
+	/// assert!(index.select0(2).is_none());
+	/// ```
+	#[inline]
+	pub fn select0(&self, rank: usize) -> Option<usize> {
+		if rank >= self.count_zeros() {
+			return None;
+		}
+		let block = self.find_block(rank, false);
+		let start = block * BLOCK_BITS;
+		self.block_bits(block)
+			.iter_zeros()
+			.nth(rank - (start - self.ones_before_block(block)))
+			.map(|idx| start + idx)
+	}
+
+	/// Counts the `1` bits before the start of a block.
+	fn ones_before_block(&self, block: usize) -> usize {
+		self.supers[block / BLOCKS_PER_SUPER] + self.blocks[block] as usize
+	}
+
+	/// Counts the bits of a given value before the start of a block.
+	fn count_before_block(&self, block: usize, value: bool) -> usize {
+		let ones = self.ones_before_block(block);
+		if value {
+			ones
+		}
+		else {
+			block * BLOCK_BITS - ones
+		}
+	}
+
+	/// Counts the bits of a given value before the start of a superblock.
+	fn count_before_super(&self, sup: usize, value: bool) -> usize {
+		let ones = self.supers[sup];
+		if value {
+			ones
+		}
+		else {
+			sup * SUPER_BITS - ones
+		}
+	}
+
+	/// Finds the block that contains the bit of a given value with `rank`
+	/// other bits of that value before it.
+	///
+	/// The caller must ensure that such a bit exists.
+	fn find_block(&self, rank: usize, value: bool) -> usize {
+		let sup = partition_point(0 .. self.supers.len(), |sup| {
+			self.count_before_super(sup, value) <= rank
+		}) - 1;
+		let first = sup * BLOCKS_PER_SUPER;
+		let last = (first + BLOCKS_PER_SUPER).min(self.blocks.len());
+		partition_point(first .. last, |block| {
+			self.count_before_block(block, value) <= rank
+		}) - 1
+	}
+
+	/// Views the bits governed by a single block.
+	fn block_bits(&self, block: usize) -> &'a BitSlice<T, O> {
+		let start = block * BLOCK_BITS;
+		let end = (start + BLOCK_BITS).min(self.len());
+		unsafe { self.bits.get_unchecked(start .. end) }
+	}
+}
+
+impl<'a, T, O> From<&'a BitSlice<T, O>> for RankSelect<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(bits: &'a BitSlice<T, O>) -> Self {
+		Self::new(bits)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Debug for RankSelect<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("RankSelect")
+			.field("len", &self.len())
+			.field("ones", &self.ones)
+			.field("superblocks", &self.supers.len())
+			.field("blocks", &self.blocks.len())
+			.finish()
+	}
+}
+
+/// Finds the first index in a range for which a predicate fails.
+///
+/// The predicate must hold for some prefix of the range and fail for the
+/// remaining suffix. This is `<[_]>::partition_point`, applied to a range of
+/// indices rather than to a materialized slice.
+fn partition_point(
+	range: Range<usize>,
+	mut pred: impl FnMut(usize) -> bool,
+) -> usize {
+	let Range { mut start, mut end } = range;
+	while start < end {
+		let mid = start + (end - start) / 2;
+		if pred(mid) {
+			start = mid + 1;
+		}
+		else {
+			end = mid;
+		}
+	}
+	start
+}
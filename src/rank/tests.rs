@@ -0,0 +1,79 @@
+//! Unit tests for rank/select indices.
+
+#![cfg(test)]
+
+use rand::random;
+
+use super::*;
+use crate::prelude::*;
+
+#[test]
+fn empty() {
+	let index = RankSelect::new(BitSlice::<u8, Lsb0>::empty());
+	assert!(index.is_empty());
+	assert_eq!(index.rank1(0), 0);
+	assert_eq!(index.rank0(0), 0);
+	assert!(index.select1(0).is_none());
+	assert!(index.select0(0).is_none());
+}
+
+#[test]
+#[should_panic]
+fn rank_out_of_bounds() {
+	RankSelect::new(bits![0, 1]).rank1(3);
+}
+
+#[test]
+fn against_naive() {
+	//  Span several superblocks, and start in the interior of an element.
+	let data = (0 .. 4500).map(|_| random::<u64>()).collect::<Vec<_>>();
+	let bits = &data.view_bits::<Msb0>()[3 ..];
+	let index = RankSelect::new(bits);
+	assert_eq!(index.count_ones(), bits.count_ones());
+	assert_eq!(index.count_zeros(), bits.count_zeros());
+
+	for idx in (0 .. bits.len()).step_by(997).chain(Some(bits.len())) {
+		assert_eq!(index.rank1(idx), bits[.. idx].count_ones());
+		assert_eq!(index.rank0(idx), bits[.. idx].count_zeros());
+	}
+
+	for (rank, idx) in bits.iter_ones().enumerate().step_by(613) {
+		assert_eq!(index.select1(rank), Some(idx));
+		assert_eq!(index.rank1(idx), rank);
+	}
+	for (rank, idx) in bits.iter_zeros().enumerate().step_by(613) {
+		assert_eq!(index.select0(rank), Some(idx));
+		assert_eq!(index.rank0(idx), rank);
+	}
+
+	assert_eq!(index.select1(index.count_ones() - 1), bits.last_one());
+	assert_eq!(index.select0(index.count_zeros() - 1), bits.last_zero());
+	assert!(index.select1(index.count_ones()).is_none());
+	assert!(index.select0(index.count_zeros()).is_none());
+}
+
+#[test]
+fn sparse_blocks() {
+	//  Long runs of empty blocks must not confuse the block search.
+	let mut bv = bitvec![u16, Lsb0; 0; 1 << 18];
+	for &idx in &[0, 511, 512, 70_000, 140_000, (1 << 18) - 1] {
+		bv.set(idx, true);
+	}
+	let index = RankSelect::new(bv.as_bitslice());
+
+	assert_eq!(index.select1(0), Some(0));
+	assert_eq!(index.select1(1), Some(511));
+	assert_eq!(index.select1(2), Some(512));
+	assert_eq!(index.select1(3), Some(70_000));
+	assert_eq!(index.select1(4), Some(140_000));
+	assert_eq!(index.select1(5), Some((1 << 18) - 1));
+	assert_eq!(index.rank1(140_000), 4);
+	assert_eq!(index.rank1(140_001), 5);
+	assert_eq!(index.select0(0), Some(1));
+	assert_eq!(index.select0(510), Some(513));
+
+	let bits = bits![1; 1000];
+	let ones = RankSelect::new(bits);
+	assert!(ones.select0(0).is_none());
+	assert_eq!(ones.select1(999), Some(999));
+}
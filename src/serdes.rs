@@ -3,6 +3,7 @@
 
 mod array;
 mod slice;
+mod text;
 mod utils;
 
 use core::fmt::{
@@ -16,6 +17,11 @@ use serde::de::{
 	Visitor,
 };
 
+pub use self::text::{
+	binary,
+	hex,
+};
+
 /// A result of serialization.
 type Result<S> = core::result::Result<
 	<S as serde::Serializer>::Ok,
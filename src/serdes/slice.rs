@@ -18,6 +18,7 @@ use serde::{
 		Error,
 		MapAccess,
 		SeqAccess,
+		Unexpected,
 		Visitor,
 	},
 	ser::{
@@ -29,7 +30,10 @@ use serde::{
 use wyz::comu::Const;
 
 use super::{
-	utils::TypeName,
+	utils::{
+		Borrowed,
+		TypeName,
+	},
 	Field,
 	FIELDS,
 };
@@ -40,7 +44,10 @@ use crate::{
 };
 use crate::{
 	index::BitIdx,
-	mem::bits_of,
+	mem::{
+		bits_of,
+		BitRegister,
+	},
 	order::BitOrder,
 	ptr::{
 		AddressExt,
@@ -100,8 +107,10 @@ where
 	}
 }
 
-impl<'de, O> Deserialize<'de> for &'de BitSlice<u8, O>
-where O: BitOrder
+impl<'de, T, O> Deserialize<'de> for &'de BitSlice<T, O>
+where
+	T: BitRegister + BitStore,
+	O: BitOrder,
 {
 	#[inline]
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -109,9 +118,9 @@ where O: BitOrder
 		deserializer.deserialize_struct(
 			"BitSeq",
 			FIELDS,
-			BitSeqVisitor::<u8, O, &'de [u8], Self, _>::new(
+			BitSeqVisitor::<T, O, Borrowed<'de, T>, Self, _>::new(
 				|data, head, bits| unsafe {
-					BitSpan::new(data.as_ptr().into_address(), head, bits)
+					BitSpan::new(data.inner.as_ptr().into_address(), head, bits)
 						.map(|span| BitSpan::into_bitslice_ref(span))
 				},
 			),
@@ -189,7 +198,7 @@ impl<'de, T, O, In, Out, Func> BitSeqVisitor<T, O, In, Out, Func>
 where
 	T: 'de + BitStore,
 	O: BitOrder,
-	In: Deserialize<'de>,
+	In: Deserialize<'de> + AsRef<[T]>,
 	Func: FnOnce(In, BitIdx<T::Mem>, usize) -> Result<Out, BitSpanError<T>>,
 {
 	/// Creates a new visitor with a given transform functor.
@@ -214,7 +223,14 @@ where
 		let bits = self.bits.take().ok_or_else(|| E::missing_field("bits"))?;
 		let data = self.data.take().ok_or_else(|| E::missing_field("data"))?;
 
-		(self.func)(data, head, bits as usize).map_err(|_| todo!())
+		//  The live bits must lie entirely within the data buffer.
+		let capa = (data.as_ref().len() as u64)
+			.saturating_mul(bits_of::<T::Mem>() as u64);
+		if bits > capa.saturating_sub(head.into_inner() as u64) {
+			return Err(E::invalid_value(Unexpected::Unsigned(bits), &self));
+		}
+
+		(self.func)(data, head, bits as usize).map_err(E::custom)
 	}
 }
 
@@ -223,7 +239,7 @@ impl<'de, T, O, In, Out, Func> Visitor<'de>
 where
 	T: 'de + BitStore,
 	O: BitOrder,
-	In: Deserialize<'de>,
+	In: Deserialize<'de> + AsRef<[T]>,
 	Func: FnOnce(In, BitIdx<T::Mem>, usize) -> Result<Out, BitSpanError<T>>,
 {
 	type Value = Out;
@@ -299,6 +315,10 @@ mod tests {
 	use alloc::format;
 	use core::any;
 
+	use serde::{
+		Serialize,
+		Serializer,
+	};
 	use serde_test::{
 		assert_de_tokens,
 		assert_de_tokens_error,
@@ -306,7 +326,10 @@ mod tests {
 		Token,
 	};
 
-	use crate::prelude::*;
+	use crate::{
+		index::BitIdx,
+		prelude::*,
+	};
 
 	#[test]
 	#[cfg(feature = "alloc")]
@@ -315,6 +338,12 @@ mod tests {
 		let encoded = bincode::serialize(&bits)?;
 		let bits2 = bincode::deserialize::<&BitSlice<u8, Msb0>>(&encoded)?;
 		assert_eq!(bits, bits2);
+		//  The bit-slice views the data buffer in place, at the end of the
+		//  stream.
+		assert_eq!(
+			bits2.as_bitptr().pointer(),
+			&encoded[encoded.len() - 1] as *const u8,
+		);
 		Ok(())
 	}
 
@@ -456,5 +485,161 @@ mod tests {
 			],
 			"duplicate field `data`",
 		);
+
+		assert_de_tokens_error::<&BitSlice<u8, Msb0>>(
+			&[
+				Token::Seq { len: Some(4) },
+				Token::BorrowedStr(any::type_name::<Msb0>()),
+				Token::Seq { len: Some(2) },
+				Token::U8(8),
+				Token::U8(2),
+				Token::SeqEnd,
+				Token::U64(15),
+				Token::BorrowedBytes(&[0x3C, 0xA5]),
+				Token::SeqEnd,
+			],
+			&format!(
+				"invalid value: integer `15`, expected a `BitSlice<u8, {}>`",
+				any::type_name::<Msb0>(),
+			),
+		);
+		assert_de_tokens_error::<BitVec<u8, Msb0>>(
+			&[
+				Token::Seq { len: Some(4) },
+				Token::BorrowedStr(any::type_name::<Msb0>()),
+				Token::Seq { len: Some(2) },
+				Token::U8(8),
+				Token::U8(0),
+				Token::SeqEnd,
+				Token::U64(9),
+				Token::Seq { len: Some(1) },
+				Token::U8(0x3C),
+				Token::SeqEnd,
+				Token::SeqEnd,
+			],
+			&format!(
+				"invalid value: integer `9`, expected a `BitSlice<u8, {}>`",
+				any::type_name::<Msb0>(),
+			),
+		);
+		assert_de_tokens_error::<&BitSlice<u16, Lsb0>>(
+			&[
+				Token::Seq { len: Some(4) },
+				Token::BorrowedStr(any::type_name::<Lsb0>()),
+				Token::Seq { len: Some(2) },
+				Token::U8(16),
+				Token::U8(0),
+				Token::SeqEnd,
+				Token::U64(16),
+				Token::BorrowedBytes(&[0x3C, 0xA5, 0x00]),
+				Token::SeqEnd,
+			],
+			"invalid length 3, expected a borrowed byte buffer of `u16`",
+		);
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn borrow_wide() -> Result<(), alloc::boxed::Box<bincode::ErrorKind>> {
+		/// Writes the data buffer as a byte string.
+		struct Bytes<'a>(&'a [u8]);
+
+		impl Serialize for Bytes<'_> {
+			fn serialize<S>(&self, serializer: S) -> super::super::Result<S>
+			where S: Serializer {
+				serializer.serialize_bytes(self.0)
+			}
+		}
+
+		let elts = [0x1234u32, 0x5678_9ABC];
+		let raw = unsafe {
+			core::slice::from_raw_parts(
+				elts.as_ptr().cast::<u8>(),
+				core::mem::size_of_val(&elts),
+			)
+		};
+		let encoded = bincode::serialize(&(
+			any::type_name::<Lsb0>(),
+			BitIdx::<u32>::new(4).unwrap(),
+			40u64,
+			Bytes(raw),
+		))?;
+
+		//  Place the stream so that its data buffer is first aligned for `u32`,
+		//  and then not.
+		let mut buf = [0u32; 32];
+		let bytes = unsafe {
+			core::slice::from_raw_parts_mut(
+				buf.as_mut_ptr().cast::<u8>(),
+				core::mem::size_of_val(&buf),
+			)
+		};
+		let start = (4 - (encoded.len() - raw.len()) % 4) % 4;
+		bytes[start .. start + encoded.len()].copy_from_slice(&encoded);
+		let bits = bincode::deserialize::<&BitSlice<u32, Lsb0>>(
+			&bytes[start .. start + encoded.len()],
+		)?;
+		assert_eq!(bits, &elts.view_bits::<Lsb0>()[4 .. 44]);
+		assert_eq!(bits.as_bitptr().pointer().cast::<u8>(), unsafe {
+			bytes.as_ptr().add(start + encoded.len() - raw.len())
+		});
+
+		bytes[start + 1 .. start + 1 + encoded.len()].copy_from_slice(&encoded);
+		let err = bincode::deserialize::<&BitSlice<u32, Lsb0>>(
+			&bytes[start + 1 .. start + 1 + encoded.len()],
+		)
+		.unwrap_err();
+		assert!(err.to_string().contains("requires 4-byte alignment"));
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn borrow_wide_tokens() {
+		/// A transport buffer, aligned for every element type.
+		#[repr(align(8))]
+		struct Aligned([u8; 17]);
+
+		static DATA: Aligned = Aligned([
+			0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0xFE, 0xDC, 0xBA,
+			0x98, 0x76, 0x54, 0x32, 0x10, 0x00,
+		]);
+
+		macro_rules! check {
+			($($elem:ident),+ $(,)?) => { $(
+				let size = core::mem::size_of::<$elem>();
+				let bytes = &DATA.0[.. 2 * size];
+				let elems = [
+					$elem::from_ne_bytes(bytes[.. size].try_into().unwrap()),
+					$elem::from_ne_bytes(bytes[size ..].try_into().unwrap()),
+				];
+				let bits = &elems.view_bits::<Msb0>()[3 .. 2 * size * 8 - 1];
+				let mut tokens = [
+					Token::Seq { len: Some(4) },
+					Token::BorrowedStr(any::type_name::<Msb0>()),
+					Token::Seq { len: Some(2) },
+					Token::U8(crate::mem::bits_of::<$elem>() as u8),
+					Token::U8(3),
+					Token::SeqEnd,
+					Token::U64(bits.len() as u64),
+					Token::BorrowedBytes(bytes),
+					Token::SeqEnd,
+				];
+				assert_de_tokens(&bits, &tokens);
+
+				tokens[7] = Token::BorrowedBytes(&DATA.0[.. 2 * size + 1]);
+				assert_de_tokens_error::<&BitSlice<$elem, Msb0>>(
+					&tokens,
+					&format!(
+						"invalid length {}, expected a borrowed byte buffer \
+						 of `u{}`",
+						2 * size + 1,
+						crate::mem::bits_of::<$elem>(),
+					),
+				);
+			)+ };
+		}
+
+		check!(u16, u32, u64);
 	}
 }
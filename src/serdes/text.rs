@@ -0,0 +1,380 @@
+#![doc=include_str!("../../doc/serdes/text.md")]
+
+use core::{
+	fmt::{
+		self,
+		Display,
+		Formatter,
+		Write,
+	},
+	marker::PhantomData,
+	str::FromStr,
+};
+
+use serde::{
+	de::{
+		value::{
+			MapAccessDeserializer,
+			SeqAccessDeserializer,
+		},
+		Deserialize,
+		Deserializer,
+		Error,
+		MapAccess,
+		SeqAccess,
+		Visitor,
+	},
+	ser::{
+		Serialize,
+		Serializer,
+	},
+};
+
+use crate::{
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+#[doc = include_str!("../../doc/serdes/binary.md")]
+pub mod binary {
+	use core::{
+		fmt::Display,
+		str::FromStr,
+	};
+
+	use serde::{
+		Deserialize,
+		Deserializer,
+		Serialize,
+		Serializer,
+	};
+
+	use super::Radix;
+	use crate::{
+		order::BitOrder,
+		slice::BitSlice,
+		store::BitStore,
+	};
+
+	/// Serializes a bit-sequence as a string of binary digits, if the
+	/// serializer is human-readable.
+	#[inline]
+	pub fn serialize<B, T, O, S>(
+		bits: &B,
+		serializer: S,
+	) -> Result<S::Ok, S::Error>
+	where
+		B: ?Sized + AsRef<BitSlice<T, O>> + Serialize,
+		T: BitStore,
+		O: BitOrder,
+		S: Serializer,
+	{
+		super::serialize(bits, Radix::Binary, serializer)
+	}
+
+	/// Deserializes a bit-sequence from either text or its `serde` structure.
+	#[inline]
+	pub fn deserialize<'de, B, D>(deserializer: D) -> Result<B, D::Error>
+	where
+		B: Deserialize<'de> + FromStr,
+		B::Err: Display,
+		D: Deserializer<'de>,
+	{
+		super::deserialize(deserializer)
+	}
+}
+
+#[doc = include_str!("../../doc/serdes/hex.md")]
+pub mod hex {
+	use core::{
+		fmt::Display,
+		str::FromStr,
+	};
+
+	use serde::{
+		Deserialize,
+		Deserializer,
+		Serialize,
+		Serializer,
+	};
+
+	use super::Radix;
+	use crate::{
+		order::BitOrder,
+		slice::BitSlice,
+		store::BitStore,
+	};
+
+	/// Serializes a bit-sequence as a sized string of hexadecimal digits, if
+	/// the serializer is human-readable.
+	#[inline]
+	pub fn serialize<B, T, O, S>(
+		bits: &B,
+		serializer: S,
+	) -> Result<S::Ok, S::Error>
+	where
+		B: ?Sized + AsRef<BitSlice<T, O>> + Serialize,
+		T: BitStore,
+		O: BitOrder,
+		S: Serializer,
+	{
+		super::serialize(bits, Radix::Hex, serializer)
+	}
+
+	/// Deserializes a bit-sequence from either text or its `serde` structure.
+	#[inline]
+	pub fn deserialize<'de, B, D>(deserializer: D) -> Result<B, D::Error>
+	where
+		B: Deserialize<'de> + FromStr,
+		B::Err: Display,
+		D: Deserializer<'de>,
+	{
+		super::deserialize(deserializer)
+	}
+}
+
+/// The digits in which a bit-sequence is rendered as text.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Radix {
+	/// One binary digit per bit, after a `0b` prefix.
+	Binary,
+	/// One hexadecimal digit per four bits, after a size prefix such as
+	/// `12'h`.
+	Hex,
+}
+
+/// Renders a bit-slice as a single word that `FromStr` can read back.
+struct Text<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The bit-slice being rendered.
+	bits:  &'a BitSlice<T, O>,
+	/// The digits in which to render it.
+	radix: Radix,
+}
+
+impl<T, O> Display for Text<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		//  A prefix with no digits is not a valid word, but the empty list is.
+		if self.bits.is_empty() {
+			return fmt.write_str("[]");
+		}
+		match self.radix {
+			Radix::Binary => {
+				fmt.write_str("0b")?;
+				for bit in self.bits.iter().by_vals() {
+					fmt.write_char(if bit { '1' } else { '0' })?;
+				}
+			},
+			Radix::Hex => {
+				write!(fmt, "{}'h", self.bits.len())?;
+				//  As in the `LowerHex` rendering, the first digit is the one
+				//  that may be partially filled.
+				for chunk in self.bits.rchunks(4).rev() {
+					let digit = chunk
+						.iter()
+						.by_vals()
+						.fold(0usize, |acc, bit| acc << 1 | bit as usize);
+					fmt.write_char(b"0123456789abcdef"[digit] as char)?;
+				}
+			},
+		}
+		Ok(())
+	}
+}
+
+/// Serializes a bit-sequence as text if the serializer is human-readable, and
+/// as its usual structure otherwise.
+fn serialize<B, T, O, S>(
+	bits: &B,
+	radix: Radix,
+	serializer: S,
+) -> super::Result<S>
+where
+	B: ?Sized + AsRef<BitSlice<T, O>> + Serialize,
+	T: BitStore,
+	O: BitOrder,
+	S: Serializer,
+{
+	if serializer.is_human_readable() {
+		serializer.collect_str(&Text {
+			bits: bits.as_ref(),
+			radix,
+		})
+	}
+	else {
+		bits.serialize(serializer)
+	}
+}
+
+/// Deserializes a bit-sequence from text or from its usual structure if the
+/// deserializer is human-readable, and from its usual structure otherwise.
+fn deserialize<'de, B, D>(deserializer: D) -> Result<B, D::Error>
+where
+	B: Deserialize<'de> + FromStr,
+	B::Err: Display,
+	D: Deserializer<'de>,
+{
+	if deserializer.is_human_readable() {
+		deserializer.deserialize_any(TextVisitor(PhantomData))
+	}
+	else {
+		B::deserialize(deserializer)
+	}
+}
+
+/// Visits either a string, which is parsed into a bit-sequence, or the usual
+/// structure, which is forwarded to the bit-sequence’s own deserializer.
+struct TextVisitor<B>(PhantomData<B>);
+
+impl<'de, B> Visitor<'de> for TextVisitor<B>
+where
+	B: Deserialize<'de> + FromStr,
+	B::Err: Display,
+{
+	type Value = B;
+
+	#[inline]
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("a bit-sequence, as text or as a structure")
+	}
+
+	#[inline]
+	fn visit_str<E>(self, text: &str) -> Result<Self::Value, E>
+	where E: Error {
+		text.parse().map_err(E::custom)
+	}
+
+	#[inline]
+	fn visit_seq<V>(self, seq: V) -> Result<Self::Value, V::Error>
+	where V: SeqAccess<'de> {
+		B::deserialize(SeqAccessDeserializer::new(seq))
+	}
+
+	#[inline]
+	fn visit_map<V>(self, map: V) -> Result<Self::Value, V::Error>
+	where V: MapAccess<'de> {
+		B::deserialize(MapAccessDeserializer::new(map))
+	}
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod tests {
+	#[cfg(not(feature = "std"))]
+	use alloc::string::ToString;
+
+	use bincode::Options;
+	use serde_test::{
+		assert_de_tokens,
+		assert_de_tokens_error,
+		assert_tokens,
+		Configure,
+		Readable,
+		Token,
+	};
+
+	use super::*;
+	use crate::prelude::*;
+
+	/// A field that uses the hexadecimal rendering.
+	#[derive(Debug, PartialEq)]
+	struct Hex(BitVec<u8, Msb0>);
+
+	impl Serialize for Hex {
+		fn serialize<S>(&self, serializer: S) -> super::super::Result<S>
+		where S: Serializer {
+			super::hex::serialize(&self.0, serializer)
+		}
+	}
+
+	impl<'de> Deserialize<'de> for Hex {
+		fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+		where D: Deserializer<'de> {
+			super::hex::deserialize(deserializer).map(Self)
+		}
+	}
+
+	#[test]
+	fn render() {
+		let bits = bits![u8, Msb0; 1, 0, 1, 1, 0, 0, 1, 1, 1, 1];
+		let text = |radix| Text { bits, radix }.to_string();
+		assert_eq!(text(Radix::Binary), "0b1011001111");
+		assert_eq!(text(Radix::Hex), "10'h2cf");
+		assert_eq!(text(Radix::Hex).parse::<BitVec<u8, Msb0>>().unwrap(), bits);
+
+		let empty = Text {
+			bits:  BitSlice::<u8, Msb0>::empty(),
+			radix: Radix::Hex,
+		};
+		assert_eq!(empty.to_string(), "[]");
+	}
+
+	#[test]
+	fn tokens() {
+		let hex = Hex(bitvec![u8, Msb0; 1, 0, 1, 1, 0, 0, 1, 1, 1, 1]);
+		assert_tokens(&hex.readable(), &[Token::Str("10'h2cf")]);
+
+		let hex = Hex(bitvec![u8, Msb0; 1, 0, 1, 1, 0, 0, 1, 1, 1, 1]);
+		assert_de_tokens(&hex.readable(), &[Token::Str("0b10_1100_1111")]);
+
+		assert_de_tokens_error::<Readable<Hex>>(
+			&[Token::Str("10'h7cf")],
+			"the digits at byte 4 do not fill exactly 10 bits",
+		);
+		assert_de_tokens_error::<Readable<Hex>>(
+			&[Token::Str("0b012")],
+			"invalid base-2 digit '2' at byte 4",
+		);
+	}
+
+	#[test]
+	fn fallback() {
+		let bits = bitvec![u8, Msb0; 0, 1, 1, 0, 1];
+		//  Human-readable formats still accept the default structure.
+		let json = serde_json::to_string(&bits).unwrap();
+		let mut de = serde_json::Deserializer::from_str(&json);
+		assert_eq!(
+			super::binary::deserialize::<BitVec<u8, Msb0>, _>(&mut de).unwrap(),
+			bits,
+		);
+
+		let arr = bitarr![u8, Msb0; 1, 0, 1, 1, 0, 0, 1, 1];
+		let mut json = Vec::new();
+		super::hex::serialize(&arr, &mut serde_json::Serializer::new(&mut json))
+			.unwrap();
+		assert_eq!(json, br#""8'hb3""#);
+		let mut de = serde_json::Deserializer::from_slice(&json);
+		assert_eq!(
+			super::hex::deserialize::<BitArr!(for 8, in u8, Msb0), _>(&mut de)
+				.unwrap(),
+			arr,
+		);
+
+		//  Other formats use the default structure in both directions.
+		let boxed = bits.clone().into_boxed_bitslice();
+		let opts = bincode::DefaultOptions::new()
+			.with_fixint_encoding()
+			.allow_trailing_bytes();
+		let mut compact = Vec::new();
+		super::hex::serialize(
+			&boxed,
+			&mut bincode::Serializer::new(&mut compact, opts),
+		)
+		.unwrap();
+		assert_eq!(compact, bincode::serialize(&boxed).unwrap());
+		let back: BitBox<u8, Msb0> = super::hex::deserialize(
+			&mut bincode::Deserializer::from_slice(&compact, opts),
+		)
+		.unwrap();
+		assert_eq!(back, boxed);
+	}
+}
@@ -7,7 +7,12 @@ use core::{
 		Formatter,
 	},
 	marker::PhantomData,
-	mem::MaybeUninit,
+	mem::{
+		self,
+		MaybeUninit,
+	},
+	ptr::NonNull,
+	slice,
 };
 
 use serde::{
@@ -28,7 +33,10 @@ use serde::{
 		Serializer,
 	},
 };
-use wyz::comu::Const;
+use wyz::comu::{
+	Address,
+	Const,
+};
 
 use crate::{
 	domain::Domain,
@@ -38,6 +46,7 @@ use crate::{
 		BitRegister,
 	},
 	order::BitOrder,
+	ptr::check_alignment,
 	store::BitStore,
 	view::BitViewSized,
 };
@@ -353,6 +362,81 @@ where R: BitRegister
 	}
 }
 
+/// A data buffer borrowed directly from the transport, without copying.
+///
+/// Serde can only lend out byte strings, so wider elements are viewed by
+/// reinterpreting the borrowed bytes in the host’s byte order. This requires
+/// that the bytes fill a whole number of elements and begin at an address
+/// aligned for `R`.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub(super) struct Borrowed<'a, R>
+where R: BitRegister
+{
+	/// The borrowed data buffer.
+	pub(super) inner: &'a [R],
+}
+
+impl<R> AsRef<[R]> for Borrowed<'_, R>
+where R: BitRegister
+{
+	#[inline]
+	fn as_ref(&self) -> &[R] {
+		self.inner
+	}
+}
+
+impl<'de, R> Deserialize<'de> for Borrowed<'de, R>
+where R: BitRegister
+{
+	#[inline]
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: Deserializer<'de> {
+		deserializer.deserialize_bytes(BorrowedVisitor::<R>(PhantomData))
+	}
+}
+
+/// Assists in deserialization of a borrowed data buffer.
+struct BorrowedVisitor<R>(PhantomData<R>)
+where R: BitRegister;
+
+impl<'de, R> Visitor<'de> for BorrowedVisitor<R>
+where R: BitRegister
+{
+	type Value = Borrowed<'de, R>;
+
+	#[inline]
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(fmt, "a borrowed byte buffer of `u{}`", bits_of::<R>())
+	}
+
+	#[inline]
+	fn visit_borrowed_bytes<E>(
+		self,
+		bytes: &'de [u8],
+	) -> Result<Self::Value, E>
+	where
+		E: Error,
+	{
+		if bytes.len() % mem::size_of::<R>() != 0 {
+			return Err(E::invalid_length(bytes.len(), &self));
+		}
+		if bytes.is_empty() {
+			return Ok(Borrowed { inner: &[] });
+		}
+		let addr = Address::<Const, R>::new(NonNull::from(bytes).cast::<R>());
+		let addr = check_alignment(addr).map_err(E::custom)?;
+		Ok(Borrowed {
+			inner: unsafe {
+				slice::from_raw_parts(
+					addr.to_const(),
+					bytes.len() / mem::size_of::<R>(),
+				)
+			},
+		})
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use serde_test::{
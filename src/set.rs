@@ -0,0 +1,429 @@
+#![doc = include_str!("../doc/set.md")]
+#![cfg(feature = "alloc")]
+
+use core::ops::{
+	BitAnd,
+	BitOr,
+	BitXor,
+};
+
+use crate::{
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::{
+		BitSlice,
+		IterOnes,
+		WORD_BITS,
+	},
+	store::BitStore,
+	vec::BitVec,
+};
+
+mod tests;
+mod traits;
+
+#[doc = include_str!("../doc/set/BitSet.md")]
+pub struct BitSet<T = usize, O = Lsb0>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The membership bits. The last bit, if any, is always `1`.
+	bits: BitVec<T, O>,
+}
+
+/// Constructors and conversions.
+impl<T, O> BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Creates an empty set.
+	///
+	/// This does not allocate.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let set = BitSet::<u8>::new();
+	/// assert!(set.is_empty());
+	/// ```
+	#[inline]
+	pub fn new() -> Self {
+		Self {
+			bits: BitVec::new(),
+		}
+	}
+
+	/// Creates an empty set, with room for members in `0 .. capacity` before
+	/// reallocating.
+	#[inline]
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self {
+			bits: BitVec::with_capacity(capacity),
+		}
+	}
+
+	/// Creates a set whose members are the indices of the `1` bits in a
+	/// bit-vector.
+	///
+	/// Trailing `0` bits are removed from the bit-vector.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::set::BitSet;
+	///
+	/// let set = BitSet::from_bitvec(bitvec![0, 1, 1, 0, 0]);
+	/// assert!(set.iter().eq([1, 2].iter().copied()));
+	/// assert_eq!(set.as_bitslice(), bits![0, 1, 1]);
+	/// ```
+	#[inline]
+	pub fn from_bitvec(bits: BitVec<T, O>) -> Self {
+		let mut out = Self { bits };
+		out.trim();
+		out
+	}
+
+	/// Views the membership bits of the set.
+	///
+	/// The bit at index `n` is `1` when `n` is a member of the set. The
+	/// bit-slice always ends with the largest member, so it is empty when the
+	/// set is empty.
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<T, O> {
+		self.bits.as_bitslice()
+	}
+
+	/// Unwraps the bit-vector that holds the membership bits.
+	#[inline]
+	pub fn into_bitvec(self) -> BitVec<T, O> {
+		self.bits
+	}
+
+	/// Gets the number of members the set can hold without reallocating.
+	///
+	/// This is the exclusive upper bound of the members that can be inserted
+	/// without reallocating, not a count of members.
+	#[inline]
+	pub fn capacity(&self) -> usize {
+		self.bits.capacity()
+	}
+
+	/// Releases excess allocated memory.
+	#[inline]
+	pub fn shrink_to_fit(&mut self) {
+		self.bits.shrink_to_fit();
+	}
+}
+
+/// Membership.
+impl<T, O> BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Counts the members of the set.
+	///
+	/// This counts the `1` bits in the set’s storage, and so is linear in the
+	/// value of the largest member.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.bits.count_ones()
+	}
+
+	/// Tests if the set has no members.
+	///
+	/// Unlike [`.len()`], this is a constant-time operation.
+	///
+	/// [`.len()`]: Self::len
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.bits.is_empty()
+	}
+
+	/// Tests if a value is a member of the set.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let set = [1, 5].iter().copied().collect::<BitSet>();
+	/// assert!(set.contains(5));
+	/// assert!(!set.contains(4));
+	/// assert!(!set.contains(1000));
+	/// ```
+	#[inline]
+	pub fn contains(&self, value: usize) -> bool {
+		self.bits.get(value).map(|bit| *bit).unwrap_or(false)
+	}
+
+	/// Adds a value to the set.
+	///
+	/// The set grows to hold the value if it is larger than every current
+	/// member.
+	///
+	/// ## Returns
+	///
+	/// `true` if the value was not already a member of the set.
+	///
+	/// ## Panics
+	///
+	/// This panics if `value` is not less than [`BitSlice::MAX_BITS`], as a
+	/// bit-vector cannot grow to hold it.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let mut set = BitSet::<u8>::new();
+	/// assert!(set.insert(10));
+	/// assert!(!set.insert(10));
+	/// assert_eq!(set.as_bitslice().len(), 11);
+	/// ```
+	///
+	/// [`BitSlice::MAX_BITS`]: crate::slice::BitSlice::MAX_BITS
+	#[inline]
+	pub fn insert(&mut self, value: usize) -> bool {
+		assert!(
+			value < BitSlice::<T, O>::MAX_BITS,
+			"bit-set member {} exceeds the maximum of {}",
+			value,
+			BitSlice::<T, O>::MAX_BITS - 1,
+		);
+		if value >= self.bits.len() {
+			self.bits.resize(value + 1, false);
+		}
+		!self.bits.replace(value, true)
+	}
+
+	/// Removes a value from the set.
+	///
+	/// The set shrinks to its new largest member if the largest member is
+	/// removed.
+	///
+	/// ## Returns
+	///
+	/// `true` if the value was a member of the set.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let mut set = [2, 9].iter().copied().collect::<BitSet>();
+	/// assert!(set.remove(9));
+	/// assert!(!set.remove(9));
+	/// assert_eq!(set.as_bitslice().len(), 3);
+	/// ```
+	#[inline]
+	pub fn remove(&mut self, value: usize) -> bool {
+		if value >= self.bits.len() {
+			return false;
+		}
+		let out = self.bits.replace(value, false);
+		if value + 1 == self.bits.len() {
+			self.trim();
+		}
+		out
+	}
+
+	/// Removes all members from the set.
+	///
+	/// This does not release the set’s allocation.
+	#[inline]
+	pub fn clear(&mut self) {
+		self.bits.clear();
+	}
+
+	/// Gets the smallest member of the set.
+	#[inline]
+	pub fn first(&self) -> Option<usize> {
+		self.bits.first_one()
+	}
+
+	/// Gets the largest member of the set.
+	///
+	/// This is a constant-time operation.
+	#[inline]
+	pub fn last(&self) -> Option<usize> {
+		self.bits.len().checked_sub(1)
+	}
+
+	/// Iterates over the members of the set, in ascending order.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let set = [8, 3, 5].iter().copied().collect::<BitSet>();
+	/// assert!(set.iter().eq([3, 5, 8].iter().copied()));
+	/// assert_eq!(set.iter().next_back(), Some(8));
+	/// ```
+	#[inline]
+	pub fn iter(&self) -> IterOnes<'_, T, O> {
+		self.bits.iter_ones()
+	}
+
+	/// Removes trailing `0` bits from the membership bits, restoring the
+	/// invariant that the last bit is a member.
+	fn trim(&mut self) {
+		let len = self.bits.last_one().map(|idx| idx + 1).unwrap_or(0);
+		self.bits.truncate(len);
+	}
+}
+
+/// Set algebra.
+impl<T, O> BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Adds all members of another set to this set.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let mut a = [1, 2].iter().copied().collect::<BitSet>();
+	/// let b = [2, 70].iter().copied().collect::<BitSet>();
+	/// a.union_with(&b);
+	/// assert!(a.iter().eq([1, 2, 70].iter().copied()));
+	/// ```
+	#[inline]
+	pub fn union_with(&mut self, other: &Self) {
+		if other.bits.len() > self.bits.len() {
+			self.bits.resize(other.bits.len(), false);
+		}
+		self.bits
+			.bitop_assign(other.as_bitslice(), BitOr::bitor, BitOr::bitor);
+	}
+
+	/// Removes all members of this set that are not members of another set.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let mut a = [1, 2, 70].iter().copied().collect::<BitSet>();
+	/// let b = [2, 3].iter().copied().collect::<BitSet>();
+	/// a.intersect_with(&b);
+	/// assert!(a.iter().eq([2].iter().copied()));
+	/// ```
+	#[inline]
+	pub fn intersect_with(&mut self, other: &Self) {
+		self.bits.truncate(other.bits.len());
+		self.bits.bitop_assign(
+			other.as_bitslice(),
+			BitAnd::bitand,
+			BitAnd::bitand,
+		);
+		self.trim();
+	}
+
+	/// Removes all members of another set from this set.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let mut a = [1, 2, 70].iter().copied().collect::<BitSet>();
+	/// let b = [2, 70].iter().copied().collect::<BitSet>();
+	/// a.difference_with(&b);
+	/// assert!(a.iter().eq([1].iter().copied()));
+	/// ```
+	#[inline]
+	pub fn difference_with(&mut self, other: &Self) {
+		self.bits.bitop_assign(
+			other.as_bitslice(),
+			|a, b| a & !b,
+			|a, b| a & !b,
+		);
+		self.trim();
+	}
+
+	/// Keeps only the values that are members of exactly one of this set and
+	/// another set.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let mut a = [1, 2].iter().copied().collect::<BitSet>();
+	/// let b = [2, 3].iter().copied().collect::<BitSet>();
+	/// a.symmetric_difference_with(&b);
+	/// assert!(a.iter().eq([1, 3].iter().copied()));
+	/// ```
+	#[inline]
+	pub fn symmetric_difference_with(&mut self, other: &Self) {
+		if other.bits.len() > self.bits.len() {
+			self.bits.resize(other.bits.len(), false);
+		}
+		self.bits.bitop_assign(
+			other.as_bitslice(),
+			BitXor::bitxor,
+			BitXor::bitxor,
+		);
+		self.trim();
+	}
+
+	/// Tests if every member of this set is also a member of another set.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let a = [1, 2].iter().copied().collect::<BitSet>();
+	/// let b = [1, 2, 3].iter().copied().collect::<BitSet>();
+	/// assert!(a.is_subset(&b));
+	/// assert!(!b.is_subset(&a));
+	/// ```
+	#[inline]
+	pub fn is_subset(&self, other: &Self) -> bool {
+		let len = self.bits.len();
+		len <= other.bits.len()
+			&& self
+				.bits
+				.chunks(WORD_BITS)
+				.zip(other.bits[.. len].chunks(WORD_BITS))
+				.all(|(a, b)| a.load_word() & !b.load_word() == 0)
+	}
+
+	/// Tests if every member of another set is also a member of this set.
+	#[inline]
+	pub fn is_superset(&self, other: &Self) -> bool {
+		other.is_subset(self)
+	}
+
+	/// Tests if this set and another set have no members in common.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let a = [1, 2].iter().copied().collect::<BitSet>();
+	/// let b = [3, 4].iter().copied().collect::<BitSet>();
+	/// assert!(a.is_disjoint(&b));
+	/// ```
+	#[inline]
+	pub fn is_disjoint(&self, other: &Self) -> bool {
+		self.bits
+			.chunks(WORD_BITS)
+			.zip(other.bits.chunks(WORD_BITS))
+			.all(|(a, b)| a.load_word() & b.load_word() == 0)
+	}
+}
@@ -0,0 +1,165 @@
+//! Unit tests for bit-sets.
+
+#![cfg(test)]
+
+use std::collections::BTreeSet;
+
+use rand::random;
+
+use super::*;
+use crate::{
+	order::HiLo,
+	prelude::*,
+};
+
+/// Builds a random set of values below `bound`, with its reference model.
+fn random_set<T, O>(bound: usize) -> (BitSet<T, O>, BTreeSet<usize>)
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let model = (0 .. bound / 2)
+		.map(|_| random::<usize>() % bound)
+		.collect::<BTreeSet<_>>();
+	(model.iter().collect(), model)
+}
+
+/// Checks that a set matches its model, and maintains its trimmed invariant.
+fn check<T, O>(set: &BitSet<T, O>, model: &BTreeSet<usize>)
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	assert!(set.iter().eq(model.iter().copied()));
+	assert_eq!(set.len(), model.len());
+	assert_eq!(set.is_empty(), model.is_empty());
+	assert_eq!(set.first(), model.iter().next().copied());
+	assert_eq!(set.last(), model.iter().next_back().copied());
+	assert_eq!(
+		set.as_bitslice().len(),
+		model.iter().next_back().map(|max| max + 1).unwrap_or(0),
+	);
+}
+
+#[test]
+fn membership() {
+	let mut set = BitSet::<u8, Msb0>::new();
+	assert!(set.is_empty());
+	assert!(!set.contains(0));
+	assert!(!set.remove(0));
+
+	assert!(set.insert(3));
+	assert!(set.insert(40));
+	assert!(!set.insert(3));
+	assert!(set.contains(3));
+	assert!(set.contains(40));
+	assert!(!set.contains(39));
+	assert_eq!(set.as_bitslice().len(), 41);
+
+	assert!(!set.remove(39));
+	assert_eq!(set.as_bitslice().len(), 41);
+	assert!(set.remove(40));
+	assert_eq!(set.as_bitslice().len(), 4);
+	assert!(set.remove(3));
+	assert!(set.as_bitslice().is_empty());
+
+	set.extend(&[1, 2, 3]);
+	set.clear();
+	assert!(set.is_empty());
+
+	let set = BitSet::from(bitvec![u16, Lsb0; 1, 0, 1, 0, 0, 0]);
+	assert_eq!(set.as_bitslice(), bits![1, 0, 1]);
+	assert_eq!(BitVec::from(set.clone()), bits![1, 0, 1]);
+	assert_eq!(set, [0, 2].iter().collect::<BitSet<u32, Msb0>>());
+	assert_eq!(
+		BitSet::<u8>::from(bitvec![u8, Lsb0; 0; 20]),
+		BitSet::<u8>::new()
+	);
+
+	#[cfg(feature = "std")]
+	assert_eq!(format!("{:?}", set), "{0, 2}");
+}
+
+#[test]
+#[should_panic = "bit-set member 18446744073709551615 exceeds the maximum"]
+#[cfg(target_pointer_width = "64")]
+fn insert_max() {
+	BitSet::<u8>::new().insert(usize::MAX);
+}
+
+#[test]
+fn random_membership() {
+	fn exercise<T, O>()
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		let (mut set, mut model) = random_set::<T, O>(300);
+		check(&set, &model);
+		for _ in 0 .. 200 {
+			let value = random::<usize>() % 320;
+			if random() {
+				assert_eq!(set.insert(value), model.insert(value));
+			}
+			else {
+				assert_eq!(set.remove(value), model.remove(&value));
+			}
+			assert_eq!(set.contains(value), model.contains(&value));
+		}
+		check(&set, &model);
+	}
+
+	exercise::<u8, Lsb0>();
+	exercise::<u16, Msb0>();
+	exercise::<u32, HiLo>();
+	exercise::<usize, Lsb0>();
+}
+
+#[test]
+fn algebra() {
+	fn exercise<T, O>()
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		let (a, ma) = random_set::<T, O>(1 + random::<usize>() % 400);
+		let (b, mb) = random_set::<T, O>(1 + random::<usize>() % 400);
+
+		let mut set = a.clone();
+		set.union_with(&b);
+		check(&set, &ma.union(&mb).copied().collect());
+
+		let mut set = a.clone();
+		set.intersect_with(&b);
+		check(&set, &ma.intersection(&mb).copied().collect());
+
+		let mut set = a.clone();
+		set.difference_with(&b);
+		check(&set, &ma.difference(&mb).copied().collect());
+
+		let mut set = a.clone();
+		set.symmetric_difference_with(&b);
+		check(&set, &ma.symmetric_difference(&mb).copied().collect());
+
+		assert_eq!(a.is_subset(&b), ma.is_subset(&mb));
+		assert_eq!(a.is_superset(&b), ma.is_superset(&mb));
+		assert_eq!(a.is_disjoint(&b), ma.is_disjoint(&mb));
+
+		let mut sub = a.clone();
+		sub.intersect_with(&b);
+		assert!(sub.is_subset(&a));
+		assert!(sub.is_subset(&b));
+		assert!(a.is_superset(&sub));
+
+		let mut rest = a.clone();
+		rest.difference_with(&b);
+		assert!(rest.is_disjoint(&b));
+	}
+
+	for _ in 0 .. 10 {
+		exercise::<u8, Lsb0>();
+		exercise::<u16, Msb0>();
+		exercise::<u32, HiLo>();
+		exercise::<usize, Msb0>();
+	}
+}
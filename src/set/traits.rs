@@ -0,0 +1,189 @@
+//! General trait implementations for bit-sets.
+
+use core::{
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+	iter::FromIterator,
+};
+
+use super::BitSet;
+use crate::{
+	order::BitOrder,
+	slice::IterOnes,
+	store::BitStore,
+	vec::BitVec,
+};
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Clone for BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			bits: self.bits.clone(),
+		}
+	}
+}
+
+impl<T, O> Eq for BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+}
+
+/// Sets are equal when they have the same members, regardless of their
+/// capacity or memory layout.
+#[cfg(not(tarpaulin_include))]
+impl<T1, T2, O1, O2> PartialEq<BitSet<T2, O2>> for BitSet<T1, O1>
+where
+	T1: BitStore,
+	T2: BitStore,
+	O1: BitOrder,
+	O2: BitOrder,
+{
+	#[inline]
+	fn eq(&self, other: &BitSet<T2, O2>) -> bool {
+		self.as_bitslice() == other.as_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Hash for BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn hash<H>(&self, state: &mut H)
+	where H: Hasher {
+		self.as_bitslice().hash(state)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Default for BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T, O> Debug for BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_set().entries(self.iter()).finish()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> From<BitVec<T, O>> for BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(bits: BitVec<T, O>) -> Self {
+		Self::from_bitvec(bits)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> From<BitSet<T, O>> for BitVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(set: BitSet<T, O>) -> Self {
+		set.into_bitvec()
+	}
+}
+
+impl<T, O> Extend<usize> for BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = usize> {
+		for value in iter {
+			self.insert(value);
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, T, O> Extend<&'a usize> for BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = &'a usize> {
+		self.extend(iter.into_iter().copied());
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> FromIterator<usize> for BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = usize> {
+		let mut out = Self::new();
+		out.extend(iter);
+		out
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, T, O> FromIterator<&'a usize> for BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = &'a usize> {
+		iter.into_iter().copied().collect()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, T, O> IntoIterator for &'a BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type IntoIter = IterOnes<'a, T, O>;
+	type Item = usize;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
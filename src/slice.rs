@@ -43,16 +43,43 @@ use crate::{
 	store::BitStore,
 };
 
+mod algebra;
 mod api;
+mod arith;
+mod atomic;
+mod display;
 mod iter;
+mod metrics;
 mod ops;
+mod parse;
+mod runs;
+mod search;
 mod specialization;
 mod tests;
 mod traits;
 
 pub use self::{
+	algebra::*,
 	api::*,
+	display::BitDisplay,
 	iter::*,
+	parse::ParseBitsError,
+	runs::{
+		IterOneRuns,
+		IterRuns,
+		IterZeroRuns,
+	},
+	search::*,
+};
+pub(crate) use self::{
+	parse::{
+		digit_value,
+		is_separator,
+		parse_bits,
+		scan_word,
+		Fault,
+	},
+	specialization::WORD_BITS,
 };
 
 #[repr(transparent)]
@@ -1487,10 +1514,7 @@ where
 			len,
 		);
 
-		unsafe {
-			self.copy_within_unchecked(by .., 0);
-			self.get_unchecked_mut(len - by ..).fill(false);
-		}
+		self.shift_words_start(by);
 	}
 
 	#[inline]
@@ -1553,10 +1577,7 @@ where
 			len,
 		);
 
-		unsafe {
-			self.copy_within_unchecked(.. len - by, by);
-			self.get_unchecked_mut(.. by).fill(false);
-		}
+		self.shift_words_end(by);
 	}
 
 	#[inline]
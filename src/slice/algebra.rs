@@ -0,0 +1,479 @@
+#![doc = include_str!("../../doc/slice/algebra.md")]
+
+use core::{
+	cmp,
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	iter::FusedIterator,
+};
+
+use super::{
+	metrics::Combine,
+	specialization::WORD_BITS,
+	BitSlice,
+};
+use crate::{
+	order::BitOrder,
+	store::BitStore,
+};
+
+/// Set algebra.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Iterates over the indices that are `1` in both `self` and `other`.
+	///
+	/// This is equivalent to `(self & other).iter_ones()`, but does not
+	/// allocate a bit-vector to hold the intersection. The bit-slices do not
+	/// need to share type parameters or lengths; the shorter bit-slice is
+	/// treated as if it were extended with `0` bits.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::intersection`](https://doc.rust-lang.org/alloc/collections/btree_set/struct.BTreeSet.html#method.intersection)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 1, 1, 0, 1];
+	/// let b = bits![u8, Msb0; 1, 1, 0, 0, 1, 1];
+	/// assert!(a.intersection(b).eq([1, 4].iter().copied()));
+	/// ```
+	#[inline]
+	pub fn intersection<'a, T2, O2>(
+		&'a self,
+		other: &'a BitSlice<T2, O2>,
+	) -> Intersection<'a, T, O, T2, O2>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let len = cmp::min(self.len(), other.len());
+		Intersection {
+			inner: Merge::new(self, other, len, |a, b| a & b),
+		}
+	}
+
+	/// Iterates over the indices that are `1` in either `self` or `other`.
+	///
+	/// This is equivalent to `(self | other).iter_ones()`, but does not
+	/// allocate a bit-vector to hold the union. The bit-slices do not need to
+	/// share type parameters or lengths; the shorter bit-slice is treated as
+	/// if it were extended with `0` bits.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::union`](https://doc.rust-lang.org/alloc/collections/btree_set/struct.BTreeSet.html#method.union)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 1, 1, 0, 1];
+	/// let b = bits![u8, Msb0; 1, 1, 0, 0, 1, 1];
+	/// assert!(a.union(b).eq([0, 1, 2, 4, 5].iter().copied()));
+	/// ```
+	#[inline]
+	pub fn union<'a, T2, O2>(
+		&'a self,
+		other: &'a BitSlice<T2, O2>,
+	) -> Union<'a, T, O, T2, O2>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let len = cmp::max(self.len(), other.len());
+		Union {
+			inner: Merge::new(self, other, len, |a, b| a | b),
+		}
+	}
+
+	/// Iterates over the indices that are `1` in `self` but not in `other`.
+	///
+	/// The bit-slices do not need to share type parameters or lengths; the
+	/// shorter bit-slice is treated as if it were extended with `0` bits.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::difference`](https://doc.rust-lang.org/alloc/collections/btree_set/struct.BTreeSet.html#method.difference)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 1, 1, 0, 1];
+	/// let b = bits![u8, Msb0; 1, 1, 0, 0, 1, 1];
+	/// assert!(a.difference(b).eq([2].iter().copied()));
+	/// ```
+	#[inline]
+	pub fn difference<'a, T2, O2>(
+		&'a self,
+		other: &'a BitSlice<T2, O2>,
+	) -> Difference<'a, T, O, T2, O2>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let len = self.len();
+		Difference {
+			inner: Merge::new(self, other, len, |a, b| a & !b),
+		}
+	}
+
+	/// Iterates over the indices that are `1` in exactly one of `self` and
+	/// `other`.
+	///
+	/// This is equivalent to `(self ^ other).iter_ones()`, but does not
+	/// allocate a bit-vector to hold the difference. The bit-slices do not need
+	/// to share type parameters or lengths; the shorter bit-slice is treated as
+	/// if it were extended with `0` bits.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::symmetric_difference`](https://doc.rust-lang.org/alloc/collections/btree_set/struct.BTreeSet.html#method.symmetric_difference)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 1, 1, 0, 1];
+	/// let b = bits![u8, Msb0; 1, 1, 0, 0, 1, 1];
+	/// let found = a.symmetric_difference(b).rev().collect::<Vec<_>>();
+	/// assert_eq!(found, [5, 2, 0]);
+	/// ```
+	#[inline]
+	pub fn symmetric_difference<'a, T2, O2>(
+		&'a self,
+		other: &'a BitSlice<T2, O2>,
+	) -> SymmetricDifference<'a, T, O, T2, O2>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let len = cmp::max(self.len(), other.len());
+		SymmetricDifference {
+			inner: Merge::new(self, other, len, |a, b| a ^ b),
+		}
+	}
+
+	/// Counts the indices that are `1` in both `self` and `other`.
+	///
+	/// This is equivalent to `(self & other).count_ones()`, but does not
+	/// allocate. It is the same as [`.and_count()`]; the bit-slices are
+	/// combined one memory element or processor word at a time.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 1, 1, 0, 1];
+	/// let b = bits![u8, Msb0; 1, 1, 0, 0, 1, 1];
+	/// assert_eq!(a.intersection_count(b), 2);
+	/// ```
+	///
+	/// [`.and_count()`]: Self::and_count
+	#[inline]
+	pub fn intersection_count<T2, O2>(&self, other: &BitSlice<T2, O2>) -> usize
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.count_combined(other, Combine::And)
+	}
+
+	/// Counts the indices that are `1` in either `self` or `other`.
+	///
+	/// This is equivalent to `(self | other).count_ones()`, but does not
+	/// allocate. The bit-slices are combined one memory element or processor
+	/// word at a time where they overlap, and the remainder of the longer
+	/// bit-slice is counted directly.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 1, 1, 0, 1];
+	/// let b = bits![u8, Msb0; 1, 1, 0, 0, 1, 1];
+	/// assert_eq!(a.union_count(b), 5);
+	/// ```
+	#[inline]
+	pub fn union_count<T2, O2>(&self, other: &BitSlice<T2, O2>) -> usize
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.count_combined(other, Combine::Or)
+	}
+}
+
+/// Generates the public set-algebra iterators, which are all thin wrappers
+/// over the `Merge` engine.
+macro_rules! merge_iter {
+	($($name:ident => $doc:literal);+ $(;)?) => { $(
+		#[doc = include_str!($doc)]
+		pub struct $name<'a, T, O, T2, O2>
+		where
+			T: 'a + BitStore,
+			O: BitOrder,
+			T2: 'a + BitStore,
+			O2: BitOrder,
+		{
+			/// The word-at-a-time combination engine.
+			inner: Merge<'a, T, O, T2, O2>,
+		}
+
+		#[cfg(not(tarpaulin_include))]
+		impl<'a, T, O, T2, O2> Clone for $name<'a, T, O, T2, O2>
+		where
+			T: 'a + BitStore,
+			O: BitOrder,
+			T2: 'a + BitStore,
+			O2: BitOrder,
+		{
+			#[inline]
+			fn clone(&self) -> Self {
+				Self {
+					inner: self.inner.clone(),
+				}
+			}
+		}
+
+		impl<'a, T, O, T2, O2> Iterator for $name<'a, T, O, T2, O2>
+		where
+			T: 'a + BitStore,
+			O: BitOrder,
+			T2: 'a + BitStore,
+			O2: BitOrder,
+		{
+			type Item = usize;
+
+			#[inline]
+			fn next(&mut self) -> Option<Self::Item> {
+				self.inner.next()
+			}
+
+			#[inline]
+			fn size_hint(&self) -> (usize, Option<usize>) {
+				self.inner.size_hint()
+			}
+		}
+
+		impl<'a, T, O, T2, O2> DoubleEndedIterator for $name<'a, T, O, T2, O2>
+		where
+			T: 'a + BitStore,
+			O: BitOrder,
+			T2: 'a + BitStore,
+			O2: BitOrder,
+		{
+			#[inline]
+			fn next_back(&mut self) -> Option<Self::Item> {
+				self.inner.next_back()
+			}
+		}
+
+		impl<'a, T, O, T2, O2> FusedIterator for $name<'a, T, O, T2, O2>
+		where
+			T: 'a + BitStore,
+			O: BitOrder,
+			T2: 'a + BitStore,
+			O2: BitOrder,
+		{
+		}
+
+		#[cfg(not(tarpaulin_include))]
+		impl<'a, T, O, T2, O2> Debug for $name<'a, T, O, T2, O2>
+		where
+			T: 'a + BitStore,
+			O: BitOrder,
+			T2: 'a + BitStore,
+			O2: BitOrder,
+		{
+			#[inline]
+			fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+				fmt.debug_struct(stringify!($name))
+					.field("this", &self.inner.this)
+					.field("that", &self.inner.that)
+					.field("front", &self.inner.front)
+					.field("back", &self.inner.back)
+					.finish()
+			}
+		}
+	)+ };
+}
+
+merge_iter! {
+	Intersection => "../../doc/slice/algebra/Intersection.md";
+	Union => "../../doc/slice/algebra/Union.md";
+	Difference => "../../doc/slice/algebra/Difference.md";
+	SymmetricDifference => "../../doc/slice/algebra/SymmetricDifference.md";
+}
+
+/// Combines two bit-slices one processor word at a time, and yields the
+/// indices of the `1` bits in the combined words.
+///
+/// Words are loaded with [`.load_word()`], so the two bit-slices may have any
+/// type parameters. The bit-slices are zero-extended to `len`, and words are
+/// loaded from either end of the region that has not yet been combined.
+///
+/// [`.load_word()`]: crate::slice::BitSlice::load_word
+struct Merge<'a, T, O, T2, O2>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+	T2: 'a + BitStore,
+	O2: BitOrder,
+{
+	/// The left operand.
+	this:       &'a BitSlice<T, O>,
+	/// The right operand.
+	that:       &'a BitSlice<T2, O2>,
+	/// Combines a word from each operand.
+	op:         fn(usize, usize) -> usize,
+	/// The lowest index that has not yet been loaded.
+	front:      usize,
+	/// One past the highest index that has not yet been loaded.
+	back:       usize,
+	/// The un-yielded `1` bits of the most recent word loaded at the front.
+	front_word: usize,
+	/// The index of the least significant bit of `front_word`.
+	front_base: usize,
+	/// The un-yielded `1` bits of the most recent word loaded at the back.
+	back_word:  usize,
+	/// The index of the least significant bit of `back_word`.
+	back_base:  usize,
+}
+
+impl<'a, T, O, T2, O2> Merge<'a, T, O, T2, O2>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+	T2: 'a + BitStore,
+	O2: BitOrder,
+{
+	/// Prepares to combine the first `len` bits of two bit-slices.
+	fn new(
+		this: &'a BitSlice<T, O>,
+		that: &'a BitSlice<T2, O2>,
+		len: usize,
+		op: fn(usize, usize) -> usize,
+	) -> Self {
+		Self {
+			this,
+			that,
+			op,
+			front: 0,
+			back: len,
+			front_word: 0,
+			front_base: 0,
+			back_word: 0,
+			back_base: 0,
+		}
+	}
+
+	/// Combines the words of each operand in `start .. end`, which must span
+	/// no more than `WORD_BITS` bits.
+	fn load(&self, start: usize, end: usize) -> usize {
+		(self.op)(
+			load_part(self.this, start, end),
+			load_part(self.that, start, end),
+		)
+	}
+
+	/// Yields the lowest remaining index.
+	fn next(&mut self) -> Option<usize> {
+		loop {
+			if self.front_word != 0 {
+				let idx = self.front_word.trailing_zeros() as usize;
+				self.front_word &= self.front_word - 1;
+				return Some(self.front_base + idx);
+			}
+			if self.front < self.back {
+				let end = cmp::min(self.front + WORD_BITS, self.back);
+				self.front_word = self.load(self.front, end);
+				self.front_base = self.front;
+				self.front = end;
+				continue;
+			}
+			if self.back_word != 0 {
+				let idx = self.back_word.trailing_zeros() as usize;
+				self.back_word &= self.back_word - 1;
+				return Some(self.back_base + idx);
+			}
+			return None;
+		}
+	}
+
+	/// Yields the highest remaining index.
+	fn next_back(&mut self) -> Option<usize> {
+		loop {
+			if self.back_word != 0 {
+				let idx =
+					WORD_BITS - 1 - self.back_word.leading_zeros() as usize;
+				self.back_word &= !(1 << idx);
+				return Some(self.back_base + idx);
+			}
+			if self.front < self.back {
+				let start =
+					cmp::max(self.front, self.back.saturating_sub(WORD_BITS));
+				self.back_word = self.load(start, self.back);
+				self.back_base = start;
+				self.back = start;
+				continue;
+			}
+			if self.front_word != 0 {
+				let idx =
+					WORD_BITS - 1 - self.front_word.leading_zeros() as usize;
+				self.front_word &= !(1 << idx);
+				return Some(self.front_base + idx);
+			}
+			return None;
+		}
+	}
+
+	/// Bounds the number of indices that remain to be yielded.
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let loaded = self.front_word.count_ones() as usize
+			+ self.back_word.count_ones() as usize;
+		(loaded, Some(loaded + (self.back - self.front)))
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, T, O, T2, O2> Clone for Merge<'a, T, O, T2, O2>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+	T2: 'a + BitStore,
+	O2: BitOrder,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self { ..*self }
+	}
+}
+
+/// Loads the bits of a bit-slice in `start .. end` into a word, treating any
+/// bits past the end of the bit-slice as `0`.
+fn load_part<T, O>(bits: &BitSlice<T, O>, start: usize, end: usize) -> usize
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let len = bits.len();
+	if start >= len {
+		return 0;
+	}
+	unsafe { bits.get_unchecked(start .. cmp::min(end, len)) }.load_word()
+}
@@ -1809,10 +1809,9 @@ where
 
 	/// Tests if the bit-slice contains the given sequence anywhere within it.
 	///
-	/// This scans over `self.windows(other.len())` until one of the windows
-	/// matches. The search key does not need to share type parameters with the
-	/// bit-slice being tested, as the comparison is bit-wise. However, sharing
-	/// type parameters will accelerate the comparison.
+	/// This is equivalent to `self.find(other).is_some()`. The search key does
+	/// not need to share type parameters with the bit-slice being tested, as
+	/// the comparison is bit-wise. See [`.find()`] for details of the search.
 	///
 	/// ## Original
 	///
@@ -1827,14 +1826,15 @@ where
 	/// assert!( bits.contains(bits![0, 1, 1, 0]));
 	/// assert!(!bits.contains(bits![1, 0, 0, 1]));
 	/// ```
+	///
+	/// [`.find()`]: Self::find
 	#[inline]
 	pub fn contains<T2, O2>(&self, other: &BitSlice<T2, O2>) -> bool
 	where
 		T2: BitStore,
 		O2: BitOrder,
 	{
-		self.len() >= other.len()
-			&& self.windows(other.len()).any(|window| window == other)
+		self.find(other).is_some()
 	}
 
 	/// Tests if the bit-slice begins with the given sequence.
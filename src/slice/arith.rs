@@ -0,0 +1,984 @@
+#![doc = include_str!("../../doc/slice/arith.md")]
+
+use core::cmp::{
+	self,
+	Ordering,
+};
+
+use super::{
+	specialization::WORD_BITS,
+	BitSlice,
+};
+use crate::{
+	order::BitOrder,
+	store::BitStore,
+};
+
+/// Multi-precision integer arithmetic.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Adds `rhs` into `self`, interpreting both as little-endian unsigned
+	/// integers, and reports whether the sum overflowed.
+	///
+	/// The little-endian interpretation places the least significant bit at
+	/// index `0` and the most significant bit at the end of the bit-slice.
+	///
+	/// ## Original
+	///
+	/// [`u32::overflowing_add`](https://doc.rust-lang.org/std/primitive.u32.html#method.overflowing_add)
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Returns
+	///
+	/// `true` if the sum carried out of the most significant bit. `self` holds
+	/// the sum modulo `2^len` either way.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 200u16;
+	/// let bits = &mut data.view_bits_mut::<Lsb0>()[.. 9];
+	/// assert!(!bits.overflowing_add_le(&100u16.view_bits::<Lsb0>()[.. 9]));
+	/// assert_eq!(data, 300);
+	///
+	/// let bits = &mut data.view_bits_mut::<Lsb0>()[.. 9];
+	/// assert!(bits.overflowing_add_le(bits![0, 0, 1, 1, 0, 1, 0, 1, 1]));
+	/// assert_eq!(data, 300 + 428 - 512);
+	/// ```
+	#[inline]
+	pub fn overflowing_add_le<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>) -> bool
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.add_digits(rhs, Endian::Little, false, false)
+	}
+
+	/// Adds `rhs` into `self`, interpreting both as big-endian unsigned
+	/// integers, and reports whether the sum overflowed.
+	///
+	/// The big-endian interpretation places the most significant bit at index
+	/// `0` and the least significant bit at the end of the bit-slice.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Returns
+	///
+	/// `true` if the sum carried out of the most significant bit.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0, 1, 1, 0];
+	/// assert!(!bits.overflowing_add_le(bits![1, 0, 0, 0]));
+	/// assert_eq!(bits, bits![1, 1, 1, 0]);
+	/// assert!(bits.overflowing_add_be(bits![1, 0, 0, 1]));
+	/// assert_eq!(bits, bits![0, 1, 1, 1]);
+	/// ```
+	#[inline]
+	pub fn overflowing_add_be<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>) -> bool
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.add_digits(rhs, Endian::Big, false, false)
+	}
+
+	/// Adds `rhs` into `self`, interpreting both as little-endian integers,
+	/// and discards any carry out of the most significant bit.
+	///
+	/// This is the same operation for signed and unsigned integers.
+	///
+	/// ## Original
+	///
+	/// [`u32::wrapping_add`](https://doc.rust-lang.org/std/primitive.u32.html#method.wrapping_add)
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1, 1, 0];
+	/// bits.wrapping_add_le(bits![1, 1, 1]);
+	/// assert_eq!(bits, bits![0, 1, 0]);
+	/// ```
+	#[inline]
+	pub fn wrapping_add_le<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.overflowing_add_le(rhs);
+	}
+
+	/// Adds `rhs` into `self`, interpreting both as big-endian integers, and
+	/// discards any carry out of the most significant bit.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0, 1, 1];
+	/// bits.wrapping_add_be(bits![1, 1, 1]);
+	/// assert_eq!(bits, bits![0, 1, 0]);
+	/// ```
+	#[inline]
+	pub fn wrapping_add_be<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.overflowing_add_be(rhs);
+	}
+
+	/// Subtracts `rhs` from `self`, interpreting both as little-endian
+	/// unsigned integers, and reports whether the difference overflowed.
+	///
+	/// ## Original
+	///
+	/// [`u32::overflowing_sub`](https://doc.rust-lang.org/std/primitive.u32.html#method.overflowing_sub)
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Returns
+	///
+	/// `true` if `rhs` was greater than `self`, and the difference borrowed
+	/// from beyond the most significant bit.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1, 0, 1];
+	/// assert!(!bits.overflowing_sub_le(bits![0, 0, 1]));
+	/// assert_eq!(bits, bits![1, 0, 0]);
+	/// assert!(bits.overflowing_sub_le(bits![0, 1, 0]));
+	/// assert_eq!(bits, bits![1, 1, 1]);
+	/// ```
+	#[inline]
+	pub fn overflowing_sub_le<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>) -> bool
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		!self.add_digits(rhs, Endian::Little, true, true)
+	}
+
+	/// Subtracts `rhs` from `self`, interpreting both as big-endian unsigned
+	/// integers, and reports whether the difference overflowed.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Returns
+	///
+	/// `true` if `rhs` was greater than `self`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1, 0, 1];
+	/// assert!(!bits.overflowing_sub_be(bits![0, 1, 1]));
+	/// assert_eq!(bits, bits![0, 1, 0]);
+	/// ```
+	#[inline]
+	pub fn overflowing_sub_be<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>) -> bool
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		!self.add_digits(rhs, Endian::Big, true, true)
+	}
+
+	/// Subtracts `rhs` from `self`, interpreting both as little-endian
+	/// integers, and discards any borrow from beyond the most significant bit.
+	///
+	/// ## Original
+	///
+	/// [`u32::wrapping_sub`](https://doc.rust-lang.org/std/primitive.u32.html#method.wrapping_sub)
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0, 0, 0, 0];
+	/// bits.wrapping_sub_le(bits![1, 0, 0, 0]);
+	/// assert!(bits.all());
+	/// ```
+	#[inline]
+	pub fn wrapping_sub_le<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.overflowing_sub_le(rhs);
+	}
+
+	/// Subtracts `rhs` from `self`, interpreting both as big-endian integers,
+	/// and discards any borrow from beyond the most significant bit.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0, 0, 0, 0];
+	/// bits.wrapping_sub_be(bits![0, 0, 1, 0]);
+	/// assert_eq!(bits, bits![1, 1, 1, 0]);
+	/// ```
+	#[inline]
+	pub fn wrapping_sub_be<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.overflowing_sub_be(rhs);
+	}
+
+	/// Negates `self` in two’s complement, interpreting it as a little-endian
+	/// integer, and reports whether the negation overflowed.
+	///
+	/// ## Original
+	///
+	/// [`u32::overflowing_neg`](https://doc.rust-lang.org/std/primitive.u32.html#method.overflowing_neg)
+	///
+	/// ## Returns
+	///
+	/// As with the unsigned integers, this is `true` unless `self` is zero.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0, 1, 0, 0];
+	/// assert!(bits.overflowing_neg_le());
+	/// assert_eq!(bits, bits![0, 1, 1, 1]);
+	///
+	/// let bits = bits![mut 0; 4];
+	/// assert!(!bits.overflowing_neg_le());
+	/// assert!(bits.not_any());
+	/// ```
+	#[inline]
+	pub fn overflowing_neg_le(&mut self) -> bool {
+		self.neg_digits(Endian::Little)
+	}
+
+	/// Negates `self` in two’s complement, interpreting it as a big-endian
+	/// integer, and reports whether the negation overflowed.
+	///
+	/// ## Returns
+	///
+	/// As with the unsigned integers, this is `true` unless `self` is zero.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0, 0, 1, 0];
+	/// assert!(bits.overflowing_neg_be());
+	/// assert_eq!(bits, bits![1, 1, 1, 0]);
+	/// ```
+	#[inline]
+	pub fn overflowing_neg_be(&mut self) -> bool {
+		self.neg_digits(Endian::Big)
+	}
+
+	/// Negates `self` in two’s complement, interpreting it as a little-endian
+	/// integer.
+	///
+	/// ## Original
+	///
+	/// [`u32::wrapping_neg`](https://doc.rust-lang.org/std/primitive.u32.html#method.wrapping_neg)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1, 0, 0, 0];
+	/// bits.wrapping_neg_le();
+	/// assert!(bits.all());
+	/// ```
+	#[inline]
+	pub fn wrapping_neg_le(&mut self) {
+		self.overflowing_neg_le();
+	}
+
+	/// Negates `self` in two’s complement, interpreting it as a big-endian
+	/// integer.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0, 0, 0, 1];
+	/// bits.wrapping_neg_be();
+	/// assert!(bits.all());
+	/// ```
+	#[inline]
+	pub fn wrapping_neg_be(&mut self) {
+		self.overflowing_neg_be();
+	}
+
+	/// Adds one to `self`, interpreting it as a little-endian unsigned
+	/// integer, and reports whether the increment overflowed.
+	///
+	/// ## Returns
+	///
+	/// `true` if every bit in `self` was `1`, and is now `0`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1, 1, 0];
+	/// assert!(!bits.overflowing_inc_le());
+	/// assert_eq!(bits, bits![0, 0, 1]);
+	/// ```
+	#[inline]
+	pub fn overflowing_inc_le(&mut self) -> bool {
+		self.step_digits(Endian::Little, true)
+	}
+
+	/// Adds one to `self`, interpreting it as a big-endian unsigned integer,
+	/// and reports whether the increment overflowed.
+	///
+	/// ## Returns
+	///
+	/// `true` if every bit in `self` was `1`, and is now `0`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1, 1];
+	/// assert!(bits.overflowing_inc_be());
+	/// assert!(bits.not_any());
+	/// ```
+	#[inline]
+	pub fn overflowing_inc_be(&mut self) -> bool {
+		self.step_digits(Endian::Big, true)
+	}
+
+	/// Adds one to `self`, interpreting it as a little-endian integer, and
+	/// wraps around to zero on overflow.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1, 0, 1];
+	/// bits.wrapping_inc_le();
+	/// assert_eq!(bits, bits![0, 1, 1]);
+	/// ```
+	#[inline]
+	pub fn wrapping_inc_le(&mut self) {
+		self.overflowing_inc_le();
+	}
+
+	/// Adds one to `self`, interpreting it as a big-endian integer, and wraps
+	/// around to zero on overflow.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1, 0, 1];
+	/// bits.wrapping_inc_be();
+	/// assert_eq!(bits, bits![1, 1, 0]);
+	/// ```
+	#[inline]
+	pub fn wrapping_inc_be(&mut self) {
+		self.overflowing_inc_be();
+	}
+
+	/// Subtracts one from `self`, interpreting it as a little-endian unsigned
+	/// integer, and reports whether the decrement overflowed.
+	///
+	/// ## Returns
+	///
+	/// `true` if every bit in `self` was `0`, and is now `1`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0, 0, 1];
+	/// assert!(!bits.overflowing_dec_le());
+	/// assert_eq!(bits, bits![1, 1, 0]);
+	/// ```
+	#[inline]
+	pub fn overflowing_dec_le(&mut self) -> bool {
+		self.step_digits(Endian::Little, false)
+	}
+
+	/// Subtracts one from `self`, interpreting it as a big-endian unsigned
+	/// integer, and reports whether the decrement overflowed.
+	///
+	/// ## Returns
+	///
+	/// `true` if every bit in `self` was `0`, and is now `1`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0, 0];
+	/// assert!(bits.overflowing_dec_be());
+	/// assert!(bits.all());
+	/// ```
+	#[inline]
+	pub fn overflowing_dec_be(&mut self) -> bool {
+		self.step_digits(Endian::Big, false)
+	}
+
+	/// Subtracts one from `self`, interpreting it as a little-endian integer,
+	/// and wraps around to all `1` bits on overflow.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0, 1, 1];
+	/// bits.wrapping_dec_le();
+	/// assert_eq!(bits, bits![1, 0, 1]);
+	/// ```
+	#[inline]
+	pub fn wrapping_dec_le(&mut self) {
+		self.overflowing_dec_le();
+	}
+
+	/// Subtracts one from `self`, interpreting it as a big-endian integer, and
+	/// wraps around to all `1` bits on overflow.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1, 1, 0];
+	/// bits.wrapping_dec_be();
+	/// assert_eq!(bits, bits![1, 0, 1]);
+	/// ```
+	#[inline]
+	pub fn wrapping_dec_be(&mut self) {
+		self.overflowing_dec_be();
+	}
+
+	/// Multiplies `self` by `rhs`, interpreting both as little-endian unsigned
+	/// integers, and reports whether the product overflowed.
+	///
+	/// The product is computed in place, without any scratch space, and is
+	/// truncated to the length of `self`.
+	///
+	/// ## Original
+	///
+	/// [`u32::overflowing_mul`](https://doc.rust-lang.org/std/primitive.u32.html#method.overflowing_mul)
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Returns
+	///
+	/// `true` if the full product did not fit in the length of `self`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 300u32;
+	/// let bits = &mut data.view_bits_mut::<Lsb0>()[.. 20];
+	/// assert!(!bits.overflowing_mul_le(&1000u32.view_bits::<Lsb0>()[.. 20]));
+	/// assert_eq!(data, 300_000);
+	///
+	/// let bits = &mut data.view_bits_mut::<Lsb0>()[.. 20];
+	/// assert!(bits.overflowing_mul_le(&4u32.view_bits::<Lsb0>()[.. 20]));
+	/// assert_eq!(data, 1_200_000 % (1 << 20));
+	/// ```
+	#[inline]
+	pub fn overflowing_mul_le<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>) -> bool
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.mul_digits(rhs, Endian::Little)
+	}
+
+	/// Multiplies `self` by `rhs`, interpreting both as big-endian unsigned
+	/// integers, and reports whether the product overflowed.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Returns
+	///
+	/// `true` if the full product did not fit in the length of `self`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0, 1, 1];
+	/// assert!(!bits.overflowing_mul_be(bits![0, 1, 0]));
+	/// assert_eq!(bits, bits![1, 1, 0]);
+	/// ```
+	#[inline]
+	pub fn overflowing_mul_be<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>) -> bool
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.mul_digits(rhs, Endian::Big)
+	}
+
+	/// Multiplies `self` by `rhs`, interpreting both as little-endian
+	/// integers, and truncates the product to the length of `self`.
+	///
+	/// This is the same operation for signed and unsigned integers.
+	///
+	/// ## Original
+	///
+	/// [`u32::wrapping_mul`](https://doc.rust-lang.org/std/primitive.u32.html#method.wrapping_mul)
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1, 1, 1];
+	/// bits.wrapping_mul_le(bits![1, 1, 1]);
+	/// assert_eq!(bits, bits![1, 0, 0]);
+	/// ```
+	#[inline]
+	pub fn wrapping_mul_le<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.overflowing_mul_le(rhs);
+	}
+
+	/// Multiplies `self` by `rhs`, interpreting both as big-endian integers,
+	/// and truncates the product to the length of `self`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1, 1, 1];
+	/// bits.wrapping_mul_be(bits![0, 1, 1]);
+	/// assert_eq!(bits, bits![1, 0, 1]);
+	/// ```
+	#[inline]
+	pub fn wrapping_mul_be<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.overflowing_mul_be(rhs);
+	}
+
+	/// Compares `self` and `rhs` as little-endian unsigned integers.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::cmp::Ordering;
+	///
+	/// let a = bits![1, 0, 1];
+	/// let b = bits![u8, Msb0; 0, 1, 1];
+	/// assert_eq!(a.cmp_unsigned_le(b), Ordering::Less);
+	/// assert_eq!(a.cmp_unsigned_le(a), Ordering::Equal);
+	/// ```
+	#[inline]
+	pub fn cmp_unsigned_le<T2, O2>(&self, rhs: &BitSlice<T2, O2>) -> Ordering
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.cmp_digits(rhs, Endian::Little, false)
+	}
+
+	/// Compares `self` and `rhs` as big-endian unsigned integers.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::cmp::Ordering;
+	///
+	/// let a = bits![1, 0, 1];
+	/// let b = bits![u8, Msb0; 0, 1, 1];
+	/// assert_eq!(a.cmp_unsigned_be(b), Ordering::Greater);
+	/// ```
+	#[inline]
+	pub fn cmp_unsigned_be<T2, O2>(&self, rhs: &BitSlice<T2, O2>) -> Ordering
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.cmp_digits(rhs, Endian::Big, false)
+	}
+
+	/// Compares `self` and `rhs` as little-endian two’s-complement signed
+	/// integers.
+	///
+	/// The sign bit is the most significant bit, at the end of the bit-slice.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::cmp::Ordering;
+	///
+	/// let a = bits![1, 0, 1];
+	/// let b = bits![u8, Msb0; 0, 1, 0];
+	/// assert_eq!(a.cmp_signed_le(b), Ordering::Less);
+	/// assert_eq!(a.cmp_unsigned_le(b), Ordering::Greater);
+	/// ```
+	#[inline]
+	pub fn cmp_signed_le<T2, O2>(&self, rhs: &BitSlice<T2, O2>) -> Ordering
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.cmp_digits(rhs, Endian::Little, true)
+	}
+
+	/// Compares `self` and `rhs` as big-endian two’s-complement signed
+	/// integers.
+	///
+	/// The sign bit is the most significant bit, at index `0`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::cmp::Ordering;
+	///
+	/// let a = bits![1, 0, 1];
+	/// let b = bits![u8, Msb0; 0, 1, 0];
+	/// assert_eq!(a.cmp_signed_be(b), Ordering::Less);
+	/// assert_eq!(a.cmp_unsigned_be(b), Ordering::Greater);
+	/// ```
+	#[inline]
+	pub fn cmp_signed_be<T2, O2>(&self, rhs: &BitSlice<T2, O2>) -> Ordering
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.cmp_digits(rhs, Endian::Big, true)
+	}
+}
+
+/// Digit-serial engine.
+///
+/// The arithmetic routines treat a bit-slice as a sequence of `WORD_BITS`-wide
+/// digits, numbered from least to most significant. Every digit is full-width
+/// except possibly the most significant, which holds the leftover bits.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Adds `rhs`, optionally inverted, and an incoming carry into `self`,
+	/// returning the carry out of the most significant digit.
+	///
+	/// Subtraction is addition of the inverted `rhs` with an incoming carry.
+	fn add_digits<T2, O2>(
+		&mut self,
+		rhs: &BitSlice<T2, O2>,
+		endian: Endian,
+		invert: bool,
+		carry: bool,
+	) -> bool
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		assert_eq!(
+			self.len(),
+			rhs.len(),
+			"cannot combine integers of different lengths",
+		);
+		let mut carry = carry as usize;
+		for idx in 0 .. self.digit_count() {
+			let width = self.digit_width(idx);
+			let this = self.digit(idx, endian);
+			let mut that = rhs.digit(idx, endian);
+			if invert {
+				that = !that & mask(width);
+			}
+			let (sum, c1) = this.overflowing_add(that);
+			let (sum, c2) = sum.overflowing_add(carry);
+			carry = if width == WORD_BITS {
+				(c1 | c2) as usize
+			}
+			else {
+				sum >> width
+			};
+			self.set_digit(idx, endian, sum);
+		}
+		carry != 0
+	}
+
+	/// Negates `self` by inverting and then incrementing it, returning `true`
+	/// unless it was zero.
+	fn neg_digits(&mut self, endian: Endian) -> bool {
+		for idx in 0 .. self.digit_count() {
+			let width = self.digit_width(idx);
+			let digit = self.digit(idx, endian);
+			self.set_digit(idx, endian, !digit & mask(width));
+		}
+		!self.step_digits(endian, true)
+	}
+
+	/// Increments or decrements `self`, returning `true` if the step wrapped
+	/// around.
+	fn step_digits(&mut self, endian: Endian, up: bool) -> bool {
+		for idx in 0 .. self.digit_count() {
+			let full = mask(self.digit_width(idx));
+			let (edge, wrap) = if up { (full, 0) } else { (0, full) };
+			let digit = self.digit(idx, endian);
+			if digit == edge {
+				self.set_digit(idx, endian, wrap);
+				continue;
+			}
+			let next = if up { digit + 1 } else { digit - 1 };
+			self.set_digit(idx, endian, next);
+			return false;
+		}
+		true
+	}
+
+	/// Multiplies `self` by `rhs` in place, returning `true` if the product
+	/// overflowed.
+	///
+	/// The digits of `self` are visited from most to least significant. Each
+	/// one is replaced by its product with `rhs`, which is then accumulated
+	/// into the more significant digits. Because the digits below the current
+	/// one are still untouched multiplicand, no scratch space is needed.
+	fn mul_digits<T2, O2>(
+		&mut self,
+		rhs: &BitSlice<T2, O2>,
+		endian: Endian,
+	) -> bool
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		assert_eq!(
+			self.len(),
+			rhs.len(),
+			"cannot multiply integers of different lengths",
+		);
+		let count = self.digit_count();
+		let mut overflow = false;
+		for idx in (0 .. count).rev() {
+			let this = self.digit(idx, endian);
+			if this == 0 {
+				continue;
+			}
+			let mut carry = 0;
+			for pos in idx .. count {
+				let acc = if pos == idx {
+					0
+				}
+				else {
+					self.digit(pos, endian)
+				};
+				let (lo, hi) =
+					mul_add(this, rhs.digit(pos - idx, endian), acc, carry);
+				let width = self.digit_width(pos);
+				if width < WORD_BITS {
+					overflow |= lo >> width != 0 || hi != 0;
+				}
+				self.set_digit(pos, endian, lo);
+				carry = hi;
+			}
+			overflow |= carry != 0
+				|| (count - idx .. count).any(|pos| rhs.digit(pos, endian) != 0);
+		}
+		overflow
+	}
+
+	/// Compares two integers from their most significant digits downward.
+	fn cmp_digits<T2, O2>(
+		&self,
+		rhs: &BitSlice<T2, O2>,
+		endian: Endian,
+		signed: bool,
+	) -> Ordering
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		assert_eq!(
+			self.len(),
+			rhs.len(),
+			"cannot compare integers of different lengths",
+		);
+		let count = self.digit_count();
+		if signed && count > 0 {
+			let sign = self.digit_width(count - 1) - 1;
+			let this = self.digit(count - 1, endian) >> sign;
+			let that = rhs.digit(count - 1, endian) >> sign;
+			if this != that {
+				return that.cmp(&this);
+			}
+		}
+		(0 .. count)
+			.rev()
+			.map(|idx| self.digit(idx, endian).cmp(&rhs.digit(idx, endian)))
+			.find(|ord| *ord != Ordering::Equal)
+			.unwrap_or(Ordering::Equal)
+	}
+
+	/// Counts the digits in the bit-slice.
+	fn digit_count(&self) -> usize {
+		(self.len() + WORD_BITS - 1) / WORD_BITS
+	}
+
+	/// Counts the bits in the `idx`th digit.
+	fn digit_width(&self, idx: usize) -> usize {
+		cmp::min(WORD_BITS, self.len() - idx * WORD_BITS)
+	}
+
+	/// Selects the bits of the `idx`th digit.
+	fn digit_span(&self, idx: usize, endian: Endian) -> (usize, usize) {
+		let len = self.len();
+		let width = self.digit_width(idx);
+		let start = idx * WORD_BITS;
+		match endian {
+			Endian::Little => (start, start + width),
+			Endian::Big => (len - start - width, len - start),
+		}
+	}
+
+	/// Loads the `idx`th digit, placing its least significant bit at `1`.
+	/// Digits past the end of the bit-slice are `0`.
+	fn digit(&self, idx: usize, endian: Endian) -> usize {
+		if idx >= self.digit_count() {
+			return 0;
+		}
+		let (start, end) = self.digit_span(idx, endian);
+		let word = unsafe { self.get_unchecked(start .. end) }.load_word();
+		match endian {
+			Endian::Little => word,
+			Endian::Big => word.reverse_bits() >> (WORD_BITS - (end - start)),
+		}
+	}
+
+	/// Stores the low bits of `word` into the `idx`th digit.
+	fn set_digit(&mut self, idx: usize, endian: Endian, word: usize) {
+		let (start, end) = self.digit_span(idx, endian);
+		let word = match endian {
+			Endian::Little => word,
+			Endian::Big => (word << (WORD_BITS - (end - start))).reverse_bits(),
+		};
+		unsafe { self.get_unchecked_mut(start .. end) }.store_word(word);
+	}
+}
+
+/// The order in which a bit-slice’s indices map to the significance of its
+/// bits, when it is interpreted as an integer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Endian {
+	/// Index `0` is the least significant bit.
+	Little,
+	/// Index `0` is the most significant bit.
+	Big,
+}
+
+/// Produces a mask over the low `width` bits of a word.
+fn mask(width: usize) -> usize {
+	if width >= WORD_BITS {
+		!0
+	}
+	else {
+		(1 << width) - 1
+	}
+}
+
+/// Computes `a * b + c + d` as a double-width `(low, high)` word pair. This
+/// cannot overflow.
+fn mul_add(a: usize, b: usize, c: usize, d: usize) -> (usize, usize) {
+	let wide = a as u128 * b as u128 + c as u128 + d as u128;
+	(wide as usize, (wide >> WORD_BITS) as usize)
+}
@@ -0,0 +1,286 @@
+#![doc = include_str!("../../doc/slice/atomic.md")]
+
+use core::{
+	cmp,
+	sync::atomic::Ordering,
+};
+
+use radium::Radium;
+
+use super::BitSlice;
+use crate::{
+	index::BitEnd,
+	mem::bits_of,
+	order::BitOrder,
+	store::BitStore,
+};
+
+/// Atomic read-modify-write operations on single bits.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore + Radium,
+	O: BitOrder,
+{
+	/// Sets a bit to `1`, returning its previous value.
+	///
+	/// ## Original
+	///
+	/// [`AtomicBool::fetch_or`](core::sync::atomic::AtomicBool::fetch_or)
+	///
+	/// ## Parameters
+	///
+	/// - `&self`: This method only exists on bit-slices with alias-safe
+	///   storage, and so does not require exclusive access.
+	/// - `index`: The bit index to set. It must be in `0 .. self.len()`.
+	/// - `order`: The memory ordering of the read-modify-write operation.
+	///
+	/// ## Returns
+	///
+	/// The value of the bit at `index` immediately before it was set.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::{cell::Cell, sync::atomic::Ordering};
+	///
+	/// let bits: &BitSlice<_, _> = bits![Cell<u8>, Lsb0; 0, 1];
+	/// assert!(!bits.fetch_set(0, Ordering::Relaxed));
+	/// assert!(bits.fetch_set(1, Ordering::Relaxed));
+	/// assert_eq!(bits, bits![1, 1]);
+	/// ```
+	#[inline]
+	pub fn fetch_set(&self, index: usize, order: Ordering) -> bool {
+		let (elem, sel) = self.bit_access(index);
+		elem.fetch_or(sel, order) & sel != T::Mem::ZERO
+	}
+
+	/// Clears a bit to `0`, returning its previous value.
+	///
+	/// ## Original
+	///
+	/// [`AtomicBool::fetch_and`](core::sync::atomic::AtomicBool::fetch_and)
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `index`: The bit index to clear. It must be in `0 .. self.len()`.
+	/// - `order`: The memory ordering of the read-modify-write operation.
+	///
+	/// ## Returns
+	///
+	/// The value of the bit at `index` immediately before it was cleared.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::{cell::Cell, sync::atomic::Ordering};
+	///
+	/// let bits: &BitSlice<_, _> = bits![Cell<u8>, Lsb0; 0, 1];
+	/// assert!(!bits.fetch_clear(0, Ordering::Relaxed));
+	/// assert!(bits.fetch_clear(1, Ordering::Relaxed));
+	/// assert!(bits.not_any());
+	/// ```
+	#[inline]
+	pub fn fetch_clear(&self, index: usize, order: Ordering) -> bool {
+		let (elem, sel) = self.bit_access(index);
+		elem.fetch_and(!sel, order) & sel != T::Mem::ZERO
+	}
+
+	/// Inverts a bit, returning its previous value.
+	///
+	/// ## Original
+	///
+	/// [`AtomicBool::fetch_xor`](core::sync::atomic::AtomicBool::fetch_xor)
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `index`: The bit index to invert. It must be in `0 .. self.len()`.
+	/// - `order`: The memory ordering of the read-modify-write operation.
+	///
+	/// ## Returns
+	///
+	/// The value of the bit at `index` immediately before it was inverted.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::{cell::Cell, sync::atomic::Ordering};
+	///
+	/// let bits: &BitSlice<_, _> = bits![Cell<u8>, Lsb0; 0, 1];
+	/// assert!(!bits.fetch_toggle(0, Ordering::Relaxed));
+	/// assert!(bits.fetch_toggle(1, Ordering::Relaxed));
+	/// assert_eq!(bits, bits![1, 0]);
+	/// ```
+	#[inline]
+	pub fn fetch_toggle(&self, index: usize, order: Ordering) -> bool {
+		let (elem, sel) = self.bit_access(index);
+		elem.fetch_xor(sel, order) & sel != T::Mem::ZERO
+	}
+
+	/// Writes `new` into a bit if it currently holds `current`.
+	///
+	/// Concurrent modification of *other* bits in the same memory element
+	/// does not cause this to fail; it retries until it either observes a
+	/// different value in the requested bit or succeeds in writing it.
+	///
+	/// ## Original
+	///
+	/// [`AtomicBool::compare_exchange`](core::sync::atomic::AtomicBool::compare_exchange)
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `index`: The bit index to modify. It must be in `0 .. self.len()`.
+	/// - `current`: The value that the bit must hold for the write to occur.
+	/// - `new`: The value to write into the bit.
+	/// - `success`: The memory ordering of the read-modify-write operation when
+	///   the comparison succeeds.
+	/// - `failure`: The memory ordering of the load when the comparison fails.
+	///   This cannot be `Release` or `AcqRel`.
+	///
+	/// ## Returns
+	///
+	/// `Ok(current)` if the bit held `current` and now holds `new`, or
+	/// `Err(!current)` if the bit did not hold `current` and was not modified.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds, or if `failure` is not a valid
+	/// load ordering.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::{cell::Cell, sync::atomic::Ordering::*};
+	///
+	/// let bits: &BitSlice<_, _> = bits![Cell<u8>, Msb0; 0, 1];
+	/// assert_eq!(bits.compare_exchange_bit(0, false, true, AcqRel, Acquire), Ok(false));
+	/// assert_eq!(bits.compare_exchange_bit(1, false, true, AcqRel, Acquire), Err(true));
+	/// assert_eq!(bits, bits![1, 1]);
+	/// ```
+	#[inline]
+	pub fn compare_exchange_bit(
+		&self,
+		index: usize,
+		current: bool,
+		new: bool,
+		success: Ordering,
+		failure: Ordering,
+	) -> Result<bool, bool> {
+		let (elem, sel) = self.bit_access(index);
+		let mut val = elem.load(failure);
+		loop {
+			if (val & sel != T::Mem::ZERO) != current {
+				return Err(!current);
+			}
+			let next = if new { val | sel } else { val & !sel };
+			match elem.compare_exchange(val, next, success, failure) {
+				Ok(_) => return Ok(current),
+				Err(now) => val = now,
+			}
+		}
+	}
+
+	/// Finds the first bit that is `0` and atomically sets it to `1`.
+	///
+	/// This is the allocation step of a bitmap allocator: a `0` bit marks a
+	/// free slot, and the bit that this sets is claimed by the caller alone,
+	/// even when other threads are claiming bits of the same bit-slice at the
+	/// same time. Bits that are set by other threads during the search are
+	/// skipped, and the search continues from them.
+	///
+	/// The search walks the bit-slice one memory element at a time, and claims
+	/// bits with a compare-exchange on the whole element, so a bit that is
+	/// cleared behind the search cursor may be missed by a concurrent call.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `order`: The memory ordering of the successful claim. Allocators
+	///   typically use `Acquire`, paired with a `Release` ordering on the
+	///   [`.fetch_clear()`] that frees the slot.
+	///
+	/// ## Returns
+	///
+	/// The index of the bit that was claimed, or `None` if every bit in
+	/// `self` was `1`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::{cell::Cell, sync::atomic::Ordering};
+	///
+	/// let bits: &BitSlice<_, _> = bits![Cell<u8>, Lsb0; 1, 1, 0, 1, 0];
+	/// assert_eq!(bits.claim_first_zero(Ordering::Acquire), Some(2));
+	/// assert_eq!(bits.claim_first_zero(Ordering::Acquire), Some(4));
+	/// assert_eq!(bits.claim_first_zero(Ordering::Acquire), None);
+	/// assert!(bits.all());
+	/// ```
+	///
+	/// [`.fetch_clear()`]: Self::fetch_clear
+	#[inline]
+	pub fn claim_first_zero(&self, order: Ordering) -> Option<usize> {
+		let len = self.len();
+		let mut start = 0;
+		while start < len {
+			let (addr, head) =
+				unsafe { self.as_bitptr().add(start) }.raw_parts();
+			let span = cmp::min(
+				bits_of::<T::Mem>() - head.into_inner() as usize,
+				len - start,
+			);
+			let elem = unsafe { &*addr.to_const().cast::<T::Access>() };
+			let mut val = elem.load(Ordering::Relaxed);
+			while let Some((offset, sel)) = head
+				.range(BitEnd::MAX)
+				.take(span)
+				.map(|idx| idx.select::<O>().into_inner())
+				.enumerate()
+				.find(|&(_, sel)| val & sel == T::Mem::ZERO)
+			{
+				match elem.compare_exchange(
+					val,
+					val | sel,
+					order,
+					Ordering::Relaxed,
+				) {
+					Ok(_) => return Some(start + offset),
+					Err(now) => val = now,
+				}
+			}
+			start += span;
+		}
+		None
+	}
+
+	/// Locates the memory element that holds a bit, and the selector for that
+	/// bit within it.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds.
+	fn bit_access(&self, index: usize) -> (&T::Access, T::Mem) {
+		self.assert_in_bounds(index, 0 .. self.len());
+		let (addr, bit) = unsafe { self.as_bitptr().add(index) }.raw_parts();
+		let elem = unsafe { &*addr.to_const().cast::<T::Access>() };
+		(elem, bit.select::<O>().into_inner())
+	}
+}
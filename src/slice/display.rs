@@ -0,0 +1,208 @@
+#![doc = include_str!("../../doc/slice/display.md")]
+
+use core::{
+	cmp,
+	fmt::{
+		self,
+		Display,
+		Formatter,
+		Write,
+	},
+};
+
+use super::BitSlice;
+use crate::{
+	index::BitIdx,
+	mem::bits_of,
+	order::BitOrder,
+	store::BitStore,
+};
+
+/// Pretty-printing.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Begins configuring a multi-line rendering of the bit-slice.
+	///
+	/// The returned adapter implements [`Display`], and prints the bits as
+	/// rows of `0` and `1` digits. By default, each row holds 64 bits, and the
+	/// digits are separated into groups at the boundaries of the memory
+	/// elements. See [`BitDisplay`] for the available options.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0x48u8, 0x69, 0x21];
+	/// let bits = data.view_bits::<Msb0>();
+	/// assert_eq!(
+	///   bits.display().row(16).with_offsets().with_ascii().to_string(),
+	///   "0000: 01001000 01101001  |Hi|\n0010: 00100001           |!|",
+	/// );
+	/// ```
+	///
+	/// [`BitDisplay`]: crate::slice::BitDisplay
+	/// [`Display`]: core::fmt::Display
+	#[inline]
+	pub fn display(&self) -> BitDisplay<'_, T, O> {
+		BitDisplay {
+			bits:    self,
+			group:   None,
+			row:     64,
+			offsets: false,
+			ascii:   false,
+		}
+	}
+}
+
+#[derive(Clone, Copy, Debug)]
+#[doc = include_str!("../../doc/slice/BitDisplay.md")]
+pub struct BitDisplay<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The bit-slice being rendered.
+	bits:    &'a BitSlice<T, O>,
+	/// The number of bits in each group, or `None` to group by memory element.
+	group:   Option<usize>,
+	/// The number of bits in each row.
+	row:     usize,
+	/// Whether to prefix each row with the index of its first bit.
+	offsets: bool,
+	/// Whether to suffix each row with its bytes rendered as ASCII.
+	ascii:   bool,
+}
+
+impl<T, O> BitDisplay<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Separates the digits into groups of `width` bits, counted from the
+	/// start of each row, rather than at memory-element boundaries.
+	///
+	/// ## Panics
+	///
+	/// This panics if `width` is zero.
+	#[inline]
+	pub fn group(mut self, width: usize) -> Self {
+		assert!(width > 0, "cannot group bits into groups of zero width");
+		self.group = Some(width);
+		self
+	}
+
+	/// Sets the number of bits printed on each row.
+	///
+	/// ## Panics
+	///
+	/// This panics if `width` is zero.
+	#[inline]
+	pub fn row(mut self, width: usize) -> Self {
+		assert!(width > 0, "cannot print rows of zero width");
+		self.row = width;
+		self
+	}
+
+	/// Prefixes each row with the index of its first bit, in hexadecimal.
+	#[inline]
+	pub fn with_offsets(mut self) -> Self {
+		self.offsets = true;
+		self
+	}
+
+	/// Suffixes each row with a column that renders each group of eight bits,
+	/// counted from the start of the row, as an ASCII character.
+	///
+	/// Each group is read as a byte whose bits are placed by the ordering `O`,
+	/// so a row over `u8` storage that begins at an element boundary shows the
+	/// bytes in memory. Bytes that are not printable ASCII, and a trailing
+	/// partial byte, are rendered as `.`.
+	#[inline]
+	pub fn with_ascii(mut self) -> Self {
+		self.ascii = true;
+		self
+	}
+
+	/// Tests whether a group separator precedes the bit at `idx`, where `idx`
+	/// counts from the start of its row.
+	fn is_boundary(&self, start: usize, idx: usize) -> bool {
+		match self.group {
+			Some(width) => idx % width == 0,
+			None => {
+				let head = self.bits.as_bitptr().bit().into_inner() as usize;
+				(head + start + idx) % bits_of::<T::Mem>() == 0
+			},
+		}
+	}
+
+	/// Renders one row of digits, and its ASCII column if requested.
+	fn fmt_row(&self, fmt: &mut Formatter, start: usize) -> fmt::Result {
+		let row = &self.bits[start ..];
+		let row = &row[.. row.len().min(self.row)];
+		//  The ASCII column is aligned by padding a short final row with
+		//  spaces, to the width of the rows before it.
+		let width = if self.ascii {
+			cmp::min(self.row, self.bits.len())
+		}
+		else {
+			row.len()
+		};
+		for idx in 0 .. width {
+			if idx > 0 && self.is_boundary(start, idx) {
+				fmt.write_char(' ')?;
+			}
+			fmt.write_char(match row.get(idx).map(|bit| *bit) {
+				Some(true) => '1',
+				Some(false) => '0',
+				None => ' ',
+			})?;
+		}
+		if self.ascii {
+			fmt.write_str("  |")?;
+			for byte in row.chunks(8) {
+				//  Each bit is placed in the byte where `O` would store it.
+				let val = BitIdx::<u8>::range_all()
+					.zip(byte.iter().by_vals())
+					.filter(|&(_, bit)| bit)
+					.fold(0u8, |acc, (idx, _)| {
+						acc | idx.select::<O>().into_inner()
+					});
+				let printable =
+					byte.len() == 8 && (val.is_ascii_graphic() || val == b' ');
+				fmt.write_char(if printable { val as char } else { '.' })?;
+			}
+			fmt.write_char('|')?;
+		}
+		Ok(())
+	}
+}
+
+impl<T, O> Display for BitDisplay<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		let len = self.bits.len();
+		let last = len.saturating_sub(1) / self.row * self.row;
+		let digits = cmp::max(
+			4,
+			(bits_of::<usize>() - last.leading_zeros() as usize + 3) / 4,
+		);
+		for start in (0 .. len).step_by(self.row) {
+			if start > 0 {
+				fmt.write_char('\n')?;
+			}
+			if self.offsets {
+				write!(fmt, "{:01$x}: ", start, digits)?;
+			}
+			self.fmt_row(fmt, start)?;
+		}
+		Ok(())
+	}
+}
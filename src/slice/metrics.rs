@@ -0,0 +1,207 @@
+#![doc = include_str!("../../doc/slice/metrics.md")]
+
+use core::cmp;
+
+use funty::Integral;
+
+use super::{
+	specialization::WORD_BITS,
+	BitSlice,
+};
+use crate::{
+	order::BitOrder,
+	store::BitStore,
+};
+
+/// Similarity metrics.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Counts the indices at which `self` and `other` differ.
+	///
+	/// The bit-slices do not need to share type parameters or lengths; the
+	/// shorter bit-slice is treated as if it were extended with `0` bits, so
+	/// every `1` bit in the longer bit-slice’s excess counts as a difference.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 1, 1, 0, 1];
+	/// let b = bits![u8, Msb0; 1, 1, 0, 0, 1, 1];
+	/// assert_eq!(a.hamming_distance(b), 3);
+	/// ```
+	#[inline]
+	pub fn hamming_distance<T2, O2>(&self, other: &BitSlice<T2, O2>) -> usize
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.count_combined(other, Combine::Xor)
+	}
+
+	/// Counts the indices that are `1` in both `self` and `other`.
+	///
+	/// The bit-slices do not need to share type parameters or lengths. Only
+	/// their common prefix is examined.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 1, 1, 0, 1];
+	/// let b = bits![u8, Msb0; 1, 1, 0, 0, 1, 1];
+	/// assert_eq!(a.and_count(b), 2);
+	/// ```
+	#[inline]
+	pub fn and_count<T2, O2>(&self, other: &BitSlice<T2, O2>) -> usize
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.count_combined(other, Combine::And)
+	}
+
+	/// Computes the Jaccard index of the sets of `1` bits in `self` and
+	/// `other`.
+	///
+	/// This is the number of indices that are `1` in both bit-slices, divided
+	/// by the number that are `1` in either. The bit-slices do not need to
+	/// share type parameters or lengths; the shorter bit-slice is treated as if
+	/// it were extended with `0` bits.
+	///
+	/// ## Returns
+	///
+	/// A value in the range `0.0 ..= 1.0`. Two bit-slices with no `1` bits are
+	/// identical sets, and so have a Jaccard index of `1.0`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 1, 1, 0, 1];
+	/// let b = bits![u8, Msb0; 1, 1, 0, 0, 1, 1];
+	/// assert_eq!(a.jaccard(b), 0.4);
+	/// assert_eq!(bits![0; 4].jaccard(bits![0; 8]), 1.0);
+	/// ```
+	#[inline]
+	pub fn jaccard<T2, O2>(&self, other: &BitSlice<T2, O2>) -> f64
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let union = self.count_combined(other, Combine::Or);
+		if union == 0 {
+			return 1.0;
+		}
+		self.and_count(other) as f64 / union as f64
+	}
+
+	/// Computes the inner product of `self` and `other`, reading each `1` bit
+	/// as `+1` and each `0` bit as `-1`.
+	///
+	/// This is the dot product of two sign-quantized (binary) embeddings, and
+	/// is equal to `len - 2 * hamming_distance`. Dividing it by the length
+	/// gives the cosine similarity of the two sign vectors.
+	///
+	/// ## Panics
+	///
+	/// This panics if the two bit-slices have different lengths.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 1, 1, 0, 1, 0];
+	/// let b = bits![u8, Msb0; 1, 1, 0, 0, 1, 1];
+	/// assert_eq!(a.dot(b), 0);
+	/// assert_eq!(a.dot(a), 6);
+	/// assert_eq!(a.dot(&!a.to_bitvec()), -6);
+	/// ```
+	#[inline]
+	pub fn dot<T2, O2>(&self, other: &BitSlice<T2, O2>) -> isize
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let len = self.len();
+		assert_eq!(
+			len,
+			other.len(),
+			"cannot take the dot product of bit-slices with different lengths",
+		);
+		len as isize - 2 * self.hamming_distance(other) as isize
+	}
+
+	/// Counts the `1` bits produced by combining `self` and `other` with a
+	/// Boolean operator, treating the shorter bit-slice as zero-extended.
+	///
+	/// If the two bit-slices share type parameters and begin at the same bit
+	/// in their first memory element, then their domains are walked in
+	/// lockstep and the operator is applied to whole memory elements.
+	/// Otherwise, their common prefix is loaded one processor word at a time.
+	pub(crate) fn count_combined<T2, O2>(
+		&self,
+		other: &BitSlice<T2, O2>,
+		op: Combine,
+	) -> usize
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let len = cmp::min(self.len(), other.len());
+		let (this, this_rest) = unsafe { self.split_at_unchecked(len) };
+		let (that, that_rest) = unsafe { other.split_at_unchecked(len) };
+
+		let prefix = match that.coerce::<T, O>() {
+			Some(that) if this.as_bitptr().bit() == that.as_bitptr().bit() => {
+				this.domain()
+					.zip(that.domain())
+					.map(|(a, b)| op.apply(a, b).count_ones() as usize)
+					.sum()
+			},
+			_ => this
+				.chunks(WORD_BITS)
+				.zip(that.chunks(WORD_BITS))
+				.map(|(a, b)| {
+					op.apply(a.load_word(), b.load_word()).count_ones() as usize
+				})
+				.sum::<usize>(),
+		};
+		match op {
+			Combine::And => prefix,
+			Combine::Or | Combine::Xor => {
+				prefix + this_rest.count_ones() + that_rest.count_ones()
+			},
+		}
+	}
+}
+
+/// A Boolean operator that combines two bit-slices for counting.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Combine {
+	/// Bits that are `1` in both operands.
+	And,
+	/// Bits that are `1` in either operand.
+	Or,
+	/// Bits that are `1` in exactly one operand.
+	Xor,
+}
+
+impl Combine {
+	/// Applies the operator to a pair of integers.
+	fn apply<I>(self, a: I, b: I) -> I
+	where I: Integral {
+		match self {
+			Self::And => a & b,
+			Self::Or => a | b,
+			Self::Xor => a ^ b,
+		}
+	}
+}
@@ -16,6 +16,10 @@ use core::ops::{
 	RangeInclusive,
 	RangeTo,
 	RangeToInclusive,
+	Shl,
+	ShlAssign,
+	Shr,
+	ShrAssign,
 };
 
 use super::{
@@ -238,3 +242,123 @@ where
 		self
 	}
 }
+
+/** Shifts the contents of the bit-slice towards the front (index `0`), and
+clears the vacated bits at the back to `0`.
+
+This is the bit-slice analogue of the integer `<<=` operator. Note that
+bit-slices are written with index `0` on the left, so a “left” shift moves bits
+towards *lower* indices. Whether this makes an integer loaded from the
+bit-slice larger or smaller depends on the bit-ordering and on the
+`BitField` method used to load it.
+
+Unlike the integer operator and [`.shift_start()`], this accepts any shift
+amount; shifting by the bit-slice’s length or more clears it entirely.
+
+The shift is performed one processor word at a time, carrying the displaced bits
+of each word into the next, rather than one bit at a time.
+
+## Examples
+
+```rust
+use bitvec::prelude::*;
+
+let bits = bits![mut 0, 0, 1, 0, 1, 1];
+*bits <<= 2;
+assert_eq!(bits, bits![1, 0, 1, 1, 0, 0]);
+*bits <<= 10;
+assert!(bits.not_any());
+```
+
+[`.shift_start()`]: crate::slice::BitSlice::shift_start
+**/
+impl<T, O> ShlAssign<usize> for BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn shl_assign(&mut self, by: usize) {
+		if by == 0 {
+			return;
+		}
+		if by >= self.len() {
+			return self.fill(false);
+		}
+		self.shift_words_start(by);
+	}
+}
+
+/** Shifts the contents of the bit-slice towards the back (the highest index),
+and clears the vacated bits at the front to `0`.
+
+This is the bit-slice analogue of the integer `>>=` operator. Note that
+bit-slices are written with index `0` on the left, so a “right” shift moves bits
+towards *higher* indices.
+
+Unlike the integer operator and [`.shift_end()`], this accepts any shift amount;
+shifting by the bit-slice’s length or more clears it entirely.
+
+The shift is performed one processor word at a time, carrying the displaced bits
+of each word into the next, rather than one bit at a time.
+
+## Examples
+
+```rust
+use bitvec::prelude::*;
+
+let bits = bits![mut 1, 1, 0, 1, 0, 0];
+*bits >>= 2;
+assert_eq!(bits, bits![0, 0, 1, 1, 0, 1]);
+```
+
+[`.shift_end()`]: crate::slice::BitSlice::shift_end
+**/
+impl<T, O> ShrAssign<usize> for BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn shr_assign(&mut self, by: usize) {
+		if by == 0 {
+			return;
+		}
+		if by >= self.len() {
+			return self.fill(false);
+		}
+		self.shift_words_end(by);
+	}
+}
+
+/// Shifts the bit-slice towards the front in place, with `<<=`, and returns
+/// the bit-slice reference.
+impl<T, O> Shl<usize> for &mut BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Output = Self;
+
+	#[inline]
+	fn shl(self, by: usize) -> Self::Output {
+		*self <<= by;
+		self
+	}
+}
+
+/// Shifts the bit-slice towards the back in place, with `>>=`, and returns
+/// the bit-slice reference.
+impl<T, O> Shr<usize> for &mut BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Output = Self;
+
+	#[inline]
+	fn shr(self, by: usize) -> Self::Output {
+		*self >>= by;
+		self
+	}
+}
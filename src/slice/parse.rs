@@ -0,0 +1,339 @@
+#![doc = include_str!("../../doc/slice/parse.md")]
+
+use core::fmt::{
+	self,
+	Display,
+	Formatter,
+};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[doc = include_str!("../../doc/slice/ParseBitsError.md")]
+pub enum ParseBitsError {
+	/// The text, or an entry in a `[…]` list, contains no digits.
+	Empty {
+		/// The byte offset in the text at which digits were expected.
+		index: usize,
+	},
+	/// A character is not a digit in the radix of the word containing it.
+	InvalidDigit {
+		/// The byte offset of the character in the text.
+		index: usize,
+		/// The character.
+		found: char,
+		/// The radix of the word containing the character.
+		radix: u32,
+	},
+	/// A `[` is not matched by a `]` at the end of the text.
+	UnclosedList,
+	/// The digits of a sized word do not fill exactly the number of bits that
+	/// its size prefix declares.
+	Width {
+		/// The byte offset of the digits in the text.
+		index: usize,
+		/// The declared number of bits.
+		width: usize,
+	},
+	/// An entry in a `[…]` list does not state how many bits it holds. Entries
+	/// must be a single binary digit, a `0b` binary word, or a sized word.
+	Unsized {
+		/// The byte offset of the entry in the text.
+		index: usize,
+	},
+	/// The text does not hold exactly as many bits as a fixed-length
+	/// destination.
+	Length {
+		/// The number of bits that the destination holds.
+		expected: usize,
+		/// The number of bits in the text.
+		found:    usize,
+	},
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Display for ParseBitsError {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::Empty { index } => {
+				write!(fmt, "expected digits at byte {}", index)
+			},
+			Self::InvalidDigit {
+				index,
+				found,
+				radix,
+			} => write!(
+				fmt,
+				"invalid base-{} digit {:?} at byte {}",
+				radix, found, index,
+			),
+			Self::UnclosedList => fmt.write_str("unclosed `[` in bit-list"),
+			Self::Width { index, width } => write!(
+				fmt,
+				"the digits at byte {} do not fill exactly {} bits",
+				index, width,
+			),
+			Self::Unsized { index } => write!(
+				fmt,
+				"the list entry at byte {} does not state how many bits it \
+				 holds",
+				index,
+			),
+			Self::Length { expected, found } => write!(
+				fmt,
+				"expected {} bits, but the text holds {}",
+				expected, found,
+			),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseBitsError {}
+
+/// Parses text into a sequence of bits.
+///
+/// ## Parameters
+///
+/// - `text`: Either a single word, or a `[…]` list of comma-separated words,
+///   which may end with a comma. Each word is either a run of binary digits, or
+///   a run of digits prefixed by `0b`, `0o`, or `0x`, or by a size such as
+///   `12'h`. Each digit contributes as many bits as its radix requires, most
+///   significant first. `_` and whitespace may separate digits within a word.
+///   See [`scan_word`] for the full rules.
+/// - `push`: Receives each bit, in order.
+pub(crate) fn parse_bits<F>(
+	text: &str,
+	mut push: F,
+) -> Result<(), ParseBitsError>
+where
+	F: FnMut(bool),
+{
+	let trimmed = text.trim();
+	let list = match trimmed.strip_prefix('[') {
+		Some(rest) => {
+			rest.strip_suffix(']').ok_or(ParseBitsError::UnclosedList)?
+		},
+		None => return parse_word(text, trimmed, false, &mut push),
+	};
+	let list = list.trim_end();
+	if list.is_empty() {
+		return Ok(());
+	}
+	//  The alternate (`{:#}`) renderings are pretty-printed, and end the last
+	//  entry with a comma.
+	list.strip_suffix(',')
+		.unwrap_or(list)
+		.split(',')
+		.try_for_each(|word| parse_word(text, word.trim(), true, &mut push))
+}
+
+/// Parses one word of text into bits.
+///
+/// `word` must be a sub-slice of `text`; `text` is used only to report the
+/// positions of errors. `listed` marks an entry in a `[…]` list.
+fn parse_word<F>(
+	text: &str,
+	word: &str,
+	listed: bool,
+	push: &mut F,
+) -> Result<(), ParseBitsError>
+where
+	F: FnMut(bool),
+{
+	let start = word.as_ptr() as usize - text.as_ptr() as usize;
+	let end = start + word.len();
+	let bytes = text.as_bytes();
+	//  The scan only stops on the first byte that it does not accept, and it
+	//  accepts only ASCII, so every fault is at a character boundary.
+	let found = |index: usize| text[index ..].chars().next().unwrap_or_default();
+	let scan = scan_word(bytes, start, end).map_err(|fault| match fault {
+		Fault::Empty(index) => ParseBitsError::Empty { index },
+		Fault::InvalidDigit(index, radix) => ParseBitsError::InvalidDigit {
+			index,
+			found: found(index),
+			radix,
+		},
+		Fault::Width(index, width) => ParseBitsError::Width { index, width },
+	})?;
+	//  The numeric renderings print each memory element as one list entry,
+	//  without recording which radix an unprefixed entry uses, or how many bits
+	//  the leading digit of an octal or hexadecimal entry holds.
+	if listed && !bytes[start .. scan.digits].contains(&b'\'') {
+		let prefixed =
+			scan.digits > start && matches!(bytes[scan.digits - 1], b'b' | b'B');
+		let digits = bytes[scan.digits .. end]
+			.iter()
+			.filter(|&&byte| !is_separator(byte))
+			.count();
+		if scan.shift != 1 || !prefixed && digits > 1 {
+			return Err(ParseBitsError::Unsized { index: start });
+		}
+	}
+	let mut skip = scan.skip;
+	for &byte in &bytes[scan.digits .. end] {
+		if is_separator(byte) {
+			continue;
+		}
+		let value = digit_value(byte);
+		for bit in (0 .. scan.shift).rev() {
+			if skip > 0 {
+				skip -= 1;
+				continue;
+			}
+			push(value >> bit & 1 == 1);
+		}
+	}
+	Ok(())
+}
+
+/// The layout of one word of bit-text, as found by [`scan_word`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Word {
+	/// The byte offset in the text after any size or radix prefix.
+	pub(crate) digits: usize,
+	/// The number of bits that each digit contributes.
+	pub(crate) shift:  u32,
+	/// The number of leading bits of the first digit, all clear, that fall
+	/// outside the declared width.
+	pub(crate) skip:   usize,
+}
+
+/// A malformed word of bit-text, as found by [`scan_word`].
+///
+/// Each variant carries the byte offset in the text at which it was found, and
+/// matches the [`ParseBitsError`] variant of the same name.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Fault {
+	/// Digits were expected at the offset.
+	Empty(usize),
+	/// The byte at the offset is not a digit in the radix.
+	InvalidDigit(usize, u32),
+	/// The digits at the offset do not fill exactly the declared width.
+	Width(usize, usize),
+}
+
+/// Checks one word of bit-text, and finds its digits.
+///
+/// This is shared by the [`FromStr`] implementations and by the bit-literals
+/// in the constructor macros, so it must be `const`.
+///
+/// The word is `text[start .. end]`. It is either a run of binary digits, a run
+/// of digits after a `0b`, `0o`, or `0x` prefix, or a run of digits after a
+/// decimal size and a `'b`, `'o`, or `'h` radix marker. The digits of a sized
+/// word must fill exactly `size` bits, with any excess bits in the first digit
+/// cleared. The size is therefore never larger than the text, so that untrusted
+/// text cannot request an arbitrarily large bit-sequence. `_` and whitespace
+/// may separate digits.
+///
+/// [`FromStr`]: core::str::FromStr
+pub(crate) const fn scan_word(
+	text: &[u8],
+	start: usize,
+	end: usize,
+) -> Result<Word, Fault> {
+	let mut idx = start;
+	while idx < end && is_separator(text[idx]) {
+		idx += 1;
+	}
+
+	let mut tick = idx;
+	while tick < end && text[tick] != b'\'' {
+		tick += 1;
+	}
+	let sized = tick < end;
+	let mut width = 0usize;
+	let (digits, shift) = if sized {
+		if tick == idx {
+			return Err(Fault::Empty(idx));
+		}
+		while idx < tick {
+			let byte = text[idx];
+			if !byte.is_ascii_digit() {
+				return Err(Fault::InvalidDigit(idx, 10));
+			}
+			//  A size too large for `usize` cannot match the digits either.
+			width = width
+				.saturating_mul(10)
+				.saturating_add((byte - b'0') as usize);
+			idx += 1;
+		}
+		if tick + 1 == end {
+			return Err(Fault::Empty(end));
+		}
+		match text[tick + 1] {
+			b'b' | b'B' => (tick + 2, 1),
+			b'o' | b'O' => (tick + 2, 3),
+			b'h' | b'H' => (tick + 2, 4),
+			_ => return Err(Fault::InvalidDigit(tick + 1, 10)),
+		}
+	}
+	else if idx + 1 < end && text[idx] == b'0' {
+		match text[idx + 1] {
+			b'b' | b'B' => (idx + 2, 1),
+			b'o' | b'O' => (idx + 2, 3),
+			b'x' | b'X' => (idx + 2, 4),
+			_ => (idx, 1),
+		}
+	}
+	else {
+		(idx, 1)
+	};
+
+	let radix = 1 << shift;
+	let mut count = 0usize;
+	let mut first = 0;
+	idx = digits;
+	while idx < end {
+		let byte = text[idx];
+		if !is_separator(byte) {
+			let value = digit_value(byte);
+			if value as u32 >= radix {
+				return Err(Fault::InvalidDigit(idx, radix));
+			}
+			if count == 0 {
+				first = value;
+			}
+			count += 1;
+		}
+		idx += 1;
+	}
+	if count == 0 {
+		return Err(Fault::Empty(digits));
+	}
+
+	if !sized {
+		return Ok(Word {
+			digits,
+			shift,
+			skip: 0,
+		});
+	}
+	let bits = count.saturating_mul(shift as usize);
+	let skip = bits.wrapping_sub(width);
+	if width > bits
+		|| skip >= shift as usize
+		|| first >> (shift as usize - skip) != 0
+	{
+		return Err(Fault::Width(digits, width));
+	}
+	Ok(Word {
+		digits,
+		shift,
+		skip,
+	})
+}
+
+/// Tests whether a byte in bit-text separates digits.
+pub(crate) const fn is_separator(byte: u8) -> bool {
+	matches!(byte, b'_' | b' ' | b'\t' | b'\n' | b'\r')
+}
+
+/// Gets the value of an ASCII hexadecimal digit, or `16` for any other byte.
+pub(crate) const fn digit_value(byte: u8) -> u8 {
+	match byte {
+		b'0' ..= b'9' => byte - b'0',
+		b'a' ..= b'f' => byte - b'a' + 10,
+		b'A' ..= b'F' => byte - b'A' + 10,
+		_ => 16,
+	}
+}
@@ -0,0 +1,630 @@
+#![doc = include_str!("../../doc/slice/runs.md")]
+
+use core::{
+	cmp,
+	iter::FusedIterator,
+};
+
+use super::BitSlice;
+use crate::{
+	devel as dvl,
+	domain::Domain,
+	index::{
+		BitEnd,
+		BitIdx,
+		BitMask,
+	},
+	mem::BitRegister,
+	order::{
+		BitOrder,
+		Lsb0,
+		Msb0,
+	},
+	store::BitStore,
+};
+
+/// Run search.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Finds the first run of `len` consecutive bits cleared to `0`.
+	///
+	/// ## Performance
+	///
+	/// The bit-slice is scanned one memory element at a time. Elements that
+	/// are entirely `0` or entirely `1` are consumed in a single step. In
+	/// `Lsb0` and `Msb0` bit-slices, elements that contain both are consumed
+	/// one run at a time; other orderings inspect them bit by bit.
+	///
+	/// ## Returns
+	///
+	/// The index of the first bit of the earliest run of at least `len` `0`
+	/// bits, or `None` if there is no such run. An empty run is found at index
+	/// `0`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 0, 0, 1, 0, 0, 0];
+	/// assert_eq!(bits.find_zero_run(1), Some(0));
+	/// assert_eq!(bits.find_zero_run(2), Some(2));
+	/// assert_eq!(bits.find_zero_run(3), Some(5));
+	/// assert_eq!(bits.find_zero_run(4), None);
+	/// ```
+	#[inline]
+	pub fn find_zero_run(&self, len: usize) -> Option<usize> {
+		self.find_run(false, len, 1)
+	}
+
+	/// Finds the first run of `len` consecutive bits set to `1`.
+	///
+	/// This is the inverse of [`.find_zero_run()`], and has the same
+	/// performance characteristics.
+	///
+	/// ## Returns
+	///
+	/// The index of the first bit of the earliest run of at least `len` `1`
+	/// bits, or `None` if there is no such run. An empty run is found at index
+	/// `0`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 0, 1, 1, 0, 1, 1, 1];
+	/// assert_eq!(bits.find_one_run(2), Some(2));
+	/// assert_eq!(bits.find_one_run(3), Some(5));
+	/// assert_eq!(bits.find_one_run(4), None);
+	/// ```
+	///
+	/// [`.find_zero_run()`]: Self::find_zero_run
+	#[inline]
+	pub fn find_one_run(&self, len: usize) -> Option<usize> {
+		self.find_run(true, len, 1)
+	}
+
+	/// Finds the first run of `len` consecutive bits cleared to `0` that begins
+	/// at a multiple of `align`.
+	///
+	/// Alignment is measured in bits from the start of the bit-slice, not from
+	/// the start of its memory.
+	///
+	/// ## Panics
+	///
+	/// This panics if `align` is zero.
+	///
+	/// ## Returns
+	///
+	/// The index of the first bit of the earliest aligned run of at least `len`
+	/// `0` bits, or `None` if there is no such run.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0];
+	/// assert_eq!(bits.find_zero_run(3), Some(1));
+	/// assert_eq!(bits.find_zero_run_aligned(3, 2), Some(2));
+	/// assert_eq!(bits.find_zero_run_aligned(4, 4), Some(8));
+	/// assert_eq!(bits.find_zero_run_aligned(5, 4), None);
+	/// ```
+	#[inline]
+	pub fn find_zero_run_aligned(
+		&self,
+		len: usize,
+		align: usize,
+	) -> Option<usize> {
+		self.find_run(false, len, align)
+	}
+
+	/// Finds the first run of `len` consecutive bits set to `1` that begins at
+	/// a multiple of `align`.
+	///
+	/// This is the inverse of [`.find_zero_run_aligned()`].
+	///
+	/// ## Panics
+	///
+	/// This panics if `align` is zero.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1, 1, 0, 1, 1];
+	/// assert_eq!(bits.find_one_run_aligned(2, 2), Some(2));
+	/// assert_eq!(bits.find_one_run_aligned(2, 3), None);
+	/// ```
+	///
+	/// [`.find_zero_run_aligned()`]: Self::find_zero_run_aligned
+	#[inline]
+	pub fn find_one_run_aligned(
+		&self,
+		len: usize,
+		align: usize,
+	) -> Option<usize> {
+		self.find_run(true, len, align)
+	}
+
+	/// Finds the first aligned run of `len` bits equal to `value`.
+	fn find_run(&self, value: bool, len: usize, align: usize) -> Option<usize> {
+		assert!(align > 0, "run alignment must be nonzero");
+		if len == 0 {
+			return Some(0);
+		}
+		if len > self.len() {
+			return None;
+		}
+		let mut scan = RunScan {
+			value,
+			len,
+			align,
+			pos: 0,
+			start: 0,
+		};
+		match self.domain() {
+			Domain::Enclave(elem) => scan.feed::<_, O>(
+				elem.load_value(),
+				elem.head(),
+				elem.tail(),
+				elem.mask(),
+			),
+			Domain::Region { head, body, tail } => head
+				.and_then(|elem| {
+					scan.feed::<_, O>(
+						elem.load_value(),
+						elem.head(),
+						elem.tail(),
+						elem.mask(),
+					)
+				})
+				.or_else(|| {
+					body.iter().map(BitStore::load_value).find_map(|elem| {
+						scan.feed::<_, O>(
+							elem,
+							BitIdx::MIN,
+							BitEnd::MAX,
+							BitMask::ALL,
+						)
+					})
+				})
+				.or_else(|| {
+					tail.and_then(|elem| {
+						scan.feed::<_, O>(
+							elem.load_value(),
+							elem.head(),
+							elem.tail(),
+							elem.mask(),
+						)
+					})
+				}),
+		}
+	}
+}
+
+/// Run iteration.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Enumerates the maximal runs of equal bits in a bit-slice.
+	///
+	/// Each run is reported as a `(start, len, value)` tuple. The runs are
+	/// yielded in order, are never empty, and cover the whole bit-slice;
+	/// adjacent runs always have different values.
+	///
+	/// ## Performance
+	///
+	/// The end of each run is found by seeking the next bit with the opposite
+	/// value, so `Lsb0` and `Msb0` bit-slices skip over each run one memory
+	/// element at a time rather than one bit at a time.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 0, 1, 1, 1, 0, 1];
+	/// let mut runs = bits.iter_runs();
+	/// assert_eq!(runs.next(), Some((0, 2, false)));
+	/// assert_eq!(runs.next_back(), Some((6, 1, true)));
+	/// assert_eq!(runs.next(), Some((2, 3, true)));
+	/// assert_eq!(runs.next(), Some((5, 1, false)));
+	/// assert!(runs.next().is_none());
+	/// ```
+	#[inline]
+	pub fn iter_runs(&self) -> IterRuns<'_, T, O> {
+		IterRuns::new(self)
+	}
+
+	/// Enumerates the maximal runs of bits set to `1` in a bit-slice.
+	///
+	/// Each run is reported as a `(start, len)` pair. This has the same
+	/// performance characteristics as [`.iter_runs()`].
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1, 0, 0, 1, 0];
+	/// assert!(bits.iter_one_runs().eq([(1, 2), (5, 1)].iter().copied()));
+	/// assert!(bits.iter_one_runs().rev().eq([(5, 1), (1, 2)].iter().copied()));
+	/// ```
+	///
+	/// [`.iter_runs()`]: Self::iter_runs
+	#[inline]
+	pub fn iter_one_runs(&self) -> IterOneRuns<'_, T, O> {
+		IterOneRuns::new(self)
+	}
+
+	/// Enumerates the maximal runs of bits cleared to `0` in a bit-slice.
+	///
+	/// Each run is reported as a `(start, len)` pair. This has the same
+	/// performance characteristics as [`.iter_runs()`].
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1, 0, 0, 1, 0];
+	/// assert!(bits.iter_zero_runs().eq([(0, 1), (3, 2), (6, 1)].iter().copied()));
+	/// ```
+	///
+	/// [`.iter_runs()`]: Self::iter_runs
+	#[inline]
+	pub fn iter_zero_runs(&self) -> IterZeroRuns<'_, T, O> {
+		IterZeroRuns::new(self)
+	}
+
+	/// Finds the first bit equal to `value`.
+	fn first_of(&self, value: bool) -> Option<usize> {
+		if value {
+			self.first_one()
+		}
+		else {
+			self.first_zero()
+		}
+	}
+
+	/// Finds the last bit equal to `value`.
+	fn last_of(&self, value: bool) -> Option<usize> {
+		if value {
+			self.last_one()
+		}
+		else {
+			self.last_zero()
+		}
+	}
+}
+
+#[derive(Clone, Copy, Debug)]
+#[doc = include_str!("../../doc/slice/runs/IterRuns.md")]
+pub struct IterRuns<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// The remaining bit-slice whose runs are to be found.
+	inner: &'a BitSlice<T, O>,
+	/// The offset from the front of the original bit-slice to the current
+	/// `.inner`.
+	front: usize,
+}
+
+impl<'a, T, O> IterRuns<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// Begins iteration over the runs of a bit-slice.
+	fn new(slice: &'a BitSlice<T, O>) -> Self {
+		Self {
+			inner: slice,
+			front: 0,
+		}
+	}
+}
+
+impl<T, O> Iterator for IterRuns<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Item = (usize, usize, bool);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let value = *self.inner.first()?;
+		let len = self
+			.inner
+			.first_of(!value)
+			.unwrap_or_else(|| self.inner.len());
+		let start = self.front;
+		self.inner = unsafe { self.inner.get_unchecked(len ..) };
+		self.front += len;
+		Some((start, len, value))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.inner.len();
+		(cmp::min(len, 1), Some(len))
+	}
+
+	#[inline]
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+}
+
+impl<T, O> DoubleEndedIterator for IterRuns<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let value = *self.inner.last()?;
+		let start = self.inner.last_of(!value).map_or(0, |idx| idx + 1);
+		let len = self.inner.len() - start;
+		self.inner = unsafe { self.inner.get_unchecked(.. start) };
+		Some((self.front + start, len, value))
+	}
+}
+
+impl<T, O> FusedIterator for IterRuns<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+}
+
+/// Creates iterators over the runs of a single bit-value.
+macro_rules! value_runs {
+	($($iter:ident => $value:expr, $doc:literal);+ $(;)?) => { $(
+		#[derive(Clone, Copy, Debug)]
+		#[doc = include_str!($doc)]
+		pub struct $iter<'a, T, O>
+		where
+			T: 'a + BitStore,
+			O: BitOrder,
+		{
+			/// The remaining bit-slice whose runs are to be found.
+			inner: &'a BitSlice<T, O>,
+			/// The offset from the front of the original bit-slice to the
+			/// current `.inner`.
+			front: usize,
+		}
+
+		impl<'a, T, O> $iter<'a, T, O>
+		where
+			T: 'a + BitStore,
+			O: BitOrder,
+		{
+			/// Begins iteration over the runs of a bit-slice.
+			fn new(slice: &'a BitSlice<T, O>) -> Self {
+				Self {
+					inner: slice,
+					front: 0,
+				}
+			}
+		}
+
+		impl<T, O> Iterator for $iter<'_, T, O>
+		where
+			T: BitStore,
+			O: BitOrder,
+		{
+			type Item = (usize, usize);
+
+			#[inline]
+			fn next(&mut self) -> Option<Self::Item> {
+				let start = match self.inner.first_of($value) {
+					Some(start) => start,
+					None => {
+						self.inner = Default::default();
+						return None;
+					},
+				};
+				let rest = unsafe { self.inner.get_unchecked(start ..) };
+				let len = rest.first_of(!$value).unwrap_or_else(|| rest.len());
+				self.inner = unsafe { rest.get_unchecked(len ..) };
+				let out = (self.front + start, len);
+				self.front += start + len;
+				Some(out)
+			}
+
+			#[inline]
+			fn size_hint(&self) -> (usize, Option<usize>) {
+				(0, Some((self.inner.len() + 1) / 2))
+			}
+
+			#[inline]
+			fn last(mut self) -> Option<Self::Item> {
+				self.next_back()
+			}
+		}
+
+		impl<T, O> DoubleEndedIterator for $iter<'_, T, O>
+		where
+			T: BitStore,
+			O: BitOrder,
+		{
+			#[inline]
+			fn next_back(&mut self) -> Option<Self::Item> {
+				let end = match self.inner.last_of($value) {
+					Some(last) => last + 1,
+					None => {
+						self.inner = Default::default();
+						return None;
+					},
+				};
+				let start = unsafe { self.inner.get_unchecked(.. end) }
+					.last_of(!$value)
+					.map_or(0, |idx| idx + 1);
+				self.inner = unsafe { self.inner.get_unchecked(.. start) };
+				Some((self.front + start, end - start))
+			}
+		}
+
+		impl<T, O> FusedIterator for $iter<'_, T, O>
+		where
+			T: BitStore,
+			O: BitOrder,
+		{
+		}
+	)+ };
+}
+
+value_runs! {
+	IterOneRuns => true, "../../doc/slice/runs/IterOneRuns.md";
+	IterZeroRuns => false, "../../doc/slice/runs/IterZeroRuns.md";
+}
+
+/// The state of a run search, carried across the memory elements of a
+/// bit-slice.
+struct RunScan {
+	/// The bit value that makes up a run.
+	value: bool,
+	/// The minimum length of the run being sought.
+	len:   usize,
+	/// The required alignment of the start of the run.
+	align: usize,
+	/// The bit-slice index of the next bit to be scanned.
+	pos:   usize,
+	/// The bit-slice index at which the current run began.
+	start: usize,
+}
+
+impl RunScan {
+	/// Scans the live bits of one memory element.
+	///
+	/// ## Parameters
+	///
+	/// - `elem`: The value of the memory element.
+	/// - `head`, `tail`: The bounds of the live bits in `elem`.
+	/// - `mask`: The selection mask of the live bits in `elem`.
+	///
+	/// ## Returns
+	///
+	/// The start of the first run long enough to satisfy the search, if one
+	/// ends within this element.
+	fn feed<R, O>(
+		&mut self,
+		elem: R,
+		head: BitIdx<R>,
+		tail: BitEnd<R>,
+		mask: BitMask<R>,
+	) -> Option<usize>
+	where
+		R: BitRegister,
+		O: BitOrder,
+	{
+		let mask = mask.into_inner();
+		let hits = if self.value { elem } else { !elem } & mask;
+		if hits == mask {
+			return self
+				.extend((tail.into_inner() - head.into_inner()) as usize);
+		}
+		if hits == R::ZERO {
+			self.pos += (tail.into_inner() - head.into_inner()) as usize;
+			self.start = self.pos;
+			return None;
+		}
+		//  `Lsb0` and `Msb0` store the live bits in order of significance, so
+		//  the runs within the element can be counted rather than walked.
+		let ordered = if dvl::match_order::<O, Lsb0>() {
+			Some(hits)
+		}
+		else if dvl::match_order::<O, Msb0>() {
+			Some(hits.reverse_bits())
+		}
+		else {
+			None
+		};
+		if let Some(bits) = ordered {
+			return self.feed_ordered(
+				bits >> head.into_inner(),
+				(tail.into_inner() - head.into_inner()) as usize,
+			);
+		}
+		for idx in head.range(tail) {
+			if hits & idx.select::<O>().into_inner() != R::ZERO {
+				if let Some(start) = self.extend(1) {
+					return Some(start);
+				}
+			}
+			else {
+				self.pos += 1;
+				self.start = self.pos;
+			}
+		}
+		None
+	}
+
+	/// Scans the `count` live bits of one memory element, one run at a time.
+	///
+	/// `hits` holds the live bits in order, starting at its least significant
+	/// bit, with a `1` wherever the bit matches the run value. Any bits above
+	/// the first `count` are ignored.
+	fn feed_ordered<R>(
+		&mut self,
+		mut hits: R,
+		mut count: usize,
+	) -> Option<usize>
+	where
+		R: BitRegister,
+	{
+		loop {
+			let ones = cmp::min(hits.trailing_ones() as usize, count);
+			if ones > 0 {
+				if let Some(start) = self.extend(ones) {
+					return Some(start);
+				}
+			}
+			count -= ones;
+			if count == 0 {
+				return None;
+			}
+			//  Neither shift can be by the full width: a full element of equal
+			//  bits was already consumed by `.feed()`.
+			hits >>= ones as u32;
+
+			let zeros = cmp::min(hits.trailing_zeros() as usize, count);
+			self.pos += zeros;
+			self.start = self.pos;
+			count -= zeros;
+			if count == 0 {
+				return None;
+			}
+			hits >>= zeros as u32;
+		}
+	}
+
+	/// Extends the current run by some number of bits.
+	///
+	/// ## Returns
+	///
+	/// The first aligned index in the current run, if the run now holds at
+	/// least `self.len` bits after it.
+	fn extend(&mut self, count: usize) -> Option<usize> {
+		self.pos += count;
+		let aligned = match self.start % self.align {
+			0 => Some(self.start),
+			rem => self.start.checked_add(self.align - rem),
+		}?;
+		self.pos
+			.checked_sub(aligned)
+			.filter(|&run| run >= self.len)
+			.map(|_| aligned)
+	}
+}
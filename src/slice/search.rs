@@ -0,0 +1,539 @@
+#![doc = include_str!("../../doc/slice/search.md")]
+
+use core::{
+	cmp,
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	iter::FusedIterator,
+};
+
+use super::{
+	specialization::WORD_BITS,
+	BitSlice,
+};
+use crate::{
+	order::BitOrder,
+	store::BitStore,
+};
+
+/// Sub-sequence search.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Finds the index of the first occurrence of a bit-sequence within the
+	/// bit-slice.
+	///
+	/// The search key does not need to share type parameters with the
+	/// bit-slice being searched, as the comparison is bit-wise.
+	///
+	/// ## Original
+	///
+	/// [`str::find`](https://doc.rust-lang.org/std/primitive.str.html#method.find)
+	///
+	/// ## Performance
+	///
+	/// Rather than comparing the needle against every window of the bit-slice
+	/// in turn, this loads processor words out of the bit-slice and tests the
+	/// front of the needle against every offset within each word using only
+	/// register shifts. The remainder of the needle is compared, one word at a
+	/// time, only at offsets where the front matches.
+	///
+	/// `Lsb0` and `Msb0` bit-slices are loaded in batches; all other orderings
+	/// must be loaded bit by bit.
+	///
+	/// ## Returns
+	///
+	/// The index of the first bit in `self` at which `needle` begins, or `None`
+	/// if `needle` does not occur in `self`. An empty needle is found at index
+	/// `0`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 0, 1, 0, 1, 1, 0, 1, 1];
+	/// assert_eq!(bits.find(bits![1, 1]), Some(4));
+	/// assert_eq!(bits.find(bits![u8, Msb0; 0, 1, 1]), Some(3));
+	/// assert!(bits.find(bits![1, 1, 1]).is_none());
+	/// assert_eq!(bits.find(bits![]), Some(0));
+	/// ```
+	#[inline]
+	pub fn find<T2, O2>(&self, needle: &BitSlice<T2, O2>) -> Option<usize>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let last = self.len().checked_sub(needle.len())?;
+		Needle::new(needle).find_in(self, 0, last + 1)
+	}
+
+	/// Finds the index of the last occurrence of a bit-sequence within the
+	/// bit-slice.
+	///
+	/// This has the same behavior as [`.find()`], except that it searches from
+	/// the back of the bit-slice towards the front.
+	///
+	/// ## Original
+	///
+	/// [`str::rfind`](https://doc.rust-lang.org/std/primitive.str.html#method.rfind)
+	///
+	/// ## Returns
+	///
+	/// The index of the first bit in `self` at which the last occurrence of
+	/// `needle` begins, or `None` if `needle` does not occur in `self`. An
+	/// empty needle is found at index `self.len()`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 0, 1, 0, 1, 1, 0, 1, 1];
+	/// assert_eq!(bits.rfind(bits![1, 1]), Some(7));
+	/// assert_eq!(bits.rfind(bits![0, 0]), Some(0));
+	/// assert!(bits.rfind(bits![0, 0, 0]).is_none());
+	/// assert_eq!(bits.rfind(bits![]), Some(9));
+	/// ```
+	///
+	/// [`.find()`]: Self::find
+	#[inline]
+	pub fn rfind<T2, O2>(&self, needle: &BitSlice<T2, O2>) -> Option<usize>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let last = self.len().checked_sub(needle.len())?;
+		Needle::new(needle).rfind_in(self, 0, last + 1)
+	}
+
+	/// Iterates over the disjoint occurrences of a bit-sequence within the
+	/// bit-slice, yielding the index at which each begins.
+	///
+	/// Occurrences are found from the front of the bit-slice, and never
+	/// overlap: once an occurrence is found, searching resumes from the first
+	/// bit after it. When the needle is empty, every index in `0 ..=
+	/// self.len()` is yielded.
+	///
+	/// ## Original
+	///
+	/// [`str::match_indices`](https://doc.rust-lang.org/std/primitive.str.html#method.match_indices)
+	///
+	/// ## API Differences
+	///
+	/// The matched sequence is always equal to `needle`, so only the index is
+	/// yielded.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 1, 1, 0, 1, 1, 0, 1];
+	/// let found = bits.match_indices(bits![1, 1]).collect::<Vec<_>>();
+	/// assert_eq!(found, [0, 4]);
+	/// ```
+	#[inline]
+	pub fn match_indices<'a, T2, O2>(
+		&'a self,
+		needle: &'a BitSlice<T2, O2>,
+	) -> MatchIndices<'a, T, O, T2, O2>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		MatchIndices::new(self, needle)
+	}
+
+	/// Iterates over the disjoint occurrences of a bit-sequence within the
+	/// bit-slice, in reverse order, yielding the index at which each begins.
+	///
+	/// This has the same behavior as [`.match_indices()`], except that
+	/// occurrences are found from the back of the bit-slice. The occurrences
+	/// it finds may differ from those found from the front when the needle can
+	/// overlap with itself.
+	///
+	/// ## Original
+	///
+	/// [`str::rmatch_indices`](https://doc.rust-lang.org/std/primitive.str.html#method.rmatch_indices)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 1, 1, 0, 1, 1, 0, 1];
+	/// let found = bits.rmatch_indices(bits![1, 1]).collect::<Vec<_>>();
+	/// assert_eq!(found, [4, 1]);
+	/// ```
+	///
+	/// [`.match_indices()`]: Self::match_indices
+	#[inline]
+	pub fn rmatch_indices<'a, T2, O2>(
+		&'a self,
+		needle: &'a BitSlice<T2, O2>,
+	) -> RMatchIndices<'a, T, O, T2, O2>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		RMatchIndices::new(self, needle)
+	}
+}
+
+#[doc = include_str!("../../doc/slice/search/MatchIndices.md")]
+pub struct MatchIndices<'a, T, O, T2, O2>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+	T2: 'a + BitStore,
+	O2: BitOrder,
+{
+	/// The bit-slice being searched.
+	haystack: &'a BitSlice<T, O>,
+	/// The preprocessed search key.
+	needle:   Needle<'a, T2, O2>,
+	/// The lowest index at which the next occurrence may begin.
+	front:    usize,
+	/// One past the highest index at which an occurrence may begin.
+	back:     usize,
+}
+
+impl<'a, T, O, T2, O2> MatchIndices<'a, T, O, T2, O2>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+	T2: 'a + BitStore,
+	O2: BitOrder,
+{
+	#[inline]
+	#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+	fn new(haystack: &'a BitSlice<T, O>, needle: &'a BitSlice<T2, O2>) -> Self {
+		let back = haystack
+			.len()
+			.checked_sub(needle.len())
+			.map_or(0, |last| last + 1);
+		Self {
+			haystack,
+			needle: Needle::new(needle),
+			front: 0,
+			back,
+		}
+	}
+}
+
+impl<'a, T, O, T2, O2> Iterator for MatchIndices<'a, T, O, T2, O2>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+	T2: 'a + BitStore,
+	O2: BitOrder,
+{
+	type Item = usize;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.needle.find_in(self.haystack, self.front, self.back) {
+			Some(idx) => {
+				self.front = idx + cmp::max(self.needle.bits.len(), 1);
+				Some(idx)
+			},
+			None => {
+				self.front = self.back;
+				None
+			},
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, Some(self.back.saturating_sub(self.front)))
+	}
+}
+
+impl<'a, T, O, T2, O2> FusedIterator for MatchIndices<'a, T, O, T2, O2>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+	T2: 'a + BitStore,
+	O2: BitOrder,
+{
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, T, O, T2, O2> Debug for MatchIndices<'a, T, O, T2, O2>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+	T2: 'a + BitStore,
+	O2: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("MatchIndices")
+			.field("haystack", &self.haystack)
+			.field("needle", &self.needle.bits)
+			.field("front", &self.front)
+			.field("back", &self.back)
+			.finish()
+	}
+}
+
+#[doc = include_str!("../../doc/slice/search/RMatchIndices.md")]
+pub struct RMatchIndices<'a, T, O, T2, O2>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+	T2: 'a + BitStore,
+	O2: BitOrder,
+{
+	/// The bit-slice being searched.
+	haystack: &'a BitSlice<T, O>,
+	/// The preprocessed search key.
+	needle:   Needle<'a, T2, O2>,
+	/// One past the highest index at which the next occurrence may begin.
+	back:     usize,
+}
+
+impl<'a, T, O, T2, O2> RMatchIndices<'a, T, O, T2, O2>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+	T2: 'a + BitStore,
+	O2: BitOrder,
+{
+	#[inline]
+	#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+	fn new(haystack: &'a BitSlice<T, O>, needle: &'a BitSlice<T2, O2>) -> Self {
+		let back = haystack
+			.len()
+			.checked_sub(needle.len())
+			.map_or(0, |last| last + 1);
+		Self {
+			haystack,
+			needle: Needle::new(needle),
+			back,
+		}
+	}
+}
+
+impl<'a, T, O, T2, O2> Iterator for RMatchIndices<'a, T, O, T2, O2>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+	T2: 'a + BitStore,
+	O2: BitOrder,
+{
+	type Item = usize;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.needle.rfind_in(self.haystack, 0, self.back) {
+			Some(idx) => {
+				//  The next occurrence must end at or before `idx`, and so must
+				//  begin at least a needle’s length before it.
+				self.back = match self.needle.bits.len() {
+					0 => idx,
+					len => (idx + 1).saturating_sub(len),
+				};
+				Some(idx)
+			},
+			None => {
+				self.back = 0;
+				None
+			},
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, Some(self.back))
+	}
+}
+
+impl<'a, T, O, T2, O2> FusedIterator for RMatchIndices<'a, T, O, T2, O2>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+	T2: 'a + BitStore,
+	O2: BitOrder,
+{
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, T, O, T2, O2> Debug for RMatchIndices<'a, T, O, T2, O2>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+	T2: 'a + BitStore,
+	O2: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("RMatchIndices")
+			.field("haystack", &self.haystack)
+			.field("needle", &self.needle.bits)
+			.field("back", &self.back)
+			.finish()
+	}
+}
+
+/// A search key, with its first word pre-loaded for comparison against each
+/// offset in the haystack.
+struct Needle<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// The full search key.
+	bits: &'a BitSlice<T, O>,
+	/// The first `WORD_BITS` bits of the search key, loaded in index order.
+	head: usize,
+	/// Selects the live bits of `head`.
+	mask: usize,
+}
+
+impl<'a, T, O> Needle<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// Preprocesses a search key.
+	fn new(bits: &'a BitSlice<T, O>) -> Self {
+		let width = cmp::min(bits.len(), WORD_BITS);
+		let head = unsafe { bits.get_unchecked(.. width) }.load_word();
+		let mask = match width {
+			WORD_BITS => !0,
+			n => (1 << n) - 1,
+		};
+		Self { bits, head, mask }
+	}
+
+	/// Finds the lowest index in `start .. end` at which the needle occurs in
+	/// the haystack.
+	///
+	/// The caller must ensure that `end` is no greater than one more than the
+	/// highest index at which the needle can begin.
+	fn find_in<T2, O2>(
+		&self,
+		haystack: &BitSlice<T2, O2>,
+		start: usize,
+		end: usize,
+	) -> Option<usize>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let mut base = start;
+		while base < end {
+			let (lo, hi) = Self::load_pair(haystack, base);
+			let span = cmp::min(WORD_BITS, end - base);
+			if let Some(offset) = (0 .. span)
+				.find(|&off| self.matches(haystack, base, off, lo, hi))
+			{
+				return Some(base + offset);
+			}
+			base += span;
+		}
+		None
+	}
+
+	/// Finds the highest index in `start .. end` at which the needle occurs in
+	/// the haystack.
+	///
+	/// The caller must ensure that `end` is no greater than one more than the
+	/// highest index at which the needle can begin.
+	fn rfind_in<T2, O2>(
+		&self,
+		haystack: &BitSlice<T2, O2>,
+		start: usize,
+		end: usize,
+	) -> Option<usize>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let mut limit = end;
+		while limit > start {
+			let span = cmp::min(WORD_BITS, limit - start);
+			let base = limit - span;
+			let (lo, hi) = Self::load_pair(haystack, base);
+			if let Some(offset) = (0 .. span)
+				.rev()
+				.find(|&off| self.matches(haystack, base, off, lo, hi))
+			{
+				return Some(base + offset);
+			}
+			limit = base;
+		}
+		None
+	}
+
+	/// Loads the two words of the haystack beginning at `base`.
+	///
+	/// Together, these hold the first `WORD_BITS` bits of every window that
+	/// begins in the first word. Bits past the end of the haystack are zeroed.
+	fn load_pair<T2, O2>(
+		haystack: &BitSlice<T2, O2>,
+		base: usize,
+	) -> (usize, usize)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let len = haystack.len();
+		let mid = cmp::min(base + WORD_BITS, len);
+		let end = cmp::min(mid + WORD_BITS, len);
+		unsafe {
+			(
+				haystack.get_unchecked(base .. mid).load_word(),
+				haystack.get_unchecked(mid .. end).load_word(),
+			)
+		}
+	}
+
+	/// Tests whether the needle occurs at `base + offset` in the haystack.
+	///
+	/// `lo` and `hi` are the words loaded by `load_pair(haystack, base)`. The
+	/// front of the needle is tested against them with register shifts; the
+	/// rest of the needle is only loaded and compared if that succeeds.
+	fn matches<T2, O2>(
+		&self,
+		haystack: &BitSlice<T2, O2>,
+		base: usize,
+		offset: usize,
+		lo: usize,
+		hi: usize,
+	) -> bool
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let window = match offset {
+			0 => lo,
+			n => (lo >> n) | (hi << (WORD_BITS - n)),
+		};
+		if window & self.mask != self.head {
+			return false;
+		}
+
+		let len = self.bits.len();
+		if len <= WORD_BITS {
+			return true;
+		}
+		let start = base + offset + WORD_BITS;
+		let rest =
+			unsafe { haystack.get_unchecked(start .. start + len - WORD_BITS) };
+		rest.chunks(WORD_BITS)
+			.zip(
+				unsafe { self.bits.get_unchecked(WORD_BITS ..) }
+					.chunks(WORD_BITS),
+			)
+			.all(|(a, b)| a.load_word() == b.load_word())
+	}
+}
@@ -1,12 +1,19 @@
 #![doc = include_str!("../../doc/slice/specialization.md")]
 
+use core::cmp;
+
 use funty::Integral;
 
 use super::BitSlice;
 use crate::{
 	devel as dvl,
+	field::BitField,
 	mem,
-	order::BitOrder,
+	order::{
+		BitOrder,
+		Lsb0,
+		Msb0,
+	},
 	store::BitStore,
 };
 
@@ -14,7 +21,7 @@ mod lsb0;
 mod msb0;
 
 /// Processor width, used for chunking.
-const WORD_BITS: usize = mem::bits_of::<usize>();
+pub(crate) const WORD_BITS: usize = mem::bits_of::<usize>();
 
 /// Tests whether the masked portion of an integer has a `0` bit in it.
 fn has_zero<T>(val: T, mask: T) -> bool
@@ -78,4 +85,176 @@ where
 			None
 		}
 	}
+
+	/// Applies a Boolean-arithmetic function across all the bits in a pair of
+	/// bit-slices, writing the result into `self`.
+	///
+	/// The secondary bit-slice is zero-extended if it expires before `self`
+	/// does. `Lsb0` and `Msb0` bit-slices are dispatched to the batched
+	/// `.sp_bitop_assign()` kernels; all others are processed bit by bit.
+	pub(crate) fn bitop_assign(
+		&mut self,
+		rhs: &Self,
+		word_op: fn(usize, usize) -> usize,
+		bool_op: fn(bool, bool) -> bool,
+	) {
+		if let (Some(this), Some(that)) =
+			(self.coerce_mut::<T, Lsb0>(), rhs.coerce::<T, Lsb0>())
+		{
+			return this.sp_bitop_assign(that, word_op, bool_op);
+		}
+		if let (Some(this), Some(that)) =
+			(self.coerce_mut::<T, Msb0>(), rhs.coerce::<T, Msb0>())
+		{
+			return this.sp_bitop_assign(that, word_op, bool_op);
+		}
+		let rhs = rhs.iter().by_vals().chain(core::iter::repeat(false));
+		for (this, that) in self.as_mut_bitptr_range().zip(rhs) {
+			unsafe {
+				this.write(bool_op(this.read(), that));
+			}
+		}
+	}
+
+	/// Moves every bit `by` places towards the front of the bit-slice, and
+	/// clears the vacated bits at the back to `0`.
+	///
+	/// The bit-slice is processed as a sequence of `WORD_BITS`-wide words. Each
+	/// destination word is assembled from the two source words that it
+	/// straddles, with the upper source word carried into the next step so
+	/// that every word is loaded and stored only once. Words are transferred
+	/// with [`.load_word()`] and [`.store_word()`], which are batched for
+	/// `Lsb0` and `Msb0` bit-slices.
+	///
+	/// `by` must be less than `self.len()`.
+	///
+	/// [`.load_word()`]: Self::load_word
+	/// [`.store_word()`]: Self::store_word
+	pub(crate) fn shift_words_start(&mut self, by: usize) {
+		let len = self.len();
+		debug_assert!(by < len, "shift {} out of range for {}", by, len);
+		let words = (len + WORD_BITS - 1) / WORD_BITS;
+		let (skip, offset) = (by / WORD_BITS, by % WORD_BITS);
+		let mut low = self.word_at(skip);
+		for idx in 0 .. words {
+			let high = self.word_at(idx + skip + 1);
+			let word = match offset {
+				0 => low,
+				n => (low >> n) | (high << (WORD_BITS - n)),
+			};
+			self.set_word_at(idx, word);
+			low = high;
+		}
+	}
+
+	/// Moves every bit `by` places towards the back of the bit-slice, and
+	/// clears the vacated bits at the front to `0`.
+	///
+	/// This is the mirror of [`.shift_words_start()`], and walks the words of
+	/// the bit-slice from back to front.
+	///
+	/// `by` must be less than `self.len()`.
+	///
+	/// [`.shift_words_start()`]: Self::shift_words_start
+	pub(crate) fn shift_words_end(&mut self, by: usize) {
+		let len = self.len();
+		debug_assert!(by < len, "shift {} out of range for {}", by, len);
+		let words = (len + WORD_BITS - 1) / WORD_BITS;
+		let (skip, offset) = (by / WORD_BITS, by % WORD_BITS);
+		let source = |idx: usize| idx.checked_sub(skip);
+		let mut high = source(words - 1).map_or(0, |src| self.word_at(src));
+		for idx in (0 .. words).rev() {
+			let low = source(idx)
+				.and_then(|src| src.checked_sub(1))
+				.map_or(0, |src| self.word_at(src));
+			let word = match offset {
+				0 => high,
+				n => (high << n) | (low >> (WORD_BITS - n)),
+			};
+			self.set_word_at(idx, word);
+			high = low;
+		}
+	}
+
+	/// Loads the `idx`th `WORD_BITS`-wide word of the bit-slice with
+	/// [`.load_word()`]. Words past the end of the bit-slice are `0`.
+	///
+	/// [`.load_word()`]: Self::load_word
+	fn word_at(&self, idx: usize) -> usize {
+		let len = self.len();
+		let start = idx.saturating_mul(WORD_BITS);
+		if start >= len {
+			return 0;
+		}
+		let end = cmp::min(start + WORD_BITS, len);
+		unsafe { self.get_unchecked(start .. end) }.load_word()
+	}
+
+	/// Stores the `idx`th `WORD_BITS`-wide word of the bit-slice with
+	/// [`.store_word()`].
+	///
+	/// [`.store_word()`]: Self::store_word
+	fn set_word_at(&mut self, idx: usize, word: usize) {
+		let len = self.len();
+		let start = idx * WORD_BITS;
+		let end = cmp::min(start + WORD_BITS, len);
+		unsafe { self.get_unchecked_mut(start .. end) }.store_word(word);
+	}
+
+	/// Loads a bit-slice of at most `WORD_BITS` bits into a processor word,
+	/// placing the bit at index `n` in the bit-slice at `1 << n` in the word.
+	///
+	/// Unlike the `BitField` loads, the placement of each bit in the result
+	/// depends only on its index, not on the ordering or storage parameters of
+	/// the bit-slice, so words loaded out of bit-slices with different type
+	/// parameters can be compared directly. `Lsb0` and `Msb0` bit-slices are
+	/// loaded in batches; all others are loaded bit by bit.
+	pub(crate) fn load_word(&self) -> usize {
+		let len = self.len();
+		debug_assert!(len <= WORD_BITS, "cannot load {} bits into a word", len);
+		if len == 0 {
+			0
+		}
+		else if let Some(bits) = self.coerce::<T, Lsb0>() {
+			bits.load_le::<usize>()
+		}
+		else if let Some(bits) = self.coerce::<T, Msb0>() {
+			bits.load_be::<usize>().reverse_bits() >> (WORD_BITS - len)
+		}
+		else {
+			self.iter()
+				.by_vals()
+				.enumerate()
+				.fold(0, |word, (idx, bit)| word | ((bit as usize) << idx))
+		}
+	}
+
+	/// Stores the low bits of a processor word into a bit-slice of at most
+	/// `WORD_BITS` bits, placing the bit at `1 << n` in the word at index `n`
+	/// in the bit-slice.
+	///
+	/// This is the inverse of [`.load_word()`], and shares its indifference to
+	/// the type parameters of the bit-slice.
+	///
+	/// [`.load_word()`]: Self::load_word
+	pub(crate) fn store_word(&mut self, word: usize) {
+		let len = self.len();
+		debug_assert!(len <= WORD_BITS, "cannot store {} bits from a word", len);
+		if len == 0 {
+			return;
+		}
+		if let Some(bits) = self.coerce_mut::<T, Lsb0>() {
+			bits.store_le::<usize>(word);
+		}
+		else if let Some(bits) = self.coerce_mut::<T, Msb0>() {
+			bits.store_be::<usize>((word << (WORD_BITS - len)).reverse_bits());
+		}
+		else {
+			for idx in 0 .. len {
+				unsafe {
+					self.set_unchecked(idx, (word >> idx) & 1 == 1);
+				}
+			}
+		}
+	}
 }
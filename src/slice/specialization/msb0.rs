@@ -158,7 +158,8 @@ where T: BitStore
 
 	/// Seeks the index of the last `1` bit in the bit-slice.
 	pub(crate) fn sp_last_one(&self) -> Option<usize> {
-		let mut out = self.len().checked_sub(1)?;
+		let mut out = self.len();
+
 		match self.domain() {
 			Domain::Enclave(elem) => {
 				let val = elem.load_value();
@@ -166,7 +167,7 @@ where T: BitStore
 					bits_of::<T::Mem>() - elem.tail().into_inner() as usize;
 				if has_one(val, elem.mask().into_inner()) {
 					out -= val.trailing_zeros() as usize - dead_bits;
-					return Some(out);
+					return Some(out - 1);
 				}
 				None
 			},
@@ -177,14 +178,14 @@ where T: BitStore
 						bits_of::<T::Mem>() - elem.tail().into_inner() as usize;
 					out -= val.trailing_zeros() as usize - dead_bits;
 					if has_one(val, elem.mask().into_inner()) {
-						return Some(out);
+						return Some(out - 1);
 					}
 				}
 
 				for val in body.iter().map(BitStore::load_value).rev() {
 					out -= val.trailing_zeros() as usize;
 					if has_one(val, !<T::Mem as Integral>::ZERO) {
-						return Some(out);
+						return Some(out - 1);
 					}
 				}
 
@@ -192,7 +193,7 @@ where T: BitStore
 					let val = elem.load_value();
 					if has_one(val, elem.mask().into_inner()) {
 						out -= val.trailing_zeros() as usize;
-						return Some(out);
+						return Some(out - 1);
 					}
 				}
 
@@ -247,7 +248,8 @@ where T: BitStore
 
 	/// Seeks the index of the last `0` bit in the bit-slice.
 	pub(crate) fn sp_last_zero(&self) -> Option<usize> {
-		let mut out = self.len().checked_sub(1)?;
+		let mut out = self.len();
+
 		match self.domain() {
 			Domain::Enclave(elem) => {
 				let val = elem.load_value() | !elem.mask().into_inner();
@@ -255,7 +257,7 @@ where T: BitStore
 					bits_of::<T::Mem>() - elem.tail().into_inner() as usize;
 				if has_zero(val, elem.mask().into_inner()) {
 					out -= val.trailing_ones() as usize - dead_bits;
-					return Some(out);
+					return Some(out - 1);
 				}
 				None
 			},
@@ -266,14 +268,14 @@ where T: BitStore
 						bits_of::<T::Mem>() - elem.tail().into_inner() as usize;
 					out -= val.trailing_ones() as usize - dead_bits;
 					if has_zero(val, elem.mask().into_inner()) {
-						return Some(out);
+						return Some(out - 1);
 					}
 				}
 
 				for val in body.iter().map(BitStore::load_value).rev() {
 					out -= val.trailing_ones() as usize;
 					if has_zero(val, !<T::Mem as Integral>::ZERO) {
-						return Some(out);
+						return Some(out - 1);
 					}
 				}
 
@@ -281,7 +283,7 @@ where T: BitStore
 					let val = elem.load_value() | !elem.mask().into_inner();
 					if has_zero(val, elem.mask().into_inner()) {
 						out -= val.trailing_ones() as usize;
-						return Some(out);
+						return Some(out - 1);
 					}
 				}
 
@@ -2,8 +2,6 @@
 
 #![cfg(test)]
 
-use core::cell::Cell;
-
 use rand::random;
 
 use crate::{
@@ -11,9 +9,15 @@ use crate::{
 	prelude::*,
 };
 
+mod algebra;
 mod api;
+mod arith;
+mod atomic;
 mod iter;
+mod metrics;
 mod ops;
+mod runs;
+mod search;
 mod traits;
 
 #[test]
@@ -139,6 +143,8 @@ fn bit_counting() {
 	assert!([0u8; 3].view_bits::<Lsb0>()[1 .. 23].last_one().is_none());
 	assert!([0u8; 1].view_bits::<Msb0>()[1 .. 7].last_one().is_none());
 	assert!([0u8; 3].view_bits::<Msb0>()[1 .. 23].last_one().is_none());
+	assert!([0u8; 1].view_bits::<Lsb0>()[.. 4].last_one().is_none());
+	assert!([0u8; 1].view_bits::<Msb0>()[.. 4].last_one().is_none());
 
 	assert!([!0u8; 1].view_bits::<Lsb0>()[1 .. 7].first_zero().is_none());
 	assert!(
@@ -157,6 +163,8 @@ fn bit_counting() {
 	assert!([!0u8; 3].view_bits::<Lsb0>()[1 .. 23].last_zero().is_none());
 	assert!([!0u8; 1].view_bits::<Msb0>()[1 .. 7].last_zero().is_none());
 	assert!([!0u8; 3].view_bits::<Msb0>()[1 .. 23].last_zero().is_none());
+	assert!([!0u8; 1].view_bits::<Lsb0>()[.. 4].last_zero().is_none());
+	assert!([!0u8; 1].view_bits::<Msb0>()[.. 4].last_zero().is_none());
 
 	let data = 0b0100_0100u8;
 	assert_eq!(data.view_bits::<Lsb0>()[1 .. 7].first_one(), Some(1));
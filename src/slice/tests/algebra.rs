@@ -0,0 +1,117 @@
+#![cfg(test)]
+
+use rand::random;
+
+use crate::{
+	order::HiLo,
+	prelude::*,
+};
+
+/// Combines two bit-slices bit by bit, zero-extending the shorter one.
+fn naive<T1, O1, T2, O2>(
+	a: &BitSlice<T1, O1>,
+	b: &BitSlice<T2, O2>,
+	len: usize,
+	op: fn(bool, bool) -> bool,
+) -> Vec<usize>
+where
+	T1: BitStore,
+	O1: BitOrder,
+	T2: BitStore,
+	O2: BitOrder,
+{
+	(0 .. len)
+		.filter(|&idx| {
+			let x = a.get(idx).map_or(false, |bit| *bit);
+			let y = b.get(idx).map_or(false, |bit| *bit);
+			op(x, y)
+		})
+		.collect()
+}
+
+fn check<T1, O1, T2, O2>(a: &BitSlice<T1, O1>, b: &BitSlice<T2, O2>)
+where
+	T1: BitStore,
+	O1: BitOrder,
+	T2: BitStore,
+	O2: BitOrder,
+{
+	let min = a.len().min(b.len());
+	let max = a.len().max(b.len());
+
+	let and = naive(a, b, min, |x, y| x & y);
+	let or = naive(a, b, max, |x, y| x | y);
+	let sub = naive(a, b, a.len(), |x, y| x & !y);
+	let xor = naive(a, b, max, |x, y| x ^ y);
+
+	assert_eq!(a.intersection(b).collect::<Vec<_>>(), and);
+	assert_eq!(a.union(b).collect::<Vec<_>>(), or);
+	assert_eq!(a.difference(b).collect::<Vec<_>>(), sub);
+	assert_eq!(a.symmetric_difference(b).collect::<Vec<_>>(), xor);
+
+	let rev = |mut v: Vec<usize>| {
+		v.reverse();
+		v
+	};
+	assert_eq!(
+		a.intersection(b).rev().collect::<Vec<_>>(),
+		rev(and.clone())
+	);
+	assert_eq!(a.union(b).rev().collect::<Vec<_>>(), rev(or.clone()));
+	assert_eq!(a.difference(b).rev().collect::<Vec<_>>(), rev(sub));
+	assert_eq!(
+		a.symmetric_difference(b).rev().collect::<Vec<_>>(),
+		rev(xor.clone()),
+	);
+
+	//  Alternate ends, so that the front and back words meet in the middle.
+	let mut iter = a.symmetric_difference(b);
+	let (mut front, mut back) = (Vec::new(), Vec::new());
+	loop {
+		let left = xor.len() - front.len() - back.len();
+		let (lo, hi) = iter.size_hint();
+		assert!(lo <= left && hi.map_or(true, |hi| left <= hi));
+		let step = if random() {
+			iter.next().map(|idx| front.push(idx))
+		}
+		else {
+			iter.next_back().map(|idx| back.push(idx))
+		};
+		if step.is_none() {
+			break;
+		}
+	}
+	front.extend(back.into_iter().rev());
+	assert_eq!(front, xor);
+	assert!(iter.next().is_none());
+	assert!(iter.next_back().is_none());
+
+	assert_eq!(a.intersection_count(b), and.len());
+	assert_eq!(a.union_count(b), or.len());
+	assert_eq!(b.intersection_count(a), and.len());
+	assert_eq!(b.union_count(a), or.len());
+}
+
+#[test]
+fn set_algebra() {
+	check(bits![], bits![u8, Msb0;]);
+	check(bits![1, 0, 1], bits![u8, Msb0;]);
+
+	for _ in 0 .. 20 {
+		let a = (0 .. random::<usize>() % 300)
+			.map(|_| random::<bool>())
+			.collect::<BitVec<u8, Lsb0>>();
+		let b = (0 .. random::<usize>() % 300)
+			.map(|_| random::<bool>())
+			.collect::<BitVec<u16, Msb0>>();
+		let c = (0 .. random::<usize>() % 300)
+			.map(|_| random::<bool>())
+			.collect::<BitVec<u32, HiLo>>();
+		let head = random::<usize>() % 8;
+
+		check(a.as_bitslice(), b.as_bitslice());
+		check(&a[head.min(a.len()) ..], b.as_bitslice());
+		check(b.as_bitslice(), c.as_bitslice());
+		check(c.as_bitslice(), &a[head.min(a.len()) ..]);
+	}
+}
@@ -0,0 +1,188 @@
+#![cfg(test)]
+
+use core::cmp::Ordering;
+
+use rand::random;
+
+use crate::{
+	order::HiLo,
+	prelude::*,
+};
+
+/// Reads a bit-slice of at most 128 bits as an integer, with the least
+/// significant bit at index `0` if `le`, or at the end otherwise.
+fn read<T, O>(bits: &BitSlice<T, O>, le: bool) -> u128
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let fold = |acc: u128, bit: bool| (acc << 1) | bit as u128;
+	if le {
+		bits.iter().by_vals().rev().fold(0, fold)
+	}
+	else {
+		bits.iter().by_vals().fold(0, fold)
+	}
+}
+
+/// Writes the low bits of an integer into a bit-slice, in the same manner as
+/// `read`.
+fn write<T, O>(bits: &mut BitSlice<T, O>, le: bool, val: u128)
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let len = bits.len();
+	for idx in 0 .. len {
+		let shift = if le { idx } else { len - 1 - idx };
+		bits.set(idx, (val >> shift) & 1 == 1);
+	}
+}
+
+/// Checks every operation on `len`-bit integers against `u128` arithmetic.
+fn check<T, O>(head: usize, len: usize, le: bool)
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let mut a = bitvec![T, O; 0; head + len];
+	let mut b = bitvec![u8, Msb0; 0; len];
+	let a = &mut a[head ..];
+	let modulus = if len == 128 { 0 } else { 1u128 << len };
+	let wrap = |val: u128| if len == 128 { val } else { val % modulus };
+	let max = wrap(!0);
+
+	for _ in 0 .. 8 {
+		let mut x = wrap(random());
+		let y = wrap(random());
+		if random::<u8>() % 4 == 0 {
+			x = [0, max, y][random::<usize>() % 3];
+		}
+		write(&mut b, le, y);
+
+		macro_rules! run {
+			($le:ident, $be:ident $(, $arg:expr)?) => {{
+				write(a, le, x);
+				let overflow = if le { a.$le($($arg)?) } else { a.$be($($arg)?) };
+				(read(a, le), overflow)
+			}};
+		}
+
+		let (sum, carry) = x.overflowing_add(y);
+		let carry = carry || (len < 128 && sum >= modulus);
+		assert_eq!(
+			run!(overflowing_add_le, overflowing_add_be, &b),
+			(wrap(sum), carry),
+			"{} + {} in {} bits",
+			x,
+			y,
+			len,
+		);
+		assert_eq!(
+			run!(overflowing_sub_le, overflowing_sub_be, &b),
+			(wrap(x.wrapping_sub(y)), y > x),
+		);
+		assert_eq!(
+			run!(overflowing_neg_le, overflowing_neg_be),
+			(wrap(x.wrapping_neg()), x != 0),
+		);
+		assert_eq!(
+			run!(overflowing_inc_le, overflowing_inc_be),
+			(wrap(x.wrapping_add(1)), x == max),
+		);
+		assert_eq!(
+			run!(overflowing_dec_le, overflowing_dec_be),
+			(wrap(x.wrapping_sub(1)), x == 0),
+		);
+		let product = x.checked_mul(y).filter(|prod| wrap(*prod) == *prod);
+		assert_eq!(
+			run!(overflowing_mul_le, overflowing_mul_be, &b),
+			(wrap(x.wrapping_mul(y)), product.is_none()),
+			"{} * {} in {} bits",
+			x,
+			y,
+			len,
+		);
+
+		write(a, le, x);
+		let sign = 1u128 << (len - 1);
+		let (sx, sy) = (x ^ sign, y ^ sign);
+		if le {
+			assert_eq!(a.cmp_unsigned_le(&b), x.cmp(&y));
+			assert_eq!(a.cmp_signed_le(&b), sx.cmp(&sy));
+		}
+		else {
+			assert_eq!(a.cmp_unsigned_be(&b), x.cmp(&y));
+			assert_eq!(a.cmp_signed_be(&b), sx.cmp(&sy));
+		}
+	}
+}
+
+#[test]
+fn against_u128() {
+	for len in (1 ..= 128).filter(|len| len % 9 < 2 || len % 32 < 2) {
+		for le in [true, false].iter().copied() {
+			check::<u8, Lsb0>(3, len, le);
+			check::<u16, Msb0>(11, len, le);
+			check::<u32, HiLo>(0, len, le);
+			check::<usize, Lsb0>(0, len, le);
+		}
+	}
+}
+
+#[test]
+fn wide() {
+	let mut a = bitvec![u16, Msb0; 0; 257];
+	let mut b = bitvec![u32, Lsb0; 0; 257];
+	for mut bit in a.iter_mut() {
+		*bit = random();
+	}
+	for mut bit in b.iter_mut() {
+		*bit = random();
+	}
+
+	let mut c = a.clone();
+	c.wrapping_add_le(&b);
+	c.wrapping_sub_le(&b);
+	assert_eq!(c, a);
+
+	let mut ab = a.clone();
+	ab.wrapping_mul_be(&b);
+	let mut ba = b.clone();
+	ba.wrapping_mul_be(&a);
+	assert_eq!(ab, ba);
+
+	let mut neg = a.clone();
+	neg.wrapping_neg_le();
+	let mut zero = bitvec![0; 257];
+	zero.wrapping_sub_le(&a);
+	assert_eq!(neg, zero);
+	neg.wrapping_add_le(&a);
+	assert!(neg.not_any());
+
+	let mut max = bitvec![1; 257];
+	assert!(max.overflowing_inc_be());
+	assert!(max.not_any());
+	assert!(max.overflowing_dec_le());
+	assert!(max.all());
+
+	let mut one = bitvec![0; 257];
+	one.set(0, true);
+	let mut root = bitvec![0; 257];
+	root.set(129, true);
+	assert!(!root.clone().overflowing_mul_le(&one));
+	let mut sq = root.clone();
+	assert!(sq.overflowing_mul_le(&root));
+	assert!(sq.not_any());
+
+	assert_eq!(a.cmp_unsigned_le(&a), Ordering::Equal);
+	assert_eq!(one.cmp_signed_le(&max), Ordering::Greater);
+	assert_eq!(one.cmp_unsigned_le(&max), Ordering::Less);
+	assert!(BitSlice::<usize, Lsb0>::empty_mut().overflowing_inc_le());
+}
+
+#[test]
+#[should_panic = "cannot combine integers of different lengths"]
+fn length_mismatch() {
+	bits![mut 0; 4].wrapping_add_le(bits![0; 5]);
+}
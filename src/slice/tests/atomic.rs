@@ -0,0 +1,134 @@
+#![cfg(test)]
+
+use core::{
+	cell::Cell,
+	sync::atomic::Ordering::*,
+};
+
+use rand::random;
+
+use crate::{
+	order::HiLo,
+	prelude::*,
+};
+
+#[test]
+fn fetch_ops() {
+	fn check<O>()
+	where O: BitOrder {
+		let mut model = bitvec![0; 40];
+		let data: [Cell<u8>; 6] = Default::default();
+		let bits = &data.view_bits::<O>()[5 .. 45];
+		for _ in 0 .. 200 {
+			let idx = random::<usize>() % 40;
+			let prev = model[idx];
+			match random::<u8>() % 3 {
+				0 => {
+					assert_eq!(bits.fetch_set(idx, Relaxed), prev);
+					model.set(idx, true);
+				},
+				1 => {
+					assert_eq!(bits.fetch_clear(idx, Relaxed), prev);
+					model.set(idx, false);
+				},
+				_ => {
+					assert_eq!(bits.fetch_toggle(idx, Relaxed), prev);
+					model.set(idx, !prev);
+				},
+			}
+			assert_eq!(bits, model);
+		}
+	}
+
+	check::<Lsb0>();
+	check::<Msb0>();
+	check::<HiLo>();
+}
+
+#[test]
+fn compare_exchange() {
+	let data = [Cell::new(0xF0u8), Cell::new(0x0F)];
+	let bits = &data.view_bits::<Lsb0>()[2 .. 14];
+
+	assert_eq!(
+		bits.compare_exchange_bit(0, false, true, AcqRel, Acquire),
+		Ok(false)
+	);
+	assert_eq!(
+		bits.compare_exchange_bit(0, false, true, AcqRel, Acquire),
+		Err(true)
+	);
+	assert_eq!(
+		bits.compare_exchange_bit(2, true, false, AcqRel, Acquire),
+		Ok(true)
+	);
+	assert_eq!(
+		bits.compare_exchange_bit(9, false, false, AcqRel, Acquire),
+		Err(true)
+	);
+	assert_eq!(
+		bits.compare_exchange_bit(9, true, true, AcqRel, Acquire),
+		Ok(true)
+	);
+	assert_eq!(data[0].get(), 0xE4);
+	assert_eq!(data[1].get(), 0x0F);
+}
+
+#[test]
+fn claim() {
+	let data = [Cell::new(0u16), Cell::new(0), Cell::new(0)];
+	let bits = &data.view_bits::<Msb0>()[7 .. 41];
+	bits.set_aliased(0, true);
+	bits.set_aliased(20, true);
+
+	let claimed = core::iter::from_fn(|| bits.claim_first_zero(Acquire))
+		.collect::<Vec<_>>();
+	assert_eq!(claimed.len(), 32);
+	assert!(claimed.windows(2).all(|pair| pair[0] < pair[1]));
+	assert!(!claimed.contains(&0) && !claimed.contains(&20));
+	assert!(bits.all());
+	assert_eq!(data[0].get(), 0x01FF);
+	assert_eq!(data[2].get(), 0xFF80);
+
+	assert!(bits.fetch_clear(17, Release));
+	assert_eq!(bits.claim_first_zero(Acquire), Some(17));
+	assert_eq!(bits![Cell<u8>, Lsb0;].claim_first_zero(Acquire), None);
+}
+
+#[test]
+#[should_panic = "index 4 out of range: Excluded(4)"]
+fn fetch_out_of_bounds() {
+	bits![Cell<u8>, Lsb0; 0; 4].fetch_set(4, Relaxed);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn claim_threaded() {
+	radium::if_atomic! {
+		if atomic(64) {
+			use std::{sync::Arc, thread};
+			use core::sync::atomic::AtomicU64;
+
+			let bitmap = Arc::new(bitarr![AtomicU64, Lsb0; 0; 1000]);
+			let threads = (0 .. 8)
+				.map(|_| {
+					let bitmap = bitmap.clone();
+					thread::spawn(move || {
+						let bits = &bitmap[.. 999];
+						core::iter::from_fn(|| bits.claim_first_zero(Acquire))
+							.collect::<Vec<_>>()
+					})
+				})
+				.collect::<Vec<_>>();
+
+			let mut claimed = threads
+				.into_iter()
+				.flat_map(|handle| handle.join().unwrap())
+				.collect::<Vec<_>>();
+			claimed.sort_unstable();
+			assert!(claimed.iter().copied().eq(0 .. 999));
+			assert!(bitmap[.. 999].all());
+			assert!(!bitmap[999]);
+		}
+	}
+}
@@ -0,0 +1,105 @@
+#![cfg(test)]
+
+use rand::random;
+
+use crate::{
+	order::HiLo,
+	prelude::*,
+};
+
+fn check<T1, O1, T2, O2>(a: &BitSlice<T1, O1>, b: &BitSlice<T2, O2>)
+where
+	T1: BitStore,
+	O1: BitOrder,
+	T2: BitStore,
+	O2: BitOrder,
+{
+	let len = a.len().max(b.len());
+	let (mut and, mut or, mut xor) = (0, 0, 0);
+	for idx in 0 .. len {
+		let x = a.get(idx).map_or(false, |bit| *bit);
+		let y = b.get(idx).map_or(false, |bit| *bit);
+		and += (x & y) as usize;
+		or += (x | y) as usize;
+		xor += (x ^ y) as usize;
+	}
+
+	assert_eq!(a.and_count(b), and);
+	assert_eq!(b.and_count(a), and);
+	assert_eq!(a.hamming_distance(b), xor);
+	assert_eq!(b.hamming_distance(a), xor);
+	assert_eq!(a.intersection_count(b), and);
+	assert_eq!(a.union_count(b), or);
+	let jaccard = if or == 0 { 1.0 } else { and as f64 / or as f64 };
+	assert_eq!(a.jaccard(b), jaccard);
+	assert_eq!(b.jaccard(a), jaccard);
+}
+
+#[test]
+fn metrics() {
+	check(bits![], bits![u8, Msb0;]);
+	check(bits![0, 1, 1], bits![u8, Msb0;]);
+
+	for _ in 0 .. 20 {
+		let a = (0 .. random::<usize>() % 500)
+			.map(|_| random::<bool>())
+			.collect::<BitVec<u64, Lsb0>>();
+		let b = (0 .. random::<usize>() % 500)
+			.map(|_| random::<bool>())
+			.collect::<BitVec<u64, Lsb0>>();
+		let c = (0 .. random::<usize>() % 500)
+			.map(|_| random::<bool>())
+			.collect::<BitVec<u8, Msb0>>();
+		let d = (0 .. random::<usize>() % 500)
+			.map(|_| random::<bool>())
+			.collect::<BitVec<u16, HiLo>>();
+		let (h1, h2) = (random::<usize>() % 70, random::<usize>() % 70);
+		let a_tail = &a[h1.min(a.len()) ..];
+		let b_tail = &b[h1.min(b.len()) ..];
+
+		//  Same types, same alignment: domains walked in lockstep.
+		check(a.as_bitslice(), b.as_bitslice());
+		check(a_tail, b_tail);
+		//  Same types, different alignment.
+		check(a_tail, &b[h2.min(b.len()) ..]);
+		//  Different types.
+		check(a.as_bitslice(), c.as_bitslice());
+		check(c.as_bitslice(), d.as_bitslice());
+		check(d.as_bitslice(), a_tail);
+	}
+}
+
+#[test]
+fn dot() {
+	for _ in 0 .. 20 {
+		let len = random::<usize>() % 300;
+		let a = (0 .. len)
+			.map(|_| random::<bool>())
+			.collect::<BitVec<u64, Lsb0>>();
+		let b = (0 .. len)
+			.map(|_| random::<bool>())
+			.collect::<BitVec<u8, Msb0>>();
+		let expected = a
+			.iter()
+			.by_vals()
+			.zip(b.iter().by_vals())
+			.map(|(x, y)| {
+				if x == y {
+					1
+				}
+				else {
+					-1
+				}
+			})
+			.sum::<isize>();
+		assert_eq!(a.dot(&b), expected);
+		assert_eq!(b.dot(&a), expected);
+		assert_eq!(a.dot(&a), len as isize);
+	}
+}
+
+#[test]
+#[should_panic]
+fn dot_length_mismatch() {
+	bits![0, 1].dot(bits![0, 1, 1]);
+}
@@ -1,6 +1,7 @@
 use rand::random;
 
 use crate::{
+	order::HiLo,
 	prelude::*,
 	slice::BitSliceIndex,
 };
@@ -108,6 +109,55 @@ fn bit_not() {
 	assert_eq!(c, [0xFF_FF_00_00, 0xFF_00_00_FF, 0x00_00_FF_FF]);
 }
 
+#[test]
+fn shifts() {
+	fn check<T, O>(bits: &mut BitSlice<T, O>)
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		for mut bit in bits.iter_mut() {
+			*bit = random();
+		}
+		let orig = bits.to_bitvec();
+		let len = orig.len();
+		for by in (0 ..= len + 1).filter(|by| by % 7 < 3 || *by + 2 >= len) {
+			bits.clone_from_bitslice(&orig);
+			*bits <<= by;
+			for idx in 0 .. len {
+				let expected = idx + by < len && orig[idx + by];
+				assert_eq!(bits[idx], expected, "<< {} at {}", by, idx);
+			}
+
+			bits.clone_from_bitslice(&orig);
+			*bits >>= by;
+			for idx in 0 .. len {
+				let expected = idx >= by && orig[idx - by];
+				assert_eq!(bits[idx], expected, ">> {} at {}", by, idx);
+			}
+		}
+	}
+
+	let mut a = [0u8; 40];
+	let mut b = [0u16; 20];
+	let mut c = [0u32; 10];
+	let mut d = [0usize; 5];
+	for head in [0, 3, 13].iter().copied() {
+		check(&mut a.view_bits_mut::<Lsb0>()[head .. 300]);
+		check(&mut b.view_bits_mut::<Msb0>()[head .. 270]);
+		check(&mut c.view_bits_mut::<HiLo>()[head .. 200]);
+		check(&mut d.view_bits_mut::<Msb0>()[head ..]);
+	}
+
+	let mut data = 0b1011_0110u8;
+	let bits = data.view_bits_mut::<Msb0>();
+	assert_eq!(bits << 1, bits![0, 1, 1, 0, 1, 1, 0, 0]);
+	assert_eq!(data, 0b0110_1100);
+	let bits = data.view_bits_mut::<Lsb0>();
+	let _ = &mut bits[2 ..] >> 3;
+	assert_eq!(data, 0b0110_0000);
+}
+
 #[test]
 fn indexing() {
 	let bits = bits![mut 0, 1, 0, 0, 1];
@@ -0,0 +1,159 @@
+#![cfg(test)]
+
+use rand::random;
+
+use crate::{
+	order::HiLo,
+	prelude::*,
+};
+
+/// Finds an aligned run by testing every candidate start.
+fn naive<T, O>(
+	bits: &BitSlice<T, O>,
+	value: bool,
+	len: usize,
+	align: usize,
+) -> Option<usize>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	(0 ..= bits.len().saturating_sub(len))
+		.step_by(align)
+		.filter(|&start| start + len <= bits.len())
+		.find(|&start| bits[start ..][.. len].iter().all(|bit| *bit == value))
+}
+
+#[test]
+fn find_runs() {
+	fn check<T, O>()
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		for round in 0 .. 20 {
+			// Long stretches of equal bits, so that whole elements match, and
+			// short ones, so that most elements hold several runs.
+			let spread = if round % 2 == 0 { 70 } else { 5 };
+			let mut bv = BitVec::<T, O>::new();
+			while bv.len() < 300 {
+				let bit = random::<bool>();
+				let span = random::<usize>() % spread;
+				bv.extend(core::iter::repeat(bit).take(span));
+			}
+			let head = random::<usize>() % 20;
+			let bits = &bv[head .. 280];
+			for &len in &[0, 1, 2, 7, 33, 64, 65, 200, 300] {
+				for &align in &[1, 3, 8, 16] {
+					assert_eq!(
+						bits.find_zero_run_aligned(len, align),
+						naive(bits, false, len, align),
+					);
+					assert_eq!(
+						bits.find_one_run_aligned(len, align),
+						naive(bits, true, len, align),
+					);
+				}
+				assert_eq!(bits.find_zero_run(len), naive(bits, false, len, 1));
+				assert_eq!(bits.find_one_run(len), naive(bits, true, len, 1));
+			}
+		}
+	}
+
+	check::<u8, Lsb0>();
+	check::<u16, Msb0>();
+	check::<u32, HiLo>();
+	check::<usize, Lsb0>();
+	check::<u8, Msb0>();
+	check::<u64, Msb0>();
+
+	let bits = bits![u16, Msb0; 1, 0, 0, 0, 1];
+	assert_eq!(bits[1 .. 4].find_zero_run(3), Some(0));
+	assert_eq!(bits[1 .. 4].find_one_run(1), None);
+	assert_eq!(bits![].find_zero_run(0), Some(0));
+	assert_eq!(bits![].find_zero_run(1), None);
+}
+
+#[test]
+#[should_panic = "run alignment must be nonzero"]
+fn zero_alignment() {
+	bits![0; 4].find_zero_run_aligned(1, 0);
+}
+
+#[test]
+fn iter_runs() {
+	fn check<T, O>()
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		let mut bv = BitVec::<T, O>::new();
+		while bv.len() < 300 {
+			let bit = random::<bool>();
+			let span = random::<usize>() % 70 + 1;
+			bv.extend(core::iter::repeat(bit).take(span));
+		}
+		let head = random::<usize>() % 20;
+		let bits = &bv[head .. 290];
+
+		//  Build the expected runs one bit at a time.
+		let mut model = Vec::<(usize, usize, bool)>::new();
+		for (idx, bit) in bits.iter().by_vals().enumerate() {
+			match model.last_mut() {
+				Some(run) if run.2 == bit => run.1 += 1,
+				_ => model.push((idx, 1, bit)),
+			}
+		}
+
+		assert!(bits.iter_runs().eq(model.iter().copied()));
+		assert!(bits.iter_runs().rev().eq(model.iter().rev().copied()));
+		let ones = model.iter().filter(|run| run.2).map(|&(s, l, _)| (s, l));
+		let zeros = model.iter().filter(|run| !run.2).map(|&(s, l, _)| (s, l));
+		assert!(bits.iter_one_runs().eq(ones.clone()));
+		assert!(bits.iter_one_runs().rev().eq(ones.rev()));
+		assert!(bits.iter_zero_runs().eq(zeros.clone()));
+		assert!(bits.iter_zero_runs().rev().eq(zeros.rev()));
+
+		//  Alternate between the two ends until they meet.
+		let mut runs = bits.iter_runs();
+		let (mut front, mut back) = (0, model.len());
+		while front < back {
+			if random::<bool>() {
+				assert_eq!(runs.next(), Some(model[front]));
+				front += 1;
+			}
+			else {
+				back -= 1;
+				assert_eq!(runs.next_back(), Some(model[back]));
+			}
+		}
+		assert!(runs.next().is_none());
+		assert!(runs.next_back().is_none());
+
+		let mut ones = bits.iter_one_runs();
+		let first = ones.next();
+		let last = ones.next_back();
+		let rest = ones.collect::<Vec<_>>();
+		let expected = model
+			.iter()
+			.filter(|run| run.2)
+			.map(|&(s, l, _)| (s, l))
+			.collect::<Vec<_>>();
+		assert_eq!(first, expected.first().copied());
+		if expected.len() > 1 {
+			assert_eq!(last, expected.last().copied());
+			assert_eq!(rest, expected[1 .. expected.len() - 1]);
+		}
+	}
+
+	for _ in 0 .. 10 {
+		check::<u8, Lsb0>();
+		check::<u16, Msb0>();
+		check::<u32, HiLo>();
+		check::<usize, Msb0>();
+	}
+
+	assert!(bits![].iter_runs().next().is_none());
+	assert!(bits![0; 5].iter_one_runs().next_back().is_none());
+	assert_eq!(bits![1; 5].iter_zero_runs().size_hint(), (0, Some(3)));
+}
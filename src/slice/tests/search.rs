@@ -0,0 +1,125 @@
+#![cfg(test)]
+
+use rand::random;
+
+use crate::{
+	order::HiLo,
+	prelude::*,
+};
+
+/// Finds every (possibly overlapping) occurrence by brute force.
+fn naive<T1, O1, T2, O2>(
+	haystack: &BitSlice<T1, O1>,
+	needle: &BitSlice<T2, O2>,
+) -> Vec<usize>
+where
+	T1: BitStore,
+	O1: BitOrder,
+	T2: BitStore,
+	O2: BitOrder,
+{
+	if needle.is_empty() {
+		return (0 ..= haystack.len()).collect();
+	}
+	haystack
+		.windows(needle.len())
+		.enumerate()
+		.filter(|(_, window)| *window == needle)
+		.map(|(idx, _)| idx)
+		.collect()
+}
+
+#[test]
+fn find_short() {
+	let bits = bits![0, 1, 1, 0, 1, 0, 0, 1, 1, 0];
+
+	assert_eq!(bits.find(bits![1, 0, 0]), Some(4));
+	assert_eq!(bits.rfind(bits![0, 1, 1]), Some(6));
+	assert_eq!(bits.find(bits![0, 1, 1]), Some(0));
+	assert!(bits.find(bits![1, 1, 1]).is_none());
+	assert!(bits.rfind(bits![1, 1, 1]).is_none());
+
+	assert_eq!(bits.find(bits), Some(0));
+	assert_eq!(bits.rfind(bits), Some(0));
+	assert!(bits[1 ..].find(bits).is_none());
+	assert!(bits![].find(bits![0]).is_none());
+	assert_eq!(bits![].find(bits![]), Some(0));
+	assert_eq!(bits.rfind(bits![]), Some(10));
+
+	assert!(bits.contains(bits![]));
+	assert!(bits.contains(bits![u16, HiLo; 0, 0, 1, 1]));
+	assert!(!bits.contains(bits![u16, HiLo; 0, 0, 0]));
+}
+
+#[test]
+fn find_long() {
+	let data = random::<[u32; 24]>();
+	let haystack = &data.view_bits::<Msb0>()[5 ..];
+
+	//  Needles longer than a word, at offsets not aligned to any element.
+	for &(start, len) in &[(0, 70), (77, 64), (300, 130), (600, 143)] {
+		let needle = haystack[start .. start + len].to_bitvec();
+		let found = haystack.find(&needle).unwrap();
+		assert!(found <= start);
+		assert_eq!(&haystack[found ..][.. len], needle);
+		let found = haystack.rfind(&needle).unwrap();
+		assert!(found >= start);
+		assert_eq!(&haystack[found ..][.. len], needle);
+
+		let mut other = BitVec::<u8, Lsb0>::new();
+		other.extend_from_bitslice(&needle);
+		assert_eq!(haystack.find(&other), haystack.find(&needle));
+	}
+
+	let mut needle = haystack[200 .. 300].to_bitvec();
+	let last = needle.len() - 1;
+	let bit = !needle[last];
+	needle.set(last, bit);
+	assert_eq!(
+		haystack.find(&needle),
+		naive(haystack, &needle).first().copied()
+	);
+}
+
+#[test]
+fn match_indices() {
+	let bits = bits![1, 1, 1, 1, 1, 0, 1, 1];
+	let needle = bits![1, 1];
+
+	assert_eq!(bits.match_indices(needle).collect::<Vec<_>>(), [0, 2, 6]);
+	assert_eq!(bits.rmatch_indices(needle).collect::<Vec<_>>(), [6, 3, 1]);
+	assert_eq!(bits![0, 0].match_indices(bits![]).collect::<Vec<_>>(), [
+		0, 1, 2
+	]);
+	assert_eq!(bits![0, 0].rmatch_indices(bits![]).collect::<Vec<_>>(), [
+		2, 1, 0
+	]);
+	assert!(bits.match_indices(bits![0, 0]).next().is_none());
+	assert!(bits.rmatch_indices(bits![0, 0]).next().is_none());
+}
+
+#[test]
+fn against_naive() {
+	for _ in 0 .. 16 {
+		let data = random::<[u16; 20]>();
+		let haystack = &data.view_bits::<Lsb0>()[3 .. 301];
+		//  Short, random needles occur often enough to exercise every path.
+		let key = random::<u16>();
+		let needle = &key.view_bits::<Msb0>()[.. 7];
+
+		let all = naive(haystack, needle);
+		assert_eq!(haystack.find(needle), all.first().copied());
+		assert_eq!(haystack.rfind(needle), all.last().copied());
+
+		let mut expected = vec![];
+		for &idx in &all {
+			if expected
+				.last()
+				.map_or(true, |&prev| idx >= prev + needle.len())
+			{
+				expected.push(idx);
+			}
+		}
+		assert_eq!(haystack.match_indices(needle).collect::<Vec<_>>(), expected);
+	}
+}
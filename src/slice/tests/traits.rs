@@ -346,4 +346,49 @@ mod format {
 ]"
 		);
 	}
+
+	#[test]
+	fn pretty() {
+		let data = [0x4142_4344u32, 0x0045_7F20];
+		let bits = data.view_bits::<Msb0>();
+
+		assert_eq!(format!("{}", bits[.. 0].display().with_offsets()), "");
+		assert_eq!(
+			format!("{}", bits[.. 40].display()),
+			"01000001010000100100001101000100 00000000",
+		);
+		assert_eq!(
+			format!("{}", bits[4 .. 40].display().row(16)),
+			"0001010000100100\n001101000100 0000\n0000",
+		);
+		assert_eq!(
+			format!("{}", bits[20 .. 40].display().row(16).with_offsets()),
+			"0000: 001101000100 0000\n0010: 0000",
+		);
+		assert_eq!(
+			format!(
+				"{}",
+				bits.display().group(8).row(24).with_offsets().with_ascii()
+			),
+			"0000: 01000001 01000010 01000011  |ABC|\n\
+			 0018: 01000100 00000000 01000101  |D.E|\n\
+			 0030: 01111111 00100000           |. |",
+		);
+		assert_eq!(
+			format!("{}", bits[.. 12].display().group(3).with_ascii()),
+			"010 000 010 100  |A.|",
+		);
+		assert_eq!(
+			format!(
+				"{}",
+				[0x41u8, 0x42].view_bits::<Lsb0>().display().with_ascii()
+			),
+			"10000010 01000010  |AB|",
+		);
+
+		let wide = BitVec::<u8, Lsb0>::repeat(false, 0x12345);
+		let text = format!("{}", wide.display().row(0x1000).with_offsets());
+		assert_eq!(text.lines().count(), 0x13);
+		assert!(text.lines().last().unwrap().starts_with("12000: 0000"));
+	}
 }
@@ -0,0 +1,96 @@
+#![doc = include_str!("../doc/stream.md")]
+
+use core::fmt::{
+	self,
+	Display,
+	Formatter,
+};
+
+pub use self::{
+	reader::BitReader,
+	writer::BitWriter,
+};
+
+mod codes;
+mod reader;
+mod tests;
+mod writer;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[doc = include_str!("../doc/stream/StreamError.md")]
+pub enum StreamError {
+	/// The stream has fewer bits remaining than were requested.
+	Exhausted {
+		/// The number of bits requested.
+		requested: usize,
+		/// The number of bits remaining in the stream.
+		remaining: usize,
+	},
+	/// The request is wider than the integer type that would hold it.
+	TooWide {
+		/// The number of bits requested.
+		requested: usize,
+		/// The width of the integer type.
+		max:       usize,
+	},
+	/// The value cannot be represented by the requested code.
+	Unencodable,
+	/// The stream holds a code whose value does not fit in the requested
+	/// integer.
+	Overflow,
+}
+
+impl StreamError {
+	/// Checks that a stream has at least `requested` bits remaining.
+	pub(crate) fn check_remaining(
+		requested: usize,
+		remaining: usize,
+	) -> Result<(), Self> {
+		if requested > remaining {
+			return Err(Self::Exhausted {
+				requested,
+				remaining,
+			});
+		}
+		Ok(())
+	}
+
+	/// Checks that a request fits in an integer of width `max`.
+	pub(crate) fn check_width(requested: usize, max: usize) -> Result<(), Self> {
+		if requested > max {
+			return Err(Self::TooWide { requested, max });
+		}
+		Ok(())
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Display for StreamError {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::Exhausted {
+				requested,
+				remaining,
+			} => write!(
+				fmt,
+				"cannot transfer {} bits through a stream with {} bits \
+				 remaining",
+				requested, remaining,
+			),
+			Self::TooWide { requested, max } => write!(
+				fmt,
+				"cannot transfer {} bits through a {}-bit integer",
+				requested, max,
+			),
+			Self::Unencodable => {
+				fmt.write_str("cannot encode the value in the requested code")
+			},
+			Self::Overflow => fmt
+				.write_str("the encoded value overflows the requested integer"),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StreamError {}
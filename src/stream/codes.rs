@@ -0,0 +1,874 @@
+//! Variable-length integer codes.
+
+use super::{
+	BitReader,
+	BitWriter,
+	StreamError,
+};
+use crate::{
+	field::BitField,
+	mem::bits_of,
+	order::BitOrder,
+	slice::{
+		BitSlice,
+		WORD_BITS,
+	},
+	store::BitStore,
+};
+
+/// Prefix codes.
+impl<'a, T, O> BitReader<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Reads a unary code: a run of `1` bits, terminated by a `0` bit.
+	///
+	/// ## Returns
+	///
+	/// The number of `1` bits before the terminator.
+	///
+	/// ## Errors
+	///
+	/// This fails if the stream ends before the terminator.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitReader;
+	///
+	/// let bits = bits![0, 1, 1, 1, 0, 1];
+	/// let mut reader = BitReader::new(bits);
+	/// assert_eq!(reader.read_unary(), Ok(0));
+	/// assert_eq!(reader.read_unary(), Ok(3));
+	/// assert!(reader.read_unary().is_err());
+	/// assert_eq!(reader.position(), 5);
+	/// ```
+	#[inline]
+	pub fn read_unary(&mut self) -> Result<u64, StreamError> {
+		let ones = self
+			.as_bitslice()
+			.first_zero()
+			.ok_or_else(|| self.exhausted())?;
+		self.skip(ones + 1).map(|()| ones as u64)
+	}
+
+	/// Reads an Elias gamma code.
+	///
+	/// A value `n` of `k` significant bits is encoded as `k - 1` `0` bits,
+	/// followed by the `k` significant bits of `n`, most significant first.
+	///
+	/// ## Returns
+	///
+	/// The encoded value, which is never zero.
+	///
+	/// ## Errors
+	///
+	/// This fails if the stream ends within the code, or if the code encodes a
+	/// value wider than 64 bits.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitReader;
+	///
+	/// let bits = bits![1, 0, 1, 0, 0, 0, 1, 0, 0];
+	/// let mut reader = BitReader::new(bits);
+	/// assert_eq!(reader.read_gamma(), Ok(1));
+	/// assert_eq!(reader.read_gamma(), Ok(2));
+	/// assert_eq!(reader.read_gamma(), Ok(4));
+	/// ```
+	#[inline]
+	pub fn read_gamma(&mut self) -> Result<u64, StreamError> {
+		self.transact(|this| {
+			let zeros = this
+				.as_bitslice()
+				.first_one()
+				.ok_or_else(|| this.exhausted())?;
+			if zeros >= bits_of::<u64>() {
+				return Err(StreamError::Overflow);
+			}
+			this.skip(zeros)?;
+			this.read_msb_first(zeros + 1)
+		})
+	}
+
+	/// Reads an Elias delta code.
+	///
+	/// A value `n` of `k` significant bits is encoded as the Elias gamma code
+	/// of `k`, followed by the `k - 1` significant bits of `n` below its
+	/// leading `1` bit, most significant first.
+	///
+	/// ## Returns
+	///
+	/// The encoded value, which is never zero.
+	///
+	/// ## Errors
+	///
+	/// This fails if the stream ends within the code, or if the code encodes a
+	/// value wider than 64 bits.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitReader;
+	///
+	/// let bits = bits![1, 0, 1, 0, 1, 0, 0, 1, 0, 1, 0, 0, 0, 1];
+	/// let mut reader = BitReader::new(bits);
+	/// assert_eq!(reader.read_delta(), Ok(1));
+	/// assert_eq!(reader.read_delta(), Ok(3));
+	/// assert_eq!(reader.read_delta(), Ok(17));
+	/// ```
+	#[inline]
+	pub fn read_delta(&mut self) -> Result<u64, StreamError> {
+		self.transact(|this| {
+			let width = this.read_gamma()?;
+			if width > bits_of::<u64>() as u64 {
+				return Err(StreamError::Overflow);
+			}
+			let width = width as usize - 1;
+			this.read_msb_first(width).map(|low| 1 << width | low)
+		})
+	}
+
+	/// Reads an unsigned Exponential-Golomb code, as used in H.264 (`ue(v)`).
+	///
+	/// A value `n` is encoded as the Elias gamma code of `n + 1`.
+	///
+	/// ## Errors
+	///
+	/// This fails if the stream ends within the code, or if the code encodes a
+	/// value wider than 64 bits.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitReader;
+	///
+	/// let bits = bits![1, 0, 1, 0, 0, 0, 1, 0, 0];
+	/// let mut reader = BitReader::new(bits);
+	/// assert_eq!(reader.read_exp_golomb(), Ok(0));
+	/// assert_eq!(reader.read_exp_golomb(), Ok(1));
+	/// assert_eq!(reader.read_exp_golomb(), Ok(3));
+	/// ```
+	#[inline]
+	pub fn read_exp_golomb(&mut self) -> Result<u64, StreamError> {
+		self.read_gamma().map(|value| value - 1)
+	}
+
+	/// Reads a signed Exponential-Golomb code, as used in H.264 (`se(v)`).
+	///
+	/// A positive value `v` is encoded as the unsigned code of `2v - 1`, and a
+	/// non-positive value as the unsigned code of `-2v`.
+	///
+	/// ## Errors
+	///
+	/// This fails if the stream ends within the code, or if the unsigned code
+	/// encodes a value wider than 64 bits. Every unsigned value that it can
+	/// produce maps into an `i64`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitReader;
+	///
+	/// let bits = bits![1, 0, 1, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 1];
+	/// let mut reader = BitReader::new(bits);
+	/// assert_eq!(reader.read_signed_exp_golomb(), Ok(0));
+	/// assert_eq!(reader.read_signed_exp_golomb(), Ok(1));
+	/// assert_eq!(reader.read_signed_exp_golomb(), Ok(-1));
+	/// assert_eq!(reader.read_signed_exp_golomb(), Ok(2));
+	/// assert_eq!(reader.read_signed_exp_golomb(), Ok(-2));
+	/// ```
+	#[inline]
+	pub fn read_signed_exp_golomb(&mut self) -> Result<i64, StreamError> {
+		self.read_exp_golomb().map(|value| {
+			let half = (value >> 1) as i64;
+			if value & 1 == 1 {
+				half + 1
+			}
+			else {
+				-half
+			}
+		})
+	}
+
+	/// Reads a Golomb-Rice code with parameter `k`.
+	///
+	/// A value `n` is encoded as the unary code of `n >> k`, followed by the
+	/// low `k` bits of `n`, most significant first. This is the Golomb code
+	/// with divisor `2^k`.
+	///
+	/// ## Errors
+	///
+	/// This fails if `k` is wider than 64, if the stream ends within the code,
+	/// or if the code encodes a value wider than 64 bits.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitReader;
+	///
+	/// let bits = bits![1, 1, 0, 0, 1, 0, 1, 1];
+	/// let mut reader = BitReader::new(bits);
+	/// assert_eq!(reader.read_rice(2), Ok(9));
+	/// assert_eq!(reader.read_rice(2), Ok(3));
+	/// ```
+	#[inline]
+	pub fn read_rice(&mut self, k: usize) -> Result<u64, StreamError> {
+		StreamError::check_width(k, bits_of::<u64>())?;
+		self.transact(|this| {
+			let quot = this.read_unary()?;
+			let rem = this.read_msb_first(k)?;
+			if k < bits_of::<u64>() && quot <= !0 >> k {
+				Ok(quot << k | rem)
+			}
+			else if quot == 0 {
+				Ok(rem)
+			}
+			else {
+				Err(StreamError::Overflow)
+			}
+		})
+	}
+
+	/// Reads a Golomb code with divisor `m`.
+	///
+	/// A value `n` is encoded as the unary code of `n / m`, followed by the
+	/// truncated binary code of `n % m`.
+	///
+	/// ## Errors
+	///
+	/// This fails if `m` is zero, if the stream ends within the code, or if
+	/// the code encodes a value wider than 64 bits.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitReader;
+	///
+	/// let bits = bits![1, 0, 0, 0, 1, 1, 0, 0, 1, 0, 1, 1, 1];
+	/// let mut reader = BitReader::new(bits);
+	/// assert_eq!(reader.read_golomb(5), Ok(5));
+	/// assert_eq!(reader.read_golomb(5), Ok(11));
+	/// assert_eq!(reader.read_golomb(5), Ok(4));
+	/// ```
+	#[inline]
+	pub fn read_golomb(&mut self, m: u64) -> Result<u64, StreamError> {
+		if m == 0 {
+			return Err(StreamError::Unencodable);
+		}
+		let (width, cutoff) = truncated_binary(m);
+		self.transact(|this| {
+			let quot = this.read_unary()?;
+			let rem = match width {
+				0 => 0,
+				_ => {
+					let short = this.read_msb_first(width - 1)?;
+					if short < cutoff {
+						short
+					}
+					else {
+						let long =
+							(short as u128) << 1 | this.read_bool()? as u128;
+						(long - cutoff as u128) as u64
+					}
+				},
+			};
+			quot.checked_mul(m)
+				.and_then(|value| value.checked_add(rem))
+				.ok_or(StreamError::Overflow)
+		})
+	}
+
+	/// Runs a read, and only moves the cursor if it succeeds.
+	fn transact<R>(
+		&mut self,
+		func: impl FnOnce(&mut Self) -> Result<R, StreamError>,
+	) -> Result<R, StreamError> {
+		let mut this = *self;
+		let out = func(&mut this)?;
+		*self = this;
+		Ok(out)
+	}
+
+	/// Reads `width` bits, with the first bit read in the most significant
+	/// position, regardless of the ordering of the bit-slice.
+	fn read_msb_first(&mut self, width: usize) -> Result<u64, StreamError> {
+		debug_assert!(width <= bits_of::<u64>(), "cannot read {} bits", width);
+		self.read_bitslice(width).map(load_msb_first)
+	}
+
+	/// Reports that the stream ended before a code was complete.
+	fn exhausted(&self) -> StreamError {
+		let remaining = self.remaining();
+		StreamError::Exhausted {
+			requested: remaining + 1,
+			remaining,
+		}
+	}
+}
+
+/// Byte-oriented codes.
+impl<'a, T, O> BitReader<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	/// Reads an unsigned LEB128 code.
+	///
+	/// Each byte is read as an eight-bit field with [`.read_bits()`], so a
+	/// byte-aligned stream over `u8` storage reads the same bytes as a LEB128
+	/// decoder working on `[u8]`.
+	///
+	/// ## Errors
+	///
+	/// This fails if the stream ends within the code, or if the code encodes a
+	/// value wider than 64 bits.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitReader;
+	///
+	/// let data = [0xE5u8, 0x8E, 0x26];
+	/// let mut reader = BitReader::new(data.view_bits::<Lsb0>());
+	/// assert_eq!(reader.read_leb128(), Ok(624_485));
+	/// ```
+	///
+	/// [`.read_bits()`]: Self::read_bits
+	#[inline]
+	pub fn read_leb128(&mut self) -> Result<u64, StreamError> {
+		self.transact(|this| {
+			let (mut value, mut shift) = (0u64, 0usize);
+			loop {
+				let byte = this.read_bits::<u8>(8)?;
+				let group = (byte & 0x7F) as u64;
+				if shift < bits_of::<u64>() {
+					if group << shift >> shift != group {
+						return Err(StreamError::Overflow);
+					}
+					value |= group << shift;
+				}
+				else if group != 0 {
+					return Err(StreamError::Overflow);
+				}
+				if byte & 0x80 == 0 {
+					return Ok(value);
+				}
+				shift = shift.saturating_add(7);
+			}
+		})
+	}
+
+	/// Reads a signed LEB128 code.
+	///
+	/// Each byte is read as an eight-bit field with [`.read_bits()`], so a
+	/// byte-aligned stream over `u8` storage reads the same bytes as a LEB128
+	/// decoder working on `[u8]`.
+	///
+	/// ## Errors
+	///
+	/// This fails if the stream ends within the code, or if the code encodes a
+	/// value that does not fit in an `i64`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitReader;
+	///
+	/// let data = [0xC0u8, 0xBB, 0x78];
+	/// let mut reader = BitReader::new(data.view_bits::<Msb0>());
+	/// assert_eq!(reader.read_signed_leb128(), Ok(-123_456));
+	/// ```
+	///
+	/// [`.read_bits()`]: Self::read_bits
+	#[inline]
+	pub fn read_signed_leb128(&mut self) -> Result<i64, StreamError> {
+		self.transact(|this| {
+			let (mut value, mut shift) = (0i64, 0usize);
+			loop {
+				let byte = this.read_bits::<u8>(8)?;
+				let group = (byte & 0x7F) as i64;
+				if shift < bits_of::<i64>() - 1 {
+					value |= group << shift;
+				}
+				else {
+					//  Bits above the top of `i64` must all repeat its sign.
+					let top = if shift == bits_of::<i64>() - 1 {
+						group & 1
+					}
+					else {
+						(value < 0) as i64
+					};
+					if group != top * 0x7F {
+						return Err(StreamError::Overflow);
+					}
+					value |= top << (bits_of::<i64>() - 1);
+				}
+				shift = shift.saturating_add(7);
+				if byte & 0x80 == 0 {
+					if byte & 0x40 != 0 && shift < bits_of::<i64>() {
+						value |= -1 << shift;
+					}
+					return Ok(value);
+				}
+			}
+		})
+	}
+}
+
+/// Prefix codes.
+impl<'a, T, O> BitWriter<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Writes a unary code: `value` `1` bits, terminated by a `0` bit.
+	///
+	/// ## Errors
+	///
+	/// This fails if the writer is [fixed] and does not have room for the
+	/// code, or if the code is longer than the address space.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitWriter;
+	///
+	/// let mut bv = bitvec![];
+	/// let mut writer = BitWriter::new(&mut bv);
+	/// writer.write_unary(0).unwrap();
+	/// writer.write_unary(3).unwrap();
+	/// assert_eq!(bv, bits![0, 1, 1, 1, 0]);
+	/// ```
+	///
+	/// [fixed]: Self::fixed
+	#[inline]
+	pub fn write_unary(&mut self, value: u64) -> Result<(), StreamError> {
+		let ones = unary_len(value)?;
+		let bits = self.reserve(ones + 1)?;
+		bits[.. ones].fill(true);
+		bits.set(ones, false);
+		Ok(())
+	}
+
+	/// Writes an Elias gamma code.
+	///
+	/// See [`BitReader::read_gamma`] for the code layout.
+	///
+	/// ## Errors
+	///
+	/// This fails if `value` is zero, or if the writer is [fixed] and does not
+	/// have room for the code.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitWriter;
+	///
+	/// let mut bv = bitvec![];
+	/// let mut writer = BitWriter::new(&mut bv);
+	/// writer.write_gamma(1).unwrap();
+	/// writer.write_gamma(4).unwrap();
+	/// assert!(writer.write_gamma(0).is_err());
+	/// assert_eq!(bv, bits![1, 0, 0, 1, 0, 0]);
+	/// ```
+	///
+	/// [`BitReader::read_gamma`]: crate::stream::BitReader::read_gamma
+	/// [fixed]: Self::fixed
+	#[inline]
+	pub fn write_gamma(&mut self, value: u64) -> Result<(), StreamError> {
+		if value == 0 {
+			return Err(StreamError::Unencodable);
+		}
+		let width = bit_len(value);
+		let bits = self.reserve(2 * width - 1)?;
+		bits[.. width - 1].fill(false);
+		store_msb_first(&mut bits[width - 1 ..], value);
+		Ok(())
+	}
+
+	/// Writes an Elias delta code.
+	///
+	/// See [`BitReader::read_delta`] for the code layout.
+	///
+	/// ## Errors
+	///
+	/// This fails if `value` is zero, or if the writer is [fixed] and does not
+	/// have room for the code.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitWriter;
+	///
+	/// let mut bv = bitvec![];
+	/// let mut writer = BitWriter::new(&mut bv);
+	/// writer.write_delta(17).unwrap();
+	/// assert_eq!(bv, bits![0, 0, 1, 0, 1, 0, 0, 0, 1]);
+	/// ```
+	///
+	/// [`BitReader::read_delta`]: crate::stream::BitReader::read_delta
+	/// [fixed]: Self::fixed
+	#[inline]
+	pub fn write_delta(&mut self, value: u64) -> Result<(), StreamError> {
+		if value == 0 {
+			return Err(StreamError::Unencodable);
+		}
+		let width = bit_len(value);
+		let head = bit_len(width as u64);
+		let bits = self.reserve(2 * head - 1 + width - 1)?;
+		bits[.. head - 1].fill(false);
+		store_msb_first(&mut bits[head - 1 .. 2 * head - 1], width as u64);
+		store_msb_first(&mut bits[2 * head - 1 ..], value);
+		Ok(())
+	}
+
+	/// Writes an unsigned Exponential-Golomb code, as used in H.264
+	/// (`ue(v)`).
+	///
+	/// See [`BitReader::read_exp_golomb`] for the code layout.
+	///
+	/// ## Errors
+	///
+	/// This fails if `value` is `u64::MAX`, or if the writer is [fixed] and
+	/// does not have room for the code.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitWriter;
+	///
+	/// let mut bv = bitvec![];
+	/// let mut writer = BitWriter::new(&mut bv);
+	/// writer.write_exp_golomb(0).unwrap();
+	/// writer.write_exp_golomb(3).unwrap();
+	/// assert_eq!(bv, bits![1, 0, 0, 1, 0, 0]);
+	/// ```
+	///
+	/// [`BitReader::read_exp_golomb`]: crate::stream::BitReader::read_exp_golomb
+	/// [fixed]: Self::fixed
+	#[inline]
+	pub fn write_exp_golomb(&mut self, value: u64) -> Result<(), StreamError> {
+		value
+			.checked_add(1)
+			.ok_or(StreamError::Unencodable)
+			.and_then(|value| self.write_gamma(value))
+	}
+
+	/// Writes a signed Exponential-Golomb code, as used in H.264 (`se(v)`).
+	///
+	/// See [`BitReader::read_signed_exp_golomb`] for the code layout.
+	///
+	/// ## Errors
+	///
+	/// This fails if `value` is `i64::MIN`, or if the writer is [fixed] and
+	/// does not have room for the code.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitWriter;
+	///
+	/// let mut bv = bitvec![];
+	/// let mut writer = BitWriter::new(&mut bv);
+	/// writer.write_signed_exp_golomb(1).unwrap();
+	/// writer.write_signed_exp_golomb(-1).unwrap();
+	/// assert_eq!(bv, bits![0, 1, 0, 0, 1, 1]);
+	/// ```
+	///
+	/// [`BitReader::read_signed_exp_golomb`]: crate::stream::BitReader::read_signed_exp_golomb
+	/// [fixed]: Self::fixed
+	#[inline]
+	pub fn write_signed_exp_golomb(
+		&mut self,
+		value: i64,
+	) -> Result<(), StreamError> {
+		let mapped = if value > 0 {
+			Some(value as u64 * 2 - 1)
+		}
+		else {
+			value.unsigned_abs().checked_mul(2)
+		};
+		mapped
+			.ok_or(StreamError::Unencodable)
+			.and_then(|value| self.write_exp_golomb(value))
+	}
+
+	/// Writes a Golomb-Rice code with parameter `k`.
+	///
+	/// See [`BitReader::read_rice`] for the code layout.
+	///
+	/// ## Errors
+	///
+	/// This fails if `k` is wider than 64, if the writer is [fixed] and does
+	/// not have room for the code, or if the code is longer than the address
+	/// space.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitWriter;
+	///
+	/// let mut bv = bitvec![];
+	/// let mut writer = BitWriter::new(&mut bv);
+	/// writer.write_rice(9, 2).unwrap();
+	/// assert_eq!(bv, bits![1, 1, 0, 0, 1]);
+	/// ```
+	///
+	/// [`BitReader::read_rice`]: crate::stream::BitReader::read_rice
+	/// [fixed]: Self::fixed
+	#[inline]
+	pub fn write_rice(
+		&mut self,
+		value: u64,
+		k: usize,
+	) -> Result<(), StreamError> {
+		StreamError::check_width(k, bits_of::<u64>())?;
+		let ones = unary_len(value.checked_shr(k as u32).unwrap_or(0))?;
+		let len = (ones + 1).checked_add(k).ok_or(StreamError::Unencodable)?;
+		let bits = self.reserve(len)?;
+		bits[.. ones].fill(true);
+		bits.set(ones, false);
+		store_msb_first(&mut bits[ones + 1 ..], value);
+		Ok(())
+	}
+
+	/// Writes a Golomb code with divisor `m`.
+	///
+	/// See [`BitReader::read_golomb`] for the code layout.
+	///
+	/// ## Errors
+	///
+	/// This fails if `m` is zero, if the writer is [fixed] and does not have
+	/// room for the code, or if the code is longer than the address space.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitWriter;
+	///
+	/// let mut bv = bitvec![];
+	/// let mut writer = BitWriter::new(&mut bv);
+	/// writer.write_golomb(5, 5).unwrap();
+	/// writer.write_golomb(4, 5).unwrap();
+	/// assert_eq!(bv, bits![1, 0, 0, 0, 0, 1, 1, 1]);
+	/// ```
+	///
+	/// [`BitReader::read_golomb`]: crate::stream::BitReader::read_golomb
+	/// [fixed]: Self::fixed
+	#[inline]
+	pub fn write_golomb(
+		&mut self,
+		value: u64,
+		m: u64,
+	) -> Result<(), StreamError> {
+		if m == 0 {
+			return Err(StreamError::Unencodable);
+		}
+		let ones = unary_len(value / m)?;
+		let rem = value % m;
+		let (width, cutoff) = truncated_binary(m);
+		let (width, code) = if rem < cutoff {
+			(width - 1, rem)
+		}
+		else {
+			(width, (rem as u128 + cutoff as u128) as u64)
+		};
+		let len = (ones + 1)
+			.checked_add(width)
+			.ok_or(StreamError::Unencodable)?;
+		let bits = self.reserve(len)?;
+		bits[.. ones].fill(true);
+		bits.set(ones, false);
+		store_msb_first(&mut bits[ones + 1 ..], code);
+		Ok(())
+	}
+}
+
+/// Byte-oriented codes.
+impl<'a, T, O> BitWriter<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	/// Writes an unsigned LEB128 code.
+	///
+	/// Each byte is written as an eight-bit field with [`.write_bits()`], so a
+	/// byte-aligned stream over `u8` storage holds the same bytes as a LEB128
+	/// encoder working on `[u8]`.
+	///
+	/// ## Errors
+	///
+	/// This fails if the writer is [fixed] and does not have room for the
+	/// code.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitWriter;
+	///
+	/// let mut bv = BitVec::<u8, Lsb0>::new();
+	/// let mut writer = BitWriter::new(&mut bv);
+	/// writer.write_leb128(624_485).unwrap();
+	/// assert_eq!(bv.as_raw_slice(), &[0xE5, 0x8E, 0x26]);
+	/// ```
+	///
+	/// [`.write_bits()`]: Self::write_bits
+	/// [fixed]: Self::fixed
+	#[inline]
+	pub fn write_leb128(&mut self, mut value: u64) -> Result<(), StreamError> {
+		let mut buf = [0u8; 10];
+		let mut len = 0;
+		loop {
+			let byte = (value & 0x7F) as u8;
+			value >>= 7;
+			if value == 0 {
+				buf[len] = byte;
+				len += 1;
+				break;
+			}
+			buf[len] = byte | 0x80;
+			len += 1;
+		}
+		self.write_bytes(&buf[.. len])
+	}
+
+	/// Writes a signed LEB128 code.
+	///
+	/// Each byte is written as an eight-bit field with [`.write_bits()`], so a
+	/// byte-aligned stream over `u8` storage holds the same bytes as a LEB128
+	/// encoder working on `[u8]`.
+	///
+	/// ## Errors
+	///
+	/// This fails if the writer is [fixed] and does not have room for the
+	/// code.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitWriter;
+	///
+	/// let mut bv = BitVec::<u8, Msb0>::new();
+	/// let mut writer = BitWriter::new(&mut bv);
+	/// writer.write_signed_leb128(-123_456).unwrap();
+	/// assert_eq!(bv.as_raw_slice(), &[0xC0, 0xBB, 0x78]);
+	/// ```
+	///
+	/// [`.write_bits()`]: Self::write_bits
+	/// [fixed]: Self::fixed
+	#[inline]
+	pub fn write_signed_leb128(
+		&mut self,
+		mut value: i64,
+	) -> Result<(), StreamError> {
+		let mut buf = [0u8; 10];
+		let mut len = 0;
+		loop {
+			let byte = (value & 0x7F) as u8;
+			value >>= 7;
+			let sign = byte & 0x40 != 0;
+			if (value == 0 && !sign) || (value == -1 && sign) {
+				buf[len] = byte;
+				len += 1;
+				break;
+			}
+			buf[len] = byte | 0x80;
+			len += 1;
+		}
+		self.write_bytes(&buf[.. len])
+	}
+
+	/// Writes a sequence of bytes as eight-bit fields, either all at once or
+	/// not at all.
+	fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), StreamError> {
+		let mut sub = BitWriter::fixed(self.reserve(bytes.len() * 8)?);
+		for &byte in bytes {
+			sub.write_bits(byte, 8)?;
+		}
+		Ok(())
+	}
+}
+
+/// Counts the significant bits in an integer.
+fn bit_len(value: u64) -> usize {
+	bits_of::<u64>() - value.leading_zeros() as usize
+}
+
+/// Computes the number of `1` bits in the unary code of `value`.
+fn unary_len(value: u64) -> Result<usize, StreamError> {
+	let ones = value as usize;
+	if ones as u64 != value || ones == usize::MAX {
+		return Err(StreamError::Unencodable);
+	}
+	Ok(ones)
+}
+
+/// Computes the parameters of the truncated binary code for remainders of
+/// division by `m`.
+///
+/// ## Returns
+///
+/// - the width of the long codewords, which is `ceil(log2(m))`
+/// - the number of remainders which use the short codewords, which are one bit
+///   narrower.
+fn truncated_binary(m: u64) -> (usize, u64) {
+	let width = bit_len(m - 1);
+	let cutoff = ((1u128 << width) - m as u128) as u64;
+	(width, cutoff)
+}
+
+/// Loads at most 64 bits, with the front of the bit-slice in the most
+/// significant position, regardless of the ordering of the bit-slice.
+fn load_msb_first<T, O>(bits: &BitSlice<T, O>) -> u64
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	bits.chunks(WORD_BITS).fold(0, |value, chunk| {
+		let width = chunk.len();
+		let word = chunk.load_word().reverse_bits() >> (WORD_BITS - width);
+		value.checked_shl(width as u32).unwrap_or(0) | word as u64
+	})
+}
+
+/// Stores the low bits of an integer into a bit-slice of at most 64 bits, with
+/// the most significant bit at the front of the bit-slice, regardless of the
+/// ordering of the bit-slice.
+fn store_msb_first<T, O>(bits: &mut BitSlice<T, O>, mut value: u64)
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let mut end = bits.len();
+	while end > 0 {
+		let start = end.saturating_sub(WORD_BITS);
+		let width = end - start;
+		let word = ((value as usize) << (WORD_BITS - width)).reverse_bits();
+		bits[start .. end].store_word(word);
+		value = value.checked_shr(width as u32).unwrap_or(0);
+		end = start;
+	}
+}
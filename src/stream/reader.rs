@@ -0,0 +1,390 @@
+//! Reading fields out of a bit-stream.
+
+use core::fmt::{
+	self,
+	Debug,
+	Formatter,
+};
+
+use funty::Integral;
+
+use super::StreamError;
+use crate::{
+	field::BitField,
+	mem::bits_of,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+};
+
+#[doc = include_str!("../../doc/stream/BitReader.md")]
+pub struct BitReader<'a, T = usize, O = Lsb0>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The entire bit-slice being read.
+	bits:     &'a BitSlice<T, O>,
+	/// The number of bits that have been consumed from the front of `bits`.
+	position: usize,
+}
+
+impl<'a, T, O> BitReader<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Creates a reader positioned at the front of a bit-slice.
+	#[inline]
+	pub fn new(bits: &'a BitSlice<T, O>) -> Self {
+		Self { bits, position: 0 }
+	}
+
+	/// Gets the number of bits that have been consumed from the stream.
+	#[inline]
+	pub fn position(&self) -> usize {
+		self.position
+	}
+
+	/// Gets the number of bits that have not yet been consumed.
+	#[inline]
+	pub fn remaining(&self) -> usize {
+		self.bits.len() - self.position
+	}
+
+	/// Tests if every bit in the stream has been consumed.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.remaining() == 0
+	}
+
+	/// Views the bits that have not yet been consumed.
+	#[inline]
+	pub fn as_bitslice(&self) -> &'a BitSlice<T, O> {
+		unsafe { self.bits.get_unchecked(self.position ..) }
+	}
+
+	/// Gets the entire bit-slice being read, including consumed bits.
+	#[inline]
+	pub fn into_inner(self) -> &'a BitSlice<T, O> {
+		self.bits
+	}
+
+	/// Views the next `width` bits of the stream, without consuming them.
+	///
+	/// ## Errors
+	///
+	/// This fails if the stream has fewer than `width` bits remaining.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitReader;
+	///
+	/// let bits = bits![0, 1, 1];
+	/// let reader = BitReader::new(bits);
+	/// assert_eq!(reader.peek_bitslice(2).unwrap(), bits![0, 1]);
+	/// assert!(reader.peek_bitslice(4).is_err());
+	/// assert_eq!(reader.position(), 0);
+	/// ```
+	#[inline]
+	pub fn peek_bitslice(
+		&self,
+		width: usize,
+	) -> Result<&'a BitSlice<T, O>, StreamError> {
+		StreamError::check_remaining(width, self.remaining())?;
+		let start = self.position;
+		Ok(unsafe { self.bits.get_unchecked(start .. start + width) })
+	}
+
+	/// Consumes the next `width` bits of the stream, and returns a view of
+	/// them.
+	///
+	/// ## Errors
+	///
+	/// This fails if the stream has fewer than `width` bits remaining.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitReader;
+	///
+	/// let bits = bits![0, 1, 1];
+	/// let mut reader = BitReader::new(bits);
+	/// assert_eq!(reader.read_bitslice(2).unwrap(), bits![0, 1]);
+	/// assert_eq!(reader.read_bitslice(1).unwrap(), bits![1]);
+	/// assert!(reader.is_empty());
+	/// ```
+	#[inline]
+	pub fn read_bitslice(
+		&mut self,
+		width: usize,
+	) -> Result<&'a BitSlice<T, O>, StreamError> {
+		let out = self.peek_bitslice(width)?;
+		self.position += width;
+		Ok(out)
+	}
+
+	/// Reads a single bit out of the stream.
+	///
+	/// ## Errors
+	///
+	/// This fails if the stream is empty.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitReader;
+	///
+	/// let bits = bits![1, 0];
+	/// let mut reader = BitReader::new(bits);
+	/// assert_eq!(reader.read_bool(), Ok(true));
+	/// assert_eq!(reader.read_bool(), Ok(false));
+	/// assert!(reader.read_bool().is_err());
+	/// ```
+	#[inline]
+	pub fn read_bool(&mut self) -> Result<bool, StreamError> {
+		self.read_bitslice(1).map(|bit| bit[0])
+	}
+
+	/// Reads a single bit out of the stream, without consuming it.
+	///
+	/// ## Errors
+	///
+	/// This fails if the stream is empty.
+	#[inline]
+	pub fn peek_bool(&self) -> Result<bool, StreamError> {
+		self.peek_bitslice(1).map(|bit| bit[0])
+	}
+
+	/// Consumes the next `width` bits of the stream without inspecting them.
+	///
+	/// ## Errors
+	///
+	/// This fails if the stream has fewer than `width` bits remaining.
+	#[inline]
+	pub fn skip(&mut self, width: usize) -> Result<(), StreamError> {
+		self.read_bitslice(width).map(drop)
+	}
+
+	/// Advances the stream to the next multiple of eight bits from its start.
+	///
+	/// Alignment is measured from the front of the bit-slice the reader was
+	/// created with, not from the memory element that holds it.
+	///
+	/// ## Returns
+	///
+	/// The number of bits skipped, which is in `0 .. 8`.
+	///
+	/// ## Errors
+	///
+	/// This fails if the stream ends before the next byte boundary.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitReader;
+	///
+	/// let bits = bits![0; 14];
+	/// let mut reader = BitReader::new(bits);
+	/// reader.skip(3).unwrap();
+	/// assert_eq!(reader.align_to_byte(), Ok(5));
+	/// assert_eq!(reader.align_to_byte(), Ok(0));
+	/// reader.skip(3).unwrap();
+	/// assert!(reader.align_to_byte().is_err());
+	/// ```
+	#[inline]
+	pub fn align_to_byte(&mut self) -> Result<usize, StreamError> {
+		let byte = bits_of::<u8>();
+		let pad = (byte - self.position % byte) % byte;
+		self.skip(pad).map(|()| pad)
+	}
+}
+
+/// Integer reads.
+impl<'a, T, O> BitReader<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	/// Reads an integer out of the next `width` bits of the stream, with the
+	/// first bit read in the most significant position.
+	///
+	/// This is an alias for [`.read_bits_be()`], as most codec formats order
+	/// their fields most-significant-bit first.
+	///
+	/// [`.read_bits_be()`]: Self::read_bits_be
+	#[inline]
+	pub fn read_bits<I>(&mut self, width: usize) -> Result<I, StreamError>
+	where I: Integral {
+		self.read_bits_be(width)
+	}
+
+	/// Reads an integer out of the next `width` bits of the stream, without
+	/// consuming them.
+	///
+	/// This is an alias for [`.peek_bits_be()`].
+	///
+	/// [`.peek_bits_be()`]: Self::peek_bits_be
+	#[inline]
+	pub fn peek_bits<I>(&self, width: usize) -> Result<I, StreamError>
+	where I: Integral {
+		self.peek_bits_be(width)
+	}
+
+	/// Reads an integer out of the next `width` bits of the stream, using
+	/// [`BitField::load_be`].
+	///
+	/// A `width` of zero reads the value `0`. Signed integers are
+	/// sign-extended from `width` bits.
+	///
+	/// ## Errors
+	///
+	/// This fails if `width` is wider than `I`, or if the stream has fewer than
+	/// `width` bits remaining.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitReader;
+	///
+	/// let bits = bits![u8, Msb0; 1, 0, 1, 1, 0, 1];
+	/// let mut reader = BitReader::new(bits);
+	/// assert_eq!(reader.read_bits_be::<u8>(3), Ok(0b101));
+	/// assert_eq!(reader.read_bits_be::<i8>(3), Ok(-3));
+	/// assert!(reader.read_bits_be::<u8>(1).is_err());
+	/// ```
+	///
+	/// [`BitField::load_be`]: crate::field::BitField::load_be
+	#[inline]
+	pub fn read_bits_be<I>(&mut self, width: usize) -> Result<I, StreamError>
+	where I: Integral {
+		let out = self.peek_bits_be(width)?;
+		self.position += width;
+		Ok(out)
+	}
+
+	/// Reads an integer out of the next `width` bits of the stream, using
+	/// [`BitField::load_le`].
+	///
+	/// A `width` of zero reads the value `0`. Signed integers are
+	/// sign-extended from `width` bits.
+	///
+	/// ## Errors
+	///
+	/// This fails if `width` is wider than `I`, or if the stream has fewer than
+	/// `width` bits remaining.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitReader;
+	///
+	/// let data = [0x8Du8, 0x02];
+	/// let mut reader = BitReader::new(data.view_bits::<Lsb0>());
+	/// assert_eq!(reader.read_bits_le::<u8>(3), Ok(0b101));
+	/// assert_eq!(reader.read_bits_le::<u16>(7), Ok(0b1010001));
+	/// ```
+	///
+	/// [`BitField::load_le`]: crate::field::BitField::load_le
+	#[inline]
+	pub fn read_bits_le<I>(&mut self, width: usize) -> Result<I, StreamError>
+	where I: Integral {
+		let out = self.peek_bits_le(width)?;
+		self.position += width;
+		Ok(out)
+	}
+
+	/// Reads an integer out of the next `width` bits of the stream, using
+	/// [`BitField::load_be`], without consuming them.
+	///
+	/// ## Errors
+	///
+	/// This fails if `width` is wider than `I`, or if the stream has fewer than
+	/// `width` bits remaining.
+	///
+	/// [`BitField::load_be`]: crate::field::BitField::load_be
+	#[inline]
+	pub fn peek_bits_be<I>(&self, width: usize) -> Result<I, StreamError>
+	where I: Integral {
+		StreamError::check_width(width, bits_of::<I>())?;
+		self.peek_bitslice(width).map(|bits| match width {
+			0 => I::ZERO,
+			_ => bits.load_be::<I>(),
+		})
+	}
+
+	/// Reads an integer out of the next `width` bits of the stream, using
+	/// [`BitField::load_le`], without consuming them.
+	///
+	/// ## Errors
+	///
+	/// This fails if `width` is wider than `I`, or if the stream has fewer than
+	/// `width` bits remaining.
+	///
+	/// [`BitField::load_le`]: crate::field::BitField::load_le
+	#[inline]
+	pub fn peek_bits_le<I>(&self, width: usize) -> Result<I, StreamError>
+	where I: Integral {
+		StreamError::check_width(width, bits_of::<I>())?;
+		self.peek_bitslice(width).map(|bits| match width {
+			0 => I::ZERO,
+			_ => bits.load_le::<I>(),
+		})
+	}
+}
+
+impl<'a, T, O> From<&'a BitSlice<T, O>> for BitReader<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(bits: &'a BitSlice<T, O>) -> Self {
+		Self::new(bits)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Clone for BitReader<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<T, O> Copy for BitReader<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Debug for BitReader<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("BitReader")
+			.field("position", &self.position)
+			.field("remaining", &self.as_bitslice())
+			.finish()
+	}
+}
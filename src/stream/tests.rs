@@ -0,0 +1,364 @@
+//! Unit tests for bit-stream cursors.
+
+#![cfg(test)]
+
+use rand::random;
+
+use super::*;
+use crate::{
+	field::BitField,
+	prelude::*,
+};
+
+#[test]
+fn reader_cursor() {
+	let bits = bits![u8, Msb0; 1, 0, 1, 1, 0, 0, 1, 0, 1, 1];
+	let mut reader = BitReader::new(bits);
+
+	assert_eq!(reader.peek_bool(), Ok(true));
+	assert_eq!(reader.read_bool(), Ok(true));
+	assert_eq!(reader.peek_bits::<u8>(3), Ok(0b011));
+	assert_eq!(reader.read_bits::<u8>(3), Ok(0b011));
+	assert_eq!(reader.position(), 4);
+	assert_eq!(reader.remaining(), 6);
+	assert_eq!(reader.as_bitslice(), bits[4 ..]);
+
+	assert_eq!(reader.read_bits::<u8>(0), Ok(0));
+	assert_eq!(reader.align_to_byte(), Ok(4));
+	assert_eq!(reader.align_to_byte(), Ok(0));
+	assert_eq!(reader.read_bitslice(2).unwrap(), bits![1, 1]);
+	assert!(reader.is_empty());
+	assert_eq!(reader.into_inner(), bits);
+}
+
+#[test]
+fn reader_errors() {
+	let bits = bits![0; 12];
+	let mut reader = BitReader::new(bits);
+	reader.skip(2).unwrap();
+
+	assert_eq!(
+		reader.read_bits::<u8>(9),
+		Err(StreamError::TooWide {
+			requested: 9,
+			max:       8,
+		})
+	);
+	assert_eq!(
+		reader.read_bits::<u16>(11),
+		Err(StreamError::Exhausted {
+			requested: 11,
+			remaining: 10,
+		})
+	);
+	assert_eq!(
+		reader.skip(11),
+		Err(StreamError::Exhausted {
+			requested: 11,
+			remaining: 10,
+		})
+	);
+	reader.skip(7).unwrap();
+	assert!(reader.align_to_byte().is_err());
+	//  Failed requests do not move the cursor.
+	assert_eq!(reader.position(), 9);
+	assert_eq!(reader.read_bits::<u16>(3), Ok(0));
+	assert!(reader.read_bool().is_err());
+}
+
+#[test]
+fn reader_matches_bitfield() {
+	let data = random::<[u16; 8]>();
+	let msb0 = data.view_bits::<Msb0>();
+	let lsb0 = data.view_bits::<Lsb0>();
+	let mut be = BitReader::new(msb0);
+	let mut le = BitReader::new(lsb0);
+
+	let mut start = 0;
+	for &width in &[3, 13, 1, 32, 7, 20, 5, 27, 9, 11] {
+		let end = start + width;
+		assert_eq!(
+			be.read_bits_be::<u32>(width),
+			Ok(msb0[start .. end].load_be())
+		);
+		assert_eq!(
+			le.read_bits_le::<i32>(width),
+			Ok(lsb0[start .. end].load_le())
+		);
+		start = end;
+	}
+	assert_eq!(be.position(), start);
+	assert_eq!(le.remaining(), 128 - start);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn writer_vec() {
+	let mut bv = bitvec![u8, Msb0; 1];
+	let mut writer = BitWriter::new(&mut bv);
+
+	assert_eq!(writer.remaining(), None);
+	writer.write_bool(false).unwrap();
+	writer.write_bits(0b1011u8, 4).unwrap();
+	writer.write_bits(0u8, 0).unwrap();
+	assert_eq!(writer.position(), 5);
+	assert_eq!(writer.as_bitslice(), bits![0, 1, 0, 1, 1]);
+	assert_eq!(
+		writer.write_bits(0u8, 9),
+		Err(StreamError::TooWide {
+			requested: 9,
+			max:       8,
+		})
+	);
+
+	assert_eq!(writer.pad_to_byte(false), Ok(3));
+	writer.write_bitslice(bits![1, 1]).unwrap();
+	assert_eq!(writer.finish(), 10);
+	assert_eq!(bv, bits![1, 0, 1, 0, 1, 1, 0, 0, 0, 1, 1]);
+}
+
+#[test]
+fn writer_fixed() {
+	let mut data = [0u8; 2];
+	let mut writer = BitWriter::fixed(data.view_bits_mut::<Msb0>());
+
+	writer.write_bits(0x5Au8, 8).unwrap();
+	writer.write_bits(0b11u8, 2).unwrap();
+	assert_eq!(writer.remaining(), Some(6));
+	assert_eq!(
+		writer.write_bits(0u8, 7),
+		Err(StreamError::Exhausted {
+			requested: 7,
+			remaining: 6,
+		})
+	);
+	assert!(writer.write_bitslice(bits![0; 7]).is_err());
+	//  Failed requests do not move the cursor or touch memory.
+	assert_eq!(writer.position(), 10);
+	writer.write_bits(0u8, 6).unwrap();
+	assert!(writer.write_bool(true).is_err());
+	assert_eq!(writer.pad_to_byte(true), Ok(0));
+	assert_eq!(writer.finish(), 16);
+	assert_eq!(data, [0x5A, 0xC0]);
+}
+
+#[test]
+fn writer_round_trip() {
+	let values = random::<[u32; 10]>();
+	let widths = [3, 13, 1, 32, 7, 20, 5, 27, 9, 11];
+	let mut data = [0u16; 8];
+
+	let mut writer = BitWriter::fixed(data.view_bits_mut::<Lsb0>());
+	for (&value, &width) in values.iter().zip(widths.iter()) {
+		writer.write_bits_le(value, width).unwrap();
+	}
+	assert_eq!(writer.finish(), 128);
+
+	let mut reader = BitReader::new(data.view_bits::<Lsb0>());
+	for (&value, &width) in values.iter().zip(widths.iter()) {
+		let mask = !0u32 >> (32 - width);
+		assert_eq!(reader.read_bits_le::<u32>(width), Ok(value & mask));
+	}
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn codes_reference() {
+	fn check<O>()
+	where
+		O: BitOrder,
+		BitSlice<u16, O>: BitField,
+	{
+		let mut bv = BitVec::<u16, O>::new();
+		let mut writer = BitWriter::new(&mut bv);
+		writer.write_unary(2).unwrap();
+		writer.write_gamma(5).unwrap();
+		writer.write_delta(17).unwrap();
+		writer.write_exp_golomb(4).unwrap();
+		writer.write_signed_exp_golomb(-3).unwrap();
+		writer.write_rice(9, 2).unwrap();
+		writer.write_golomb(4, 5).unwrap();
+		writer.write_gamma(u64::MAX).unwrap();
+		assert_eq!(writer.finish(), 163);
+
+		assert_eq!(bv[.. 3], bits![1, 1, 0]);
+		assert_eq!(bv[3 .. 8], bits![0, 0, 1, 0, 1]);
+		assert_eq!(bv[8 .. 17], bits![0, 0, 1, 0, 1, 0, 0, 0, 1]);
+		assert_eq!(bv[17 .. 22], bits![0, 0, 1, 0, 1]);
+		assert_eq!(bv[22 .. 27], bits![0, 0, 1, 1, 1]);
+		assert_eq!(bv[27 .. 32], bits![1, 1, 0, 0, 1]);
+		assert_eq!(bv[32 .. 36], bits![0, 1, 1, 1]);
+		assert!(bv[36 .. 99].not_any());
+		assert!(bv[99 ..].all());
+
+		let mut reader = BitReader::new(bv.as_bitslice());
+		assert_eq!(reader.read_unary(), Ok(2));
+		assert_eq!(reader.read_gamma(), Ok(5));
+		assert_eq!(reader.read_delta(), Ok(17));
+		assert_eq!(reader.read_exp_golomb(), Ok(4));
+		assert_eq!(reader.read_signed_exp_golomb(), Ok(-3));
+		assert_eq!(reader.read_rice(2), Ok(9));
+		assert_eq!(reader.read_golomb(5), Ok(4));
+		assert_eq!(reader.read_gamma(), Ok(u64::MAX));
+		assert!(reader.is_empty());
+	}
+
+	check::<Lsb0>();
+	check::<Msb0>();
+
+	for &(value, bytes) in &[
+		(0u64, &[0x00u8][..]),
+		(127, &[0x7F]),
+		(128, &[0x80, 0x01]),
+		(624_485, &[0xE5, 0x8E, 0x26]),
+		(u64::MAX, &[
+			0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01,
+		]),
+	] {
+		let mut bv = BitVec::<u8, Lsb0>::new();
+		BitWriter::new(&mut bv).write_leb128(value).unwrap();
+		assert_eq!(bv.as_raw_slice(), bytes);
+		let mut reader = BitReader::new(bytes.view_bits::<Msb0>());
+		assert_eq!(reader.read_leb128(), Ok(value));
+	}
+
+	for &(value, bytes) in &[
+		(0i64, &[0x00u8][..]),
+		(-1, &[0x7F]),
+		(63, &[0x3F]),
+		(64, &[0xC0, 0x00]),
+		(-123_456, &[0xC0, 0xBB, 0x78]),
+		(i64::MIN, &[
+			0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F,
+		]),
+	] {
+		let mut bv = BitVec::<u8, Msb0>::new();
+		BitWriter::new(&mut bv).write_signed_leb128(value).unwrap();
+		assert_eq!(bv.as_raw_slice(), bytes);
+		let mut reader = BitReader::new(bytes.view_bits::<Lsb0>());
+		assert_eq!(reader.read_signed_leb128(), Ok(value));
+	}
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn codes_round_trip() {
+	let values = random::<[u64; 16]>();
+	let mut bv = BitVec::<u32, Msb0>::new();
+	let mut writer = BitWriter::new(&mut bv);
+	for &value in &values {
+		let small = value >> 56;
+		writer.write_unary(small).unwrap();
+		writer.write_gamma(value | 1).unwrap();
+		writer.write_delta(value | 1).unwrap();
+		writer.write_exp_golomb(value >> 1).unwrap();
+		writer.write_signed_exp_golomb(value as i64 >> 1).unwrap();
+		writer.write_rice(value, 60).unwrap();
+		writer.write_golomb(value, (value >> 8) | 1).unwrap();
+		writer.write_golomb(small, 7).unwrap();
+		writer.write_leb128(value).unwrap();
+		writer.write_signed_leb128(value as i64).unwrap();
+	}
+
+	let mut reader = BitReader::new(bv.as_bitslice());
+	for &value in &values {
+		let small = value >> 56;
+		assert_eq!(reader.read_unary(), Ok(small));
+		assert_eq!(reader.read_gamma(), Ok(value | 1));
+		assert_eq!(reader.read_delta(), Ok(value | 1));
+		assert_eq!(reader.read_exp_golomb(), Ok(value >> 1));
+		assert_eq!(reader.read_signed_exp_golomb(), Ok(value as i64 >> 1));
+		assert_eq!(reader.read_rice(60), Ok(value));
+		assert_eq!(reader.read_golomb((value >> 8) | 1), Ok(value));
+		assert_eq!(reader.read_golomb(7), Ok(small));
+		assert_eq!(reader.read_leb128(), Ok(value));
+		assert_eq!(reader.read_signed_leb128(), Ok(value as i64));
+	}
+	assert!(reader.is_empty());
+}
+
+#[test]
+fn codes_errors() {
+	let mut data = [0u8; 2];
+	let mut writer = BitWriter::fixed(data.view_bits_mut::<Msb0>());
+	assert_eq!(writer.write_gamma(0), Err(StreamError::Unencodable));
+	assert_eq!(writer.write_delta(0), Err(StreamError::Unencodable));
+	assert_eq!(
+		writer.write_exp_golomb(u64::MAX),
+		Err(StreamError::Unencodable)
+	);
+	assert_eq!(
+		writer.write_signed_exp_golomb(i64::MIN),
+		Err(StreamError::Unencodable)
+	);
+	assert_eq!(writer.write_golomb(1, 0), Err(StreamError::Unencodable));
+	assert_eq!(
+		writer.write_rice(1, 65),
+		Err(StreamError::TooWide {
+			requested: 65,
+			max:       64,
+		})
+	);
+	writer.write_unary(10).unwrap();
+	assert_eq!(
+		writer.write_gamma(16),
+		Err(StreamError::Exhausted {
+			requested: 9,
+			remaining: 5,
+		})
+	);
+	assert!(writer.write_leb128(1).is_err());
+	assert_eq!(writer.position(), 11);
+	assert_eq!(writer.finish(), 11);
+	assert_eq!(data, [0xFF, 0xC0]);
+
+	//  Failed reads do not move the cursor.
+	let bits = bits![u8, Msb0; 1, 1, 1, 0, 0, 0, 0, 0];
+	let mut reader = BitReader::new(&bits[3 ..]);
+	assert!(reader.read_gamma().is_err());
+	assert!(reader.read_delta().is_err());
+	assert_eq!(reader.position(), 0);
+	let mut reader = BitReader::new(&bits[.. 3]);
+	assert!(reader.read_unary().is_err());
+	assert!(reader.read_golomb(3).is_err());
+	assert_eq!(reader.position(), 0);
+
+	let mut wide = [0u8; 9];
+	wide[8] = 0x80;
+	assert_eq!(
+		BitReader::new(wide.view_bits::<Msb0>()).read_gamma(),
+		Err(StreamError::Overflow)
+	);
+	let ones = bits![1; 65];
+	let mut reader = BitReader::new(ones);
+	assert_eq!(
+		reader.read_rice(0),
+		Err(StreamError::Exhausted {
+			requested: 66,
+			remaining: 65,
+		})
+	);
+	assert_eq!(reader.position(), 0);
+
+	let mut leb = [0xFFu8; 11];
+	leb[10] = 0x00;
+	assert_eq!(
+		BitReader::new(leb.view_bits::<Lsb0>()).read_leb128(),
+		Err(StreamError::Overflow)
+	);
+	leb[9] = 0x02;
+	assert_eq!(
+		BitReader::new(leb[.. 10].view_bits::<Lsb0>()).read_leb128(),
+		Err(StreamError::Overflow)
+	);
+	leb[9] = 0x01;
+	assert_eq!(
+		BitReader::new(leb[.. 10].view_bits::<Lsb0>()).read_leb128(),
+		Ok(u64::MAX)
+	);
+	leb[9] = 0x7E;
+	assert_eq!(
+		BitReader::new(leb[.. 10].view_bits::<Lsb0>()).read_signed_leb128(),
+		Err(StreamError::Overflow)
+	);
+}
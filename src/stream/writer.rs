@@ -0,0 +1,421 @@
+//! Writing fields into a bit-stream.
+
+use core::fmt::{
+	self,
+	Debug,
+	Formatter,
+};
+
+use funty::Integral;
+
+use super::StreamError;
+#[cfg(feature = "alloc")]
+use crate::vec::BitVec;
+use crate::{
+	field::BitField,
+	mem::bits_of,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+};
+
+#[doc = include_str!("../../doc/stream/BitWriter.md")]
+pub struct BitWriter<'a, T = usize, O = Lsb0>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The destination of written bits.
+	sink:     Sink<'a, T, O>,
+	/// The number of bits that have been written through this writer.
+	position: usize,
+}
+
+/// The storage into which a [`BitWriter`] places its bits.
+enum Sink<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// A bit-vector that grows to hold each write.
+	#[cfg(feature = "alloc")]
+	Vec {
+		/// The bit-vector being appended.
+		vec:   &'a mut BitVec<T, O>,
+		/// The length of the bit-vector when the writer was created.
+		start: usize,
+	},
+	/// A fixed-size bit-slice that is filled from front to back.
+	Slice(&'a mut BitSlice<T, O>),
+}
+
+impl<'a, T, O> BitWriter<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Creates a writer that appends to the end of a bit-vector.
+	///
+	/// The bit-vector grows as bits are written into it, so writes never run
+	/// out of room. Bits already in the bit-vector are left untouched.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitWriter;
+	///
+	/// let mut bv = bitvec![u8, Msb0; 1, 1];
+	/// let mut writer = BitWriter::new(&mut bv);
+	/// writer.write_bits(0b01u8, 2).unwrap();
+	/// assert_eq!(writer.finish(), 2);
+	/// assert_eq!(bv, bits![1, 1, 0, 1]);
+	/// ```
+	#[inline]
+	#[cfg(feature = "alloc")]
+	pub fn new(vec: &'a mut BitVec<T, O>) -> Self {
+		let start = vec.len();
+		Self {
+			sink:     Sink::Vec { vec, start },
+			position: 0,
+		}
+	}
+
+	/// Creates a writer that fills a fixed-size bit-slice from its front.
+	///
+	/// Writes that would run past the end of the bit-slice fail, and leave it
+	/// unmodified.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitWriter;
+	///
+	/// let mut data = 0u8;
+	/// let mut writer = BitWriter::fixed(data.view_bits_mut::<Msb0>());
+	/// writer.write_bits(0b1011u8, 4).unwrap();
+	/// assert!(writer.write_bits(0u8, 5).is_err());
+	/// assert_eq!(writer.remaining(), Some(4));
+	/// assert_eq!(data, 0xB0);
+	/// ```
+	#[inline]
+	pub fn fixed(bits: &'a mut BitSlice<T, O>) -> Self {
+		Self {
+			sink:     Sink::Slice(bits),
+			position: 0,
+		}
+	}
+
+	/// Gets the number of bits that have been written through this writer.
+	#[inline]
+	pub fn position(&self) -> usize {
+		self.position
+	}
+
+	/// Gets the number of bits that can still be written.
+	///
+	/// ## Returns
+	///
+	/// The space left in a [fixed] writer, or `None` if the writer grows a
+	/// bit-vector.
+	///
+	/// [fixed]: Self::fixed
+	#[inline]
+	pub fn remaining(&self) -> Option<usize> {
+		match self.sink {
+			#[cfg(feature = "alloc")]
+			Sink::Vec { .. } => None,
+			Sink::Slice(ref bits) => Some(bits.len() - self.position),
+		}
+	}
+
+	/// Views the bits that have been written through this writer.
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<T, O> {
+		match self.sink {
+			#[cfg(feature = "alloc")]
+			Sink::Vec { ref vec, start } => unsafe { vec.get_unchecked(start ..) },
+			Sink::Slice(ref bits) => unsafe {
+				bits.get_unchecked(.. self.position)
+			},
+		}
+	}
+
+	/// Writes a bit-slice into the stream.
+	///
+	/// ## Errors
+	///
+	/// This fails if the writer is [fixed] and has fewer than `src.len()` bits
+	/// remaining.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitWriter;
+	///
+	/// let mut bv = BitVec::<u8, Msb0>::new();
+	/// let mut writer = BitWriter::new(&mut bv);
+	/// writer.write_bitslice(bits![0, 1]).unwrap();
+	/// writer.write_bitslice(bits![u16, Msb0; 1]).unwrap();
+	/// assert_eq!(bv, bits![0, 1, 1]);
+	/// ```
+	///
+	/// [fixed]: Self::fixed
+	#[inline]
+	pub fn write_bitslice<T2, O2>(
+		&mut self,
+		src: &BitSlice<T2, O2>,
+	) -> Result<(), StreamError>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.reserve(src.len())?.clone_from_bitslice(src);
+		Ok(())
+	}
+
+	/// Writes a single bit into the stream.
+	///
+	/// ## Errors
+	///
+	/// This fails if the writer is [fixed] and full.
+	///
+	/// [fixed]: Self::fixed
+	#[inline]
+	pub fn write_bool(&mut self, bit: bool) -> Result<(), StreamError> {
+		self.reserve(1)?.set(0, bit);
+		Ok(())
+	}
+
+	/// Writes copies of a bit until the stream reaches the next multiple of
+	/// eight bits from its start.
+	///
+	/// Alignment is measured from the first bit written by this writer, not
+	/// from the memory element that holds it.
+	///
+	/// ## Returns
+	///
+	/// The number of bits written, which is in `0 .. 8`.
+	///
+	/// ## Errors
+	///
+	/// This fails if the writer is [fixed] and ends before the next byte
+	/// boundary.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitWriter;
+	///
+	/// let mut bv = BitVec::<u8, Msb0>::new();
+	/// let mut writer = BitWriter::new(&mut bv);
+	/// writer.write_bits(0b101u8, 3).unwrap();
+	/// assert_eq!(writer.pad_to_byte(true), Ok(5));
+	/// assert_eq!(writer.pad_to_byte(true), Ok(0));
+	/// assert_eq!(bv.as_raw_slice(), &[0xBF]);
+	/// ```
+	///
+	/// [fixed]: Self::fixed
+	#[inline]
+	pub fn pad_to_byte(&mut self, bit: bool) -> Result<usize, StreamError> {
+		let byte = bits_of::<u8>();
+		let pad = (byte - self.position % byte) % byte;
+		self.reserve(pad)?.fill(bit);
+		Ok(pad)
+	}
+
+	/// Ends the stream.
+	///
+	/// ## Returns
+	///
+	/// The number of bits written through this writer.
+	#[inline]
+	pub fn finish(self) -> usize {
+		self.position
+	}
+
+	/// Claims the next `width` bits of the stream for writing, and advances
+	/// the cursor past them.
+	///
+	/// A bit-vector sink is grown to hold the new bits. A bit-slice sink is
+	/// checked for room, and the cursor is not moved if it has none.
+	pub(crate) fn reserve(
+		&mut self,
+		width: usize,
+	) -> Result<&mut BitSlice<T, O>, StreamError> {
+		let position = self.position;
+		let out = match self.sink {
+			#[cfg(feature = "alloc")]
+			Sink::Vec { ref mut vec, start } => {
+				let from = start + position;
+				vec.resize(from + width, false);
+				unsafe { vec.get_unchecked_mut(from ..) }
+			},
+			Sink::Slice(ref mut bits) => {
+				StreamError::check_remaining(width, bits.len() - position)?;
+				unsafe { bits.get_unchecked_mut(position .. position + width) }
+			},
+		};
+		self.position += width;
+		Ok(out)
+	}
+}
+
+/// Integer writes.
+impl<'a, T, O> BitWriter<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	/// Writes the low `width` bits of an integer into the stream, with the most
+	/// significant of them written first.
+	///
+	/// This is an alias for [`.write_bits_be()`], as most codec formats order
+	/// their fields most-significant-bit first.
+	///
+	/// [`.write_bits_be()`]: Self::write_bits_be
+	#[inline]
+	pub fn write_bits<I>(
+		&mut self,
+		value: I,
+		width: usize,
+	) -> Result<(), StreamError>
+	where
+		I: Integral,
+	{
+		self.write_bits_be(value, width)
+	}
+
+	/// Writes the low `width` bits of an integer into the stream, using
+	/// [`BitField::store_be`].
+	///
+	/// Bits of `value` above `width` are discarded. A `width` of zero writes
+	/// nothing.
+	///
+	/// ## Errors
+	///
+	/// This fails if `width` is wider than `I`, or if the writer is [fixed]
+	/// and has fewer than `width` bits remaining. The stream is not modified
+	/// when a write fails.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitWriter;
+	///
+	/// let mut bv = BitVec::<u8, Msb0>::new();
+	/// let mut writer = BitWriter::new(&mut bv);
+	/// writer.write_bits_be(0b101u8, 3).unwrap();
+	/// writer.write_bits_be(-3i8, 3).unwrap();
+	/// assert!(writer.write_bits_be(0u8, 9).is_err());
+	/// assert_eq!(bv, bits![1, 0, 1, 1, 0, 1]);
+	/// ```
+	///
+	/// [`BitField::store_be`]: crate::field::BitField::store_be
+	/// [fixed]: Self::fixed
+	#[inline]
+	pub fn write_bits_be<I>(
+		&mut self,
+		value: I,
+		width: usize,
+	) -> Result<(), StreamError>
+	where
+		I: Integral,
+	{
+		StreamError::check_width(width, bits_of::<I>())?;
+		if width > 0 {
+			self.reserve(width)?.store_be(value);
+		}
+		Ok(())
+	}
+
+	/// Writes the low `width` bits of an integer into the stream, using
+	/// [`BitField::store_le`].
+	///
+	/// Bits of `value` above `width` are discarded. A `width` of zero writes
+	/// nothing.
+	///
+	/// ## Errors
+	///
+	/// This fails if `width` is wider than `I`, or if the writer is [fixed]
+	/// and has fewer than `width` bits remaining. The stream is not modified
+	/// when a write fails.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitWriter;
+	///
+	/// let mut data = [0u8; 2];
+	/// let mut writer = BitWriter::fixed(data.view_bits_mut::<Lsb0>());
+	/// writer.write_bits_le(0b101u8, 3).unwrap();
+	/// writer.write_bits_le(0b1010001u16, 7).unwrap();
+	/// assert_eq!(data, [0x8D, 0x02]);
+	/// ```
+	///
+	/// [`BitField::store_le`]: crate::field::BitField::store_le
+	/// [fixed]: Self::fixed
+	#[inline]
+	pub fn write_bits_le<I>(
+		&mut self,
+		value: I,
+		width: usize,
+	) -> Result<(), StreamError>
+	where
+		I: Integral,
+	{
+		StreamError::check_width(width, bits_of::<I>())?;
+		if width > 0 {
+			self.reserve(width)?.store_le(value);
+		}
+		Ok(())
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T, O> From<&'a mut BitVec<T, O>> for BitWriter<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(vec: &'a mut BitVec<T, O>) -> Self {
+		Self::new(vec)
+	}
+}
+
+impl<'a, T, O> From<&'a mut BitSlice<T, O>> for BitWriter<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(bits: &'a mut BitSlice<T, O>) -> Self {
+		Self::fixed(bits)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Debug for BitWriter<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("BitWriter")
+			.field("position", &self.position)
+			.field("remaining", &self.remaining())
+			.field("written", &self.as_bitslice())
+			.finish()
+	}
+}
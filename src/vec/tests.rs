@@ -78,6 +78,15 @@ fn misc() {
 	bv.force_align();
 
 	assert_eq!(!bitvec![0, 1], bits![1, 0]);
+
+	let bv = bitvec![u16, Msb0; 1, 0, 1, 1, 0];
+	assert_eq!(bv.clone() << 2, bits![1, 1, 0, 0, 0]);
+	assert_eq!(bv.clone() >> 1, bits![0, 1, 0, 1, 1]);
+	let mut bv = bv;
+	bv >>= 4;
+	assert_eq!(bv, bits![0, 0, 0, 0, 1]);
+	bv <<= 5;
+	assert!(bv.not_any());
 }
 
 #[test]
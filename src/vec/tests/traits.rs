@@ -21,13 +21,17 @@ use core::{
 		RefUnwindSafe,
 		UnwindSafe,
 	},
+	str::FromStr,
 };
 #[cfg(feature = "std")]
 use std::io::Write;
 
 use static_assertions::*;
 
-use crate::prelude::*;
+use crate::{
+	prelude::*,
+	slice::ParseBitsError,
+};
 
 #[test]
 fn alloc_impl() {
@@ -53,6 +57,7 @@ fn alloc_impl() {
 		From<BitBox<usize, Lsb0>>,
 		From<Cow<'static, BitSlice<usize, Lsb0>>>,
 		FromIterator<bool>,
+		FromStr,
 		Hash,
 		Index<usize>,
 		Index<Range<usize>>,
@@ -100,3 +105,207 @@ fn format() {
 	);
 	assert!(text.ends_with(" } [0, 1, 0, 0]"), "{}", text);
 }
+
+#[test]
+fn parse() {
+	#[cfg(not(feature = "std"))]
+	use alloc::format;
+
+	for len in [0, 1, 7, 8, 9, 31, 64, 100].iter().copied() {
+		let bv = (0 .. len)
+			.map(|_| rand::random::<bool>())
+			.collect::<BitVec<u8, Msb0>>();
+		let bits = bv.as_bitslice();
+		assert_eq!(
+			format!("{}", bv).parse::<BitVec<u8, Msb0>>(),
+			Ok(bv.clone())
+		);
+		assert_eq!(
+			format!("{:#b}", bits).parse::<BitVec<u16, Lsb0>>().unwrap(),
+			bits
+		);
+		assert_eq!(format!("{:#b}", bits).parse::<BitBox>().unwrap(), bits);
+	}
+
+	assert_eq!(" 0o17_ ".parse::<BitVec>().unwrap(), bits![
+		0, 0, 1, 1, 1, 1
+	]);
+	assert_eq!("[ ]".parse::<BitVec>().unwrap(), bits![]);
+	assert_eq!("[0b1, 4'h0, 1, 0b1_0]".parse::<BitVec>().unwrap(), bits![
+		1, 0, 0, 0, 0, 1, 1, 0
+	]);
+	assert_eq!(
+		"[0b1, 10]".parse::<BitVec>(),
+		Err(ParseBitsError::Unsized { index: 6 })
+	);
+	assert_eq!(
+		"[0, 0x1]".parse::<BitVec>(),
+		Err(ParseBitsError::Unsized { index: 4 })
+	);
+	assert_eq!(
+		"[0o2]".parse::<BitVec>(),
+		Err(ParseBitsError::Unsized { index: 1 })
+	);
+	assert_eq!(
+		"[12]".parse::<BitVec>(),
+		Err(ParseBitsError::InvalidDigit {
+			index: 2,
+			found: '2',
+			radix: 2,
+		})
+	);
+
+	assert_eq!(
+		"".parse::<BitVec>(),
+		Err(ParseBitsError::Empty { index: 0 })
+	);
+	assert_eq!(
+		"0x_".parse::<BitVec>(),
+		Err(ParseBitsError::Empty { index: 2 })
+	);
+	assert_eq!(
+		"[0, , 1]".parse::<BitVec>(),
+		Err(ParseBitsError::Empty { index: 3 })
+	);
+	assert_eq!(
+		"0o18".parse::<BitVec>(),
+		Err(ParseBitsError::InvalidDigit {
+			index: 3,
+			found: '8',
+			radix: 8,
+		})
+	);
+	assert_eq!(
+		"0110b".parse::<BitVec>(),
+		Err(ParseBitsError::InvalidDigit {
+			index: 4,
+			found: 'b',
+			radix: 2,
+		})
+	);
+	assert_eq!("[0, 1".parse::<BitVec>(), Err(ParseBitsError::UnclosedList));
+
+	assert_eq!("10'h2cf".parse::<BitVec>().unwrap(), bits![
+		1, 0, 1, 1, 0, 0, 1, 1, 1, 1
+	]);
+	assert_eq!("[5'O07, 2'b10]".parse::<BitVec>().unwrap(), bits![
+		0, 0, 1, 1, 1, 1, 0
+	]);
+	assert_eq!(
+		"10'h7cf".parse::<BitVec>(),
+		Err(ParseBitsError::Width {
+			index: 4,
+			width: 10,
+		})
+	);
+	assert_eq!(
+		"4'h0f".parse::<BitVec>(),
+		Err(ParseBitsError::Width { index: 3, width: 4 })
+	);
+	assert_eq!(
+		"99999999999999999999999'h1".parse::<BitVec>(),
+		Err(ParseBitsError::Width {
+			index: 25,
+			width: usize::MAX,
+		})
+	);
+	assert_eq!(
+		"1a'h1".parse::<BitVec>(),
+		Err(ParseBitsError::InvalidDigit {
+			index: 1,
+			found: 'a',
+			radix: 10,
+		})
+	);
+	assert_eq!(
+		"'h1".parse::<BitVec>(),
+		Err(ParseBitsError::Empty { index: 0 })
+	);
+	assert_eq!(
+		"12'xA1".parse::<BitVec>(),
+		Err(ParseBitsError::InvalidDigit {
+			index: 3,
+			found: 'x',
+			radix: 10,
+		})
+	);
+	assert_eq!(
+		"3'o05".parse::<BitVec>(),
+		Err(ParseBitsError::Width { index: 3, width: 3 })
+	);
+	assert_eq!(
+		"0x0\u{e9}".parse::<BitVec>(),
+		Err(ParseBitsError::InvalidDigit {
+			index: 3,
+			found: '\u{e9}',
+			radix: 16,
+		})
+	);
+	assert_eq!("0x01 23".parse::<BitVec>().unwrap(), bits![
+		0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 1, 1
+	]);
+}
+
+/// Every rendering of a bit-slice either parses back into the same bits, or
+/// fails rather than producing different bits.
+#[test]
+fn parse_renderings() {
+	#[cfg(not(feature = "std"))]
+	use alloc::format;
+
+	fn check<T, O>()
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		for len in [0, 8, 16, 24, 31, 64, 100].iter().copied() {
+			let bv = (0 .. len)
+				.map(|_| rand::random::<bool>())
+				.collect::<BitVec<T, O>>();
+			for head in [0, 3].iter().copied() {
+				let bits = &bv[head.min(len) ..];
+				let exact = [format!("{}", bits), format!("{:#b}", bits)];
+				for text in exact.iter() {
+					assert_eq!(text.parse::<BitVec<T, O>>().unwrap(), bits);
+				}
+				let others = [
+					format!("{:b}", bits),
+					format!("{:o}", bits),
+					format!("{:#o}", bits),
+					format!("{:x}", bits),
+					format!("{:#x}", bits),
+					format!("{:X}", bits),
+					format!("{:#X}", bits),
+				];
+				//  Each of these bit-slices has an element with more live bits
+				//  than one digit holds, so its entry cannot be read.
+				for text in others.iter() {
+					let parsed = text.parse::<BitVec<T, O>>();
+					if bits.is_empty() {
+						assert_eq!(parsed.unwrap(), bits);
+					}
+					else {
+						assert!(parsed.is_err(), "{}", text);
+					}
+				}
+			}
+		}
+	}
+
+	check::<u8, Msb0>();
+	check::<u8, Lsb0>();
+	check::<u16, Msb0>();
+	check::<u16, Lsb0>();
+
+	let bv = bitvec![u8, Msb0; 0, 0, 0, 1, 0, 0, 0, 0];
+	assert_eq!(format!("{:x}", bv), "[10]");
+	assert_eq!(
+		format!("{:x}", bv).parse::<BitVec<u8, Msb0>>(),
+		Err(ParseBitsError::Unsized { index: 1 })
+	);
+	assert_eq!(format!("{:#o}", bv), "[\n    0o020,\n]");
+	assert_eq!(
+		format!("{:#o}", bv).parse::<BitVec<u8, Msb0>>(),
+		Err(ParseBitsError::Unsized { index: 6 })
+	);
+}
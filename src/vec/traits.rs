@@ -25,6 +25,7 @@ use core::{
 		Hasher,
 	},
 	marker::Unpin,
+	str::FromStr,
 };
 
 use super::BitVec;
@@ -32,7 +33,11 @@ use crate::{
 	array::BitArray,
 	boxed::BitBox,
 	order::BitOrder,
-	slice::BitSlice,
+	slice::{
+		parse_bits,
+		BitSlice,
+		ParseBitsError,
+	},
 	store::BitStore,
 	view::BitViewSized,
 };
@@ -374,6 +379,42 @@ easy_fmt! {
 	for BitVec
 }
 
+/// Parses bit-text, including the `Display` and `{:#b}` renderings.
+///
+/// See the [`ParseBitsError`] documentation for the accepted forms, and for
+/// why the other numeric renderings are rejected.
+///
+/// ## Examples
+///
+/// ```rust
+/// use bitvec::prelude::*;
+///
+/// let bv: BitVec<u8, Msb0> = "[0, 1, 1]".parse().unwrap();
+/// assert_eq!(bv, bits![0, 1, 1]);
+/// assert_eq!(bv.to_string().parse::<BitVec<u8, Msb0>>(), Ok(bv));
+///
+/// assert_eq!("0b10_01".parse::<BitVec>().unwrap(), bits![1, 0, 0, 1]);
+/// assert_eq!("0o7".parse::<BitVec>().unwrap(), bits![1, 1, 1]);
+/// assert_eq!("[4'hA, 1]".parse::<BitVec>().unwrap(), bits![1, 0, 1, 0, 1]);
+/// assert!("[0xA, 1]".parse::<BitVec>().is_err());
+/// ```
+///
+/// [`ParseBitsError`]: crate::slice::ParseBitsError
+impl<T, O> FromStr for BitVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Err = ParseBitsError;
+
+	#[inline]
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		let mut out = Self::new();
+		parse_bits(text, |bit| out.push(bit))?;
+		Ok(out)
+	}
+}
+
 #[cfg(not(tarpaulin_include))]
 impl<T, O> Hash for BitVec<T, O>
 where